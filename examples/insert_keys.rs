@@ -1,5 +1,5 @@
 use crabdis::error::Result;
-use crabdis::storage::value::Value;
+use crabdis::protocol::frame::Frame;
 use tokio::io::{AsyncWriteExt, BufReader};
 use tokio::net::TcpStream;
 
@@ -10,11 +10,11 @@ async fn main() -> Result<()> {
     let mut bufreader = BufReader::new(&mut reader);
 
     for i in 0..1_000_000 {
-        let req = Value::Multi(
+        let req = Frame::Array(
             vec![
-                Value::String("SET".into()),
-                Value::String(format!("key{i}")),
-                Value::String(format!("value{i}")),
+                Frame::from("SET"),
+                Frame::from(format!("key{i}")),
+                Frame::from(format!("value{i}")),
             ]
             .into(),
         );
@@ -26,13 +26,13 @@ async fn main() -> Result<()> {
         writer.flush().await?;
 
         // can return none if the client has disconnected
-        let Some(resp) = Value::from_resp(&mut bufreader).await? else {
+        let Some(resp) = Frame::from_resp(&mut bufreader).await? else {
             return Ok(());
         };
 
         println!("Received response: {resp:?}");
 
-        assert_eq!(resp, Value::Ok);
+        assert_eq!(resp, Frame::Ok);
     }
 
     Ok(())
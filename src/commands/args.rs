@@ -0,0 +1,420 @@
+use std::ops::Deref;
+use std::time::Duration;
+
+use crate::prelude::*;
+use crate::utils::number::{parse_double, parse_integer};
+use crate::utils::time::unix_millis;
+
+/// The arguments of a single call, minus the command name.
+///
+/// Commands take their positional arguments off the front one by one and
+/// describe trailing keyword options with an [`Opt`] table, so the parsing
+/// rules and error replies are the same everywhere.
+pub struct Args {
+    command: String,
+    args: VecDeque<Bytes>,
+}
+
+impl Args {
+    pub fn new(command: &str, args: VecDeque<Bytes>) -> Self {
+        Self {
+            command: command.to_lowercase(),
+            args,
+        }
+    }
+
+    /// The lowercase name of the command, as used in error replies.
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// The next argument, running out of arguments is an arity error.
+    pub fn next(&mut self) -> Result<Bytes> {
+        self.args
+            .pop_front()
+            .ok_or_else(|| ReplyError::wrong_arity(&self.command).into())
+    }
+
+    pub fn next_int(&mut self) -> Result<i64> {
+        parse_int(&self.next()?)
+    }
+
+    pub fn next_float(&mut self) -> Result<f64> {
+        parse_float(&self.next()?)
+    }
+
+    /// The next argument as a positive expire time in milliseconds.
+    pub fn next_ttl(&mut self, unit: TimeUnit) -> Result<i64> {
+        let value = self.next_int()?;
+
+        unit.to_millis(value)
+            .filter(|millis| *millis > 0)
+            .ok_or_else(|| ReplyError::invalid_expire_time(&self.command).into())
+    }
+
+    /// The next argument as the timeout of a blocking command in seconds,
+    /// zero waits forever.
+    pub fn next_timeout(&mut self) -> Result<Option<Duration>> {
+        parse_timeout(&self.next()?)
+    }
+
+    /// Take every remaining argument.
+    pub fn rest(&mut self) -> impl Iterator<Item = Bytes> + '_ {
+        self.args.drain(..)
+    }
+
+    /// Take the remaining arguments as pairs, an odd count is an arity error.
+    pub fn pairs(&mut self) -> Result<Vec<(Bytes, Bytes)>> {
+        if !self.args.len().is_multiple_of(2) {
+            return Err(ReplyError::wrong_arity(&self.command).into());
+        }
+
+        let mut pairs = Vec::with_capacity(self.args.len() / 2);
+
+        while let (Some(first), Some(second)) = (self.args.pop_front(), self.args.pop_front()) {
+            pairs.push((first, second));
+        }
+
+        Ok(pairs)
+    }
+
+    /// Parse all the remaining arguments as keyword options.
+    ///
+    /// Option names are case insensitive. Unknown options, missing values and
+    /// two options from the same exclusive group are syntax errors.
+    pub fn options(&mut self, table: &[Opt]) -> Result<Options> {
+        self.options_before(table, None)
+    }
+
+    /// Like [`Args::options`], but stops in front of the `stop` keyword, for
+    /// commands whose options are followed by more arguments.
+    pub fn options_before(&mut self, table: &[Opt], stop: Option<&str>) -> Result<Options> {
+        let mut options = Options::default();
+
+        while let Some(arg) = self.args.pop_front() {
+            if stop.is_some_and(|stop| arg.eq_ignore_ascii_case(stop.as_bytes())) {
+                self.args.push_front(arg);
+                break;
+            }
+
+            let Some(opt) = table
+                .iter()
+                .find(|opt| arg.eq_ignore_ascii_case(opt.name.as_bytes()))
+            else {
+                return Err(ReplyError::Syntax.into());
+            };
+
+            let value = match opt.kind {
+                OptKind::Flag => OptValue::Flag,
+                OptKind::Int => OptValue::Int(parse_int(&self.next_value()?)?),
+                OptKind::Ttl(unit) => {
+                    let value = parse_int(&self.next_value()?)?;
+
+                    let millis = unit
+                        .to_millis(value)
+                        .filter(|millis| *millis > 0)
+                        .ok_or_else(|| ReplyError::invalid_expire_time(&self.command))?;
+
+                    OptValue::Int(millis)
+                }
+            };
+
+            let conflict = options.values.iter().any(|(name, _)| {
+                let other = table.iter().find(|opt| opt.name == *name);
+
+                // a flag may be repeated, anything else only given once per group
+                match other {
+                    Some(other) if other.name == opt.name => opt.kind != OptKind::Flag,
+                    Some(other) => opt.group != 0 && other.group == opt.group,
+                    None => false,
+                }
+            });
+
+            if conflict {
+                return Err(ReplyError::Syntax.into());
+            }
+
+            options.values.push((opt.name, value));
+        }
+
+        Ok(options)
+    }
+
+    /// The next argument of a subcommand or option, running out of arguments
+    /// is a syntax error.
+    pub fn next_value(&mut self) -> Result<Bytes> {
+        self.args
+            .pop_front()
+            .ok_or_else(|| ReplyError::Syntax.into())
+    }
+}
+
+impl Deref for Args {
+    type Target = VecDeque<Bytes>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.args
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeUnit {
+    Seconds,
+    Millis,
+}
+
+impl TimeUnit {
+    /// Convert to milliseconds, `None` if it doesn't fit.
+    pub fn to_millis(self, value: i64) -> Option<i64> {
+        match self {
+            Self::Seconds => value.checked_mul(1000),
+            Self::Millis => Some(value),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptKind {
+    /// A bare keyword.
+    Flag,
+    Int,
+    /// A positive expire time, stored in milliseconds.
+    Ttl(TimeUnit),
+}
+
+/// A keyword option a command accepts after its positional arguments.
+///
+/// Options sharing a non zero `group` are mutually exclusive.
+#[derive(Clone, Copy, Debug)]
+pub struct Opt {
+    pub name: &'static str,
+    pub kind: OptKind,
+    pub group: u8,
+}
+
+impl Opt {
+    pub const fn flag(name: &'static str, group: u8) -> Self {
+        Self::new(name, OptKind::Flag, group)
+    }
+
+    pub const fn new(name: &'static str, kind: OptKind, group: u8) -> Self {
+        Self { name, kind, group }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum OptValue {
+    Flag,
+    Int(i64),
+}
+
+/// The options that were given, looked up by their name in the table.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    values: Vec<(&'static str, OptValue)>,
+}
+
+impl Options {
+    pub fn has(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    pub fn int(&self, name: &str) -> Option<i64> {
+        match self.get(name)? {
+            OptValue::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The absolute deadline in Unix milliseconds set by an `EX`, `PX`, `EXAT`
+    /// or `PXAT` option, relative times count from now.
+    pub fn deadline(&self, command: &str) -> Result<Option<i64>> {
+        if let Some(absolute) = self.int("EXAT").or_else(|| self.int("PXAT")) {
+            return Ok(Some(absolute));
+        }
+
+        match self.int("EX").or_else(|| self.int("PX")) {
+            Some(relative) => relative
+                .checked_add(unix_millis())
+                .map(Some)
+                .ok_or_else(|| ReplyError::invalid_expire_time(command).into()),
+            None => Ok(None),
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<&OptValue> {
+        self.values
+            .iter()
+            .find(|(option, _)| *option == name)
+            .map(|(_, value)| value)
+    }
+}
+
+pub fn parse_int(value: &[u8]) -> Result<i64> {
+    parse_integer(value).ok_or_else(|| ReplyError::NotInteger.into())
+}
+
+/// Parse a float, accepting `inf`, `-inf` and their long forms but never NaN.
+pub fn parse_float(value: &[u8]) -> Result<f64> {
+    std::str::from_utf8(value)
+        .ok()
+        .filter(|value| !value.is_empty() && !value.starts_with(char::is_whitespace))
+        .and_then(parse_double)
+        .filter(|value| !value.is_nan())
+        .ok_or_else(|| ReplyError::NotFloat.into())
+}
+
+/// Parse the timeout of a blocking command in seconds, zero waits forever.
+pub fn parse_timeout(value: &[u8]) -> Result<Option<Duration>> {
+    let timeout = parse_float(value)
+        .map_err(|_| ReplyError::Generic("timeout is not a float or out of range".into()))?;
+
+    if timeout < 0.0 {
+        return Err(ReplyError::Generic("timeout is negative".into()).into());
+    }
+
+    if timeout == 0.0 {
+        return Ok(None);
+    }
+
+    Duration::try_from_secs_f64(timeout)
+        .map(Some)
+        .map_err(|_| ReplyError::Generic("timeout is out of range".into()).into())
+}
+
+/// Resolve an inclusive `start end` range over `len` elements, negative ends
+/// count from the back. `None` if the range is empty.
+pub fn range(start: i64, end: i64, len: usize) -> Option<(usize, usize)> {
+    let len = len as i64;
+
+    let start = if start < 0 {
+        (len + start).max(0)
+    } else {
+        start
+    };
+    let end = if end < 0 { len + end } else { end.min(len - 1) };
+
+    if start > end || start >= len {
+        return None;
+    }
+
+    Some((start as usize, end as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TABLE: &[Opt] = &[
+        Opt::flag("NX", 1),
+        Opt::flag("XX", 1),
+        Opt::flag("GET", 0),
+        Opt::new("EX", OptKind::Ttl(TimeUnit::Seconds), 2),
+        Opt::new("PX", OptKind::Ttl(TimeUnit::Millis), 2),
+        Opt::flag("KEEPTTL", 2),
+    ];
+
+    fn args(args: &[&str]) -> Args {
+        Args::new(
+            "SET",
+            args.iter()
+                .map(|arg| Bytes::from(arg.to_string()))
+                .collect(),
+        )
+    }
+
+    fn reply(result: Result<impl std::fmt::Debug>) -> String {
+        match result {
+            Err(Error::Reply(e)) => e.to_string(),
+            other => panic!("expected an error reply, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_args_positional() {
+        let mut parsed = args(&["key", "10"]);
+
+        assert_eq!(parsed.next().unwrap(), Bytes::from("key"));
+        assert_eq!(parsed.next_int().unwrap(), 10);
+        assert_eq!(
+            reply(parsed.next()),
+            "ERR wrong number of arguments for 'set' command"
+        );
+    }
+
+    #[test]
+    fn test_args_options() {
+        let options = args(&["nx", "Ex", "10", "get"]).options(TABLE).unwrap();
+
+        assert!(options.has("NX") && options.has("GET") && !options.has("XX"));
+        assert_eq!(options.int("EX"), Some(10_000));
+
+        assert_eq!(
+            reply(args(&["NX", "XX"]).options(TABLE)),
+            "ERR syntax error"
+        );
+        assert_eq!(
+            reply(args(&["EX", "1", "PX", "1"]).options(TABLE)),
+            "ERR syntax error"
+        );
+        assert_eq!(
+            reply(args(&["EX", "1", "EX", "1"]).options(TABLE)),
+            "ERR syntax error"
+        );
+        assert_eq!(reply(args(&["EX"]).options(TABLE)), "ERR syntax error");
+        assert_eq!(reply(args(&["BOGUS"]).options(TABLE)), "ERR syntax error");
+        assert_eq!(
+            reply(args(&["EX", "ten"]).options(TABLE)),
+            "ERR value is not an integer or out of range"
+        );
+        assert_eq!(
+            reply(args(&["PX", "0"]).options(TABLE)),
+            "ERR invalid expire time in 'set' command"
+        );
+        assert!(args(&["NX", "NX"]).options(TABLE).is_ok());
+
+        let mut parsed = args(&["GET", "fields", "NX"]);
+        let options = parsed.options_before(TABLE, Some("FIELDS")).unwrap();
+        assert!(options.has("GET") && !options.has("NX"));
+        assert_eq!(parsed.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(parse_int(b"-42").unwrap(), -42);
+        assert_eq!(parse_int(b"0").unwrap(), 0);
+        assert!(parse_int(b"").is_err());
+        assert!(parse_int(b"+1").is_err());
+        assert!(parse_int(b"01").is_err());
+        assert!(parse_int(b"-0").is_err());
+        assert!(parse_int(b" 1").is_err());
+        assert!(parse_int(b"9223372036854775808").is_err());
+
+        assert_eq!(parse_float(b"1e3").unwrap(), 1000.0);
+        assert_eq!(parse_float(b"-inf").unwrap(), f64::NEG_INFINITY);
+        assert!(parse_float(b"nan").is_err());
+        assert!(parse_float(b" 1").is_err());
+        assert!(parse_float(b"abc").is_err());
+    }
+
+    #[test]
+    fn test_args_timeout() {
+        assert_eq!(args(&["0"]).next_timeout().unwrap(), None);
+        assert_eq!(
+            args(&["0.25"]).next_timeout().unwrap(),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(
+            reply(args(&["-1"]).next_timeout()),
+            "ERR timeout is negative"
+        );
+        assert_eq!(
+            reply(args(&["soon"]).next_timeout()),
+            "ERR timeout is not a float or out of range"
+        );
+        assert_eq!(
+            reply(args(&["inf"]).next_timeout()),
+            "ERR timeout is out of range"
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use super::bits;
+use crate::prelude::*;
+
+pub struct BitCount;
+
+#[async_trait]
+impl CommandTrait for BitCount {
+    fn name(&self) -> &str {
+        "BITCOUNT"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "bitmap"
+    }
+
+    fn summary(&self) -> &str {
+        "Counts the number of set bits (population counting) in a string."
+    }
+
+    fn since(&self) -> &str {
+        "2.6.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let range = match args.len() {
+            0 => None,
+            2 | 3 => {
+                let start = args.next_int()?;
+                let end = args.next_int()?;
+
+                Some((start, end, bits::parse_unit(args.rest().next())?))
+            }
+            _ => return Err(ReplyError::Syntax.into()),
+        };
+
+        let store = session.state.store.read().await;
+        let bytes = bits::read(&store, &key)?;
+
+        let (start, end, bit_unit) = range.unwrap_or((0, -1, false));
+
+        let count = match bits::bit_range(start, end, bit_unit, bytes.len()) {
+            Some((first, last)) => bits::count(&bytes, first, last),
+            None => 0,
+        };
+
+        Ok(Frame::Integer(count as i64))
+    }
+}
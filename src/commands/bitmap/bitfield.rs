@@ -0,0 +1,325 @@
+use super::bits;
+use crate::commands::args::parse_int;
+use crate::prelude::*;
+
+pub struct BitField;
+
+#[async_trait]
+impl CommandTrait for BitField {
+    fn name(&self) -> &str {
+        "BITFIELD"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "bitmap"
+    }
+
+    fn summary(&self) -> &str {
+        "Performs arbitrary bitfield integer operations on strings."
+    }
+
+    fn since(&self) -> &str {
+        "3.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        bitfield(args, session, false).await
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Overflow {
+    Wrap,
+    Sat,
+    Fail,
+}
+
+/// An integer of `bits` bits at the bit `offset`.
+#[derive(Clone, Copy, Debug)]
+struct Field {
+    signed: bool,
+    bits: u32,
+    offset: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    Get(Field),
+    Set(Field, i64, Overflow),
+    IncrBy(Field, i64, Overflow),
+}
+
+/// Run the `GET`, `SET`, `INCRBY` and `OVERFLOW` subcommands in order, a
+/// read only call accepts nothing but `GET`.
+pub(super) async fn bitfield(
+    args: &mut Args,
+    session: SessionRef,
+    read_only: bool,
+) -> Result<Frame> {
+    let key = args.next()?.to_vec();
+    let max_len = session.state.limits.max_bulk_len;
+
+    let mut ops = Vec::new();
+    let mut overflow = Overflow::Wrap;
+
+    while !args.is_empty() {
+        let subcommand = args.next()?.to_ascii_uppercase();
+
+        if subcommand == b"OVERFLOW" {
+            let kind = args.next_value()?;
+
+            overflow = match kind.to_ascii_uppercase().as_slice() {
+                b"WRAP" => Overflow::Wrap,
+                b"SAT" => Overflow::Sat,
+                b"FAIL" => Overflow::Fail,
+                _ => return Ok(value_error!("Invalid OVERFLOW type specified")),
+            };
+
+            continue;
+        }
+
+        let kind = args.next_value()?;
+        let offset = args.next_value()?;
+
+        let field = parse_field(&kind, &offset, max_len)?;
+
+        let op = match subcommand.as_slice() {
+            b"GET" => Op::Get(field),
+            b"SET" | b"INCRBY" => {
+                let value = parse_int(&args.next_value()?)?;
+
+                if subcommand == b"SET" {
+                    Op::Set(field, value, overflow)
+                } else {
+                    Op::IncrBy(field, value, overflow)
+                }
+            }
+            _ => return Err(ReplyError::Syntax.into()),
+        };
+
+        if read_only && !matches!(op, Op::Get(_)) {
+            return Ok(value_error!("BITFIELD_RO only supports the GET subcommand"));
+        }
+
+        ops.push(op);
+    }
+
+    // the string is grown to fit every write up front, even the ones that fail
+    let highest_write = ops
+        .iter()
+        .filter_map(|op| match op {
+            Op::Get(_) => None,
+            Op::Set(field, ..) | Op::IncrBy(field, ..) => {
+                Some(field.offset + field.bits as usize - 1)
+            }
+        })
+        .max();
+
+    let replies = if let Some(highest_write) = highest_write {
+        let mut store = session.state.store.write().await;
+        let bytes = bits::write(&mut store, key)?;
+
+        bits::grow_to(bytes, highest_write);
+
+        ops.iter().map(|op| run(bytes, *op)).collect()
+    } else {
+        let store = session.state.store.read().await;
+        let bytes = bits::read(&store, &key)?;
+
+        ops.iter()
+            .map(|op| match op {
+                Op::Get(field) => Frame::Integer(field.get(&bytes)),
+                _ => unreachable!("only reads without writes"),
+            })
+            .collect()
+    };
+
+    Ok(Frame::Array(replies))
+}
+
+/// Parse a type like `i16` or `u8` and an offset in bits, or in multiples of
+/// the type width when prefixed with `#`.
+fn parse_field(kind: &[u8], offset: &[u8], max_len: usize) -> Result<Field> {
+    let bits = std::str::from_utf8(&kind[1.min(kind.len())..])
+        .ok()
+        .and_then(|bits| bits.parse::<u32>().ok());
+
+    let (signed, bits) = match (kind.first().map(u8::to_ascii_lowercase), bits) {
+        (Some(b'i'), Some(bits @ 1..=64)) => (true, bits),
+        (Some(b'u'), Some(bits @ 1..=63)) => (false, bits),
+        _ => {
+            return Err(ReplyError::Generic(
+                "Invalid bitfield type. Use something like i16 u8. Note that u64 is not supported but i64 is.".into(),
+            )
+            .into())
+        }
+    };
+
+    let offset = match offset.strip_prefix(b"#") {
+        Some(index) => parse_int(index)
+            .ok()
+            .and_then(|index| index.checked_mul(i64::from(bits))),
+        None => parse_int(offset).ok(),
+    };
+
+    let offset = bits::check_offset(offset, max_len)?;
+
+    Ok(Field {
+        signed,
+        bits,
+        offset,
+    })
+}
+
+fn run(bytes: &mut [u8], op: Op) -> Frame {
+    let (field, next) = match op {
+        Op::Get(field) => return Frame::Integer(field.get(bytes)),
+        Op::Set(field, value, overflow) => (field, field.add(field.cast(value), 0, overflow)),
+        Op::IncrBy(field, increment, overflow) => (
+            field,
+            field.add(field.get(bytes).into(), increment, overflow),
+        ),
+    };
+
+    // a failed write replies nil and leaves the field as it is
+    let Some(next) = next else {
+        return Frame::Nil;
+    };
+
+    let prev = field.get(bytes);
+    bits::set_field(bytes, field.offset, field.bits, next as u64);
+
+    // SET replies the old value, INCRBY the new one
+    match op {
+        Op::Set(..) => Frame::Integer(prev),
+        _ => Frame::Integer(next),
+    }
+}
+
+impl Field {
+    fn get(self, bytes: &[u8]) -> i64 {
+        let value = bits::get_field(bytes, self.offset, self.bits);
+
+        if self.signed && self.bits < 64 {
+            // sign extend from the top bit of the field
+            let shift = 64 - self.bits;
+            ((value << shift) as i64) >> shift
+        } else {
+            value as i64
+        }
+    }
+
+    /// A value to SET, unsigned types take negative values as their two's
+    /// complement the same way Redis does.
+    fn cast(self, value: i64) -> i128 {
+        if self.signed {
+            value.into()
+        } else {
+            (value as u64).into()
+        }
+    }
+
+    /// `value + increment` as this type, `None` if it overflows under `FAIL`.
+    fn add(self, value: i128, increment: i64, overflow: Overflow) -> Option<i64> {
+        let (min, max) = if self.signed {
+            let max = (1i128 << (self.bits - 1)) - 1;
+            (-max - 1, max)
+        } else {
+            (0, (1i128 << self.bits) - 1)
+        };
+
+        let result = value + i128::from(increment);
+
+        if (min..=max).contains(&result) {
+            return Some(result as i64);
+        }
+
+        match overflow {
+            Overflow::Fail => None,
+            Overflow::Sat => Some(if result > max { max } else { min } as i64),
+            Overflow::Wrap => {
+                // keep the low bits, then read them back with the type's sign
+                let wrapped = result.rem_euclid(1i128 << self.bits);
+
+                if self.signed && wrapped > max {
+                    Some((wrapped - (1i128 << self.bits)) as i64)
+                } else {
+                    Some(wrapped as i64)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(signed: bool, bits: u32) -> Field {
+        Field {
+            signed,
+            bits,
+            offset: 3,
+        }
+    }
+
+    #[test]
+    fn test_bitfield_overflow() {
+        let u8 = field(false, 8);
+
+        assert_eq!(u8.add(250, 10, Overflow::Wrap), Some(4));
+        assert_eq!(u8.add(250, 10, Overflow::Sat), Some(255));
+        assert_eq!(u8.add(250, 10, Overflow::Fail), None);
+        assert_eq!(u8.add(5, -10, Overflow::Wrap), Some(251));
+        assert_eq!(u8.add(5, -10, Overflow::Sat), Some(0));
+        assert_eq!(u8.add(u8.cast(-1), 0, Overflow::Wrap), Some(255));
+        assert_eq!(u8.add(u8.cast(-1), 0, Overflow::Sat), Some(255));
+
+        let i8 = field(true, 8);
+
+        assert_eq!(i8.add(127, 1, Overflow::Wrap), Some(-128));
+        assert_eq!(i8.add(-100, -100, Overflow::Sat), Some(-128));
+        assert_eq!(i8.add(200, 0, Overflow::Wrap), Some(-56));
+
+        let i64 = field(true, 64);
+
+        assert_eq!(i64.add(i64::MAX.into(), 1, Overflow::Wrap), Some(i64::MIN));
+        assert_eq!(i64.add(i64::MAX.into(), 1, Overflow::Sat), Some(i64::MAX));
+        assert_eq!(i64.add(i64::MIN.into(), -1, Overflow::Fail), None);
+    }
+
+    #[test]
+    fn test_bitfield_run() {
+        let mut bytes = vec![0; 2];
+
+        let set = Op::Set(field(true, 5), -10, Overflow::Wrap);
+        assert_eq!(run(&mut bytes, set), Frame::Integer(0));
+        assert_eq!(
+            run(&mut bytes, Op::Get(field(false, 5))),
+            Frame::Integer(22)
+        );
+        assert_eq!(
+            run(&mut bytes, Op::Get(field(true, 5))),
+            Frame::Integer(-10)
+        );
+
+        let incr = Op::IncrBy(field(true, 5), -7, Overflow::Fail);
+        assert_eq!(run(&mut bytes, incr), Frame::Nil);
+        assert_eq!(
+            run(&mut bytes, Op::Get(field(true, 5))),
+            Frame::Integer(-10)
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use super::bitfield::bitfield;
+use crate::prelude::*;
+
+pub struct BitFieldRo;
+
+#[async_trait]
+impl CommandTrait for BitFieldRo {
+    fn name(&self) -> &str {
+        "BITFIELD_RO"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "bitmap"
+    }
+
+    fn summary(&self) -> &str {
+        "Performs arbitrary read-only bitfield integer operations on strings."
+    }
+
+    fn since(&self) -> &str {
+        "6.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        bitfield(args, session, true).await
+    }
+}
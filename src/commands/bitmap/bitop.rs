@@ -0,0 +1,100 @@
+use super::bits;
+use crate::prelude::*;
+use crate::storage::value::StringValue;
+
+pub struct BitOp;
+
+#[async_trait]
+impl CommandTrait for BitOp {
+    fn name(&self) -> &str {
+        "BITOP"
+    }
+
+    fn arity(&self) -> i64 {
+        -4
+    }
+
+    fn group(&self) -> &str {
+        "bitmap"
+    }
+
+    fn summary(&self) -> &str {
+        "Performs bitwise operations on multiple strings, and stores the result."
+    }
+
+    fn since(&self) -> &str {
+        "2.6.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (2, -1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let operation = args.next()?;
+        let dest = args.next()?.to_vec();
+        let keys: Vec<Bytes> = args.rest().collect();
+
+        let operation = match operation.to_ascii_uppercase().as_slice() {
+            b"AND" => Operation::And,
+            b"OR" => Operation::Or,
+            b"XOR" => Operation::Xor,
+            b"NOT" if keys.len() == 1 => Operation::Not,
+            b"NOT" => {
+                return Ok(value_error!(
+                    "BITOP NOT must be called with a single source key."
+                ))
+            }
+            _ => return Err(ReplyError::Syntax.into()),
+        };
+
+        let mut store = session.state.store.write().await;
+
+        let sources = keys
+            .iter()
+            .map(|key| bits::read(&store, key))
+            .collect::<Result<Vec<_>>>()?;
+
+        // shorter strings are padded with zero bytes up to the longest one
+        let len = sources.iter().map(|bytes| bytes.len()).max().unwrap_or(0);
+
+        let result: Vec<u8> = (0..len)
+            .map(|i| {
+                let mut bytes = sources
+                    .iter()
+                    .map(|bytes| bytes.get(i).copied().unwrap_or(0));
+                let first = bytes.next().unwrap_or(0);
+
+                match operation {
+                    Operation::And => bytes.fold(first, |acc, byte| acc & byte),
+                    Operation::Or => bytes.fold(first, |acc, byte| acc | byte),
+                    Operation::Xor => bytes.fold(first, |acc, byte| acc ^ byte),
+                    Operation::Not => !first,
+                }
+            })
+            .collect();
+
+        drop(sources);
+
+        // an empty result deletes the destination
+        if result.is_empty() {
+            store.remove(&dest);
+        } else {
+            store.insert(dest, Entry::new(Value::String(StringValue::Raw(result))));
+        }
+
+        Ok(Frame::Integer(len as i64))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    And,
+    Or,
+    Xor,
+    Not,
+}
@@ -0,0 +1,79 @@
+use super::bits;
+use crate::prelude::*;
+
+pub struct BitPos;
+
+#[async_trait]
+impl CommandTrait for BitPos {
+    fn name(&self) -> &str {
+        "BITPOS"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "bitmap"
+    }
+
+    fn summary(&self) -> &str {
+        "Finds the first set (1) or clear (0) bit in a string."
+    }
+
+    fn since(&self) -> &str {
+        "2.8.7"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let bit = match &args.next()?[..] {
+            b"0" => false,
+            b"1" => true,
+            _ => return Ok(value_error!("The bit argument must be 1 or 0.")),
+        };
+
+        if args.len() > 3 {
+            return Err(ReplyError::Syntax.into());
+        }
+
+        let start = if args.is_empty() { 0 } else { args.next_int()? };
+        let end = if args.is_empty() {
+            None
+        } else {
+            Some(args.next_int()?)
+        };
+        let bit_unit = bits::parse_unit(args.rest().next())?;
+
+        let store = session.state.store.read().await;
+        let bytes = bits::read(&store, &key)?;
+
+        // a missing key is all clear bits
+        if bytes.is_empty() {
+            return Ok(Frame::Integer(if bit { -1 } else { 0 }));
+        }
+
+        let Some((first, last)) = bits::bit_range(start, end.unwrap_or(-1), bit_unit, bytes.len())
+        else {
+            return Ok(Frame::Integer(-1));
+        };
+
+        let position = match bits::position(&bytes, first, last, bit) {
+            Some(position) => position as i64,
+            // without an explicit end the string counts as padded with clear bits
+            None if !bit && end.is_none() => last as i64 + 1,
+            None => -1,
+        };
+
+        Ok(Frame::Integer(position))
+    }
+}
@@ -0,0 +1,225 @@
+use std::borrow::Cow;
+
+use crate::commands::args::parse_int;
+use crate::prelude::*;
+use crate::storage::db::Db;
+
+/// The bytes of the string at `key`, a missing key reads as an empty string.
+pub(super) fn read<'a>(db: &'a Db, key: &[u8]) -> Result<Cow<'a, [u8]>> {
+    match db.get(key) {
+        Some(Entry {
+            value: Value::String(value),
+            ..
+        }) => Ok(value.as_bytes()),
+        Some(_) => Err(ReplyError::WrongType.into()),
+        None => Ok(Cow::Borrowed(&[])),
+    }
+}
+
+/// The bytes of the string at `key` for editing in place, a missing key is
+/// created empty. Check the arguments first so a bad call doesn't leave an
+/// empty key behind.
+pub(super) fn write(db: &mut Db, key: Vec<u8>) -> Result<&mut Vec<u8>> {
+    if db
+        .get(&key)
+        .is_some_and(|entry| !matches!(entry.value, Value::String(_)))
+    {
+        return Err(ReplyError::WrongType.into());
+    }
+
+    match &mut db
+        .get_or_insert_with(key, || Entry::new(Value::from(Vec::new())))
+        .value
+    {
+        Value::String(value) => Ok(value.raw_mut()),
+        _ => unreachable!("checked to be a string above"),
+    }
+}
+
+/// Parse a bit offset, it has to fall within a string of `max_len` bytes.
+pub(super) fn parse_offset(value: &[u8], max_len: usize) -> Result<usize> {
+    check_offset(parse_int(value).ok(), max_len)
+}
+
+pub(super) fn check_offset(offset: Option<i64>, max_len: usize) -> Result<usize> {
+    offset
+        .and_then(|offset| usize::try_from(offset).ok())
+        .filter(|offset| offset / 8 < max_len)
+        .ok_or_else(|| {
+            ReplyError::Generic("bit offset is not an integer or out of range".into()).into()
+        })
+}
+
+/// Resolve an inclusive `start end` range in bytes, or in bits with `bit_unit`,
+/// to an inclusive range of bit offsets. Negative ends count from the end of
+/// the string, `None` if the range is empty.
+pub(super) fn bit_range(
+    start: i64,
+    end: i64,
+    bit_unit: bool,
+    len: usize,
+) -> Option<(usize, usize)> {
+    let total = if bit_unit { len * 8 } else { len } as i64;
+
+    let start = if start < 0 {
+        (total + start).max(0)
+    } else {
+        start
+    };
+    let end = if end < 0 {
+        (total + end).max(0)
+    } else {
+        end.min(total - 1)
+    };
+
+    if total == 0 || start > end {
+        return None;
+    }
+
+    let (start, end) = (start as usize, end as usize);
+
+    if bit_unit {
+        Some((start, end))
+    } else {
+        Some((start * 8, end * 8 + 7))
+    }
+}
+
+/// Parse the optional `BYTE|BIT` unit of a range, returns whether it's `BIT`.
+pub(super) fn parse_unit(value: Option<Bytes>) -> Result<bool> {
+    match value {
+        None => Ok(false),
+        Some(unit) if unit.eq_ignore_ascii_case(b"BYTE") => Ok(false),
+        Some(unit) if unit.eq_ignore_ascii_case(b"BIT") => Ok(true),
+        Some(_) => Err(ReplyError::Syntax.into()),
+    }
+}
+
+/// The bit at `offset`, counting from the most significant bit of the first
+/// byte. Bits past the end of the string are clear.
+pub(super) fn get_bit(bytes: &[u8], offset: usize) -> bool {
+    bytes
+        .get(offset / 8)
+        .is_some_and(|byte| byte & (0x80 >> (offset % 8)) != 0)
+}
+
+/// Set or clear the bit at `offset`, the string has to be long enough.
+pub(super) fn set_bit(bytes: &mut [u8], offset: usize, on: bool) {
+    let mask = 0x80 >> (offset % 8);
+
+    if on {
+        bytes[offset / 8] |= mask;
+    } else {
+        bytes[offset / 8] &= !mask;
+    }
+}
+
+/// Grow the string with zero bytes so it holds the bit at `offset`.
+pub(super) fn grow_to(bytes: &mut Vec<u8>, offset: usize) {
+    if bytes.len() <= offset / 8 {
+        bytes.resize(offset / 8 + 1, 0);
+    }
+}
+
+/// Count the set bits in the inclusive bit range.
+pub(super) fn count(bytes: &[u8], first: usize, last: usize) -> usize {
+    let (first_byte, last_byte) = (first / 8, last / 8);
+
+    // whole bytes are counted at once, the partial ones at the edges masked
+    let head = 0xffu8 >> (first % 8);
+    let tail = 0xffu8 << (7 - last % 8);
+
+    if first_byte == last_byte {
+        return (bytes[first_byte] & head & tail).count_ones() as usize;
+    }
+
+    let middle: u32 = bytes[first_byte + 1..last_byte]
+        .iter()
+        .map(|byte| byte.count_ones())
+        .sum();
+
+    ((bytes[first_byte] & head).count_ones() + middle + (bytes[last_byte] & tail).count_ones())
+        as usize
+}
+
+/// The offset of the first bit set to `bit` in the inclusive bit range.
+pub(super) fn position(bytes: &[u8], first: usize, last: usize, bit: bool) -> Option<usize> {
+    // a byte with nothing to find is skipped whole
+    let skip = if bit { 0x00 } else { 0xff };
+    let mut offset = first;
+
+    while offset <= last {
+        if offset.is_multiple_of(8) && offset + 7 <= last && bytes[offset / 8] == skip {
+            offset += 8;
+            continue;
+        }
+
+        if get_bit(bytes, offset) == bit {
+            return Some(offset);
+        }
+
+        offset += 1;
+    }
+
+    None
+}
+
+/// Read a `bits` wide unsigned integer starting at the bit `offset`, most
+/// significant bit first.
+pub(super) fn get_field(bytes: &[u8], offset: usize, bits: u32) -> u64 {
+    (0..bits as usize).fold(0, |value, i| {
+        value << 1 | u64::from(get_bit(bytes, offset + i))
+    })
+}
+
+/// Write the low `bits` bits of `value` starting at the bit `offset`, the
+/// string has to be long enough.
+pub(super) fn set_field(bytes: &mut [u8], offset: usize, bits: u32, value: u64) {
+    for i in 0..bits {
+        set_bit(bytes, offset + i as usize, value >> (bits - 1 - i) & 1 == 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bits_ranges() {
+        assert_eq!(bit_range(0, -1, false, 3), Some((0, 23)));
+        assert_eq!(bit_range(1, 1, false, 3), Some((8, 15)));
+        assert_eq!(bit_range(5, 30, true, 3), Some((5, 23)));
+        assert_eq!(bit_range(-100, -100, false, 3), Some((0, 7)));
+        assert_eq!(bit_range(3, 5, false, 3), None);
+        assert_eq!(bit_range(0, -1, false, 0), None);
+    }
+
+    #[test]
+    fn test_bits_count_and_position() {
+        let bytes = b"foobar";
+
+        assert_eq!(count(bytes, 0, 47), 26);
+        assert_eq!(count(bytes, 8, 15), 6);
+        assert_eq!(count(bytes, 5, 30), 17);
+        assert_eq!(count(bytes, 3, 3), 0);
+
+        let bytes = [0xff, 0xf0, 0x00];
+
+        assert_eq!(position(&bytes, 0, 23, false), Some(12));
+        assert_eq!(position(&bytes, 0, 23, true), Some(0));
+        assert_eq!(position(&bytes, 16, 23, true), None);
+        assert_eq!(position(&bytes, 2, 7, false), None);
+    }
+
+    #[test]
+    fn test_bits_fields() {
+        let mut bytes = vec![0; 2];
+
+        set_field(&mut bytes, 4, 8, 0xab);
+        assert_eq!(bytes, [0x0a, 0xb0]);
+        assert_eq!(get_field(&bytes, 4, 8), 0xab);
+        assert_eq!(get_field(&bytes, 0, 4), 0);
+        assert_eq!(get_field(&bytes, 12, 16), 0);
+        assert!(get_bit(&bytes, 4) && !get_bit(&bytes, 5));
+    }
+}
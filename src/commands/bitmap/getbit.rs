@@ -0,0 +1,45 @@
+use super::bits;
+use crate::prelude::*;
+
+pub struct GetBit;
+
+#[async_trait]
+impl CommandTrait for GetBit {
+    fn name(&self) -> &str {
+        "GETBIT"
+    }
+
+    fn arity(&self) -> i64 {
+        3
+    }
+
+    fn group(&self) -> &str {
+        "bitmap"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns a bit value by offset."
+    }
+
+    fn since(&self) -> &str {
+        "2.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let offset = bits::parse_offset(&args.next()?, session.state.limits.max_bulk_len)?;
+
+        let store = session.state.store.read().await;
+        let bytes = bits::read(&store, &key)?;
+
+        Ok(Frame::Integer(bits::get_bit(&bytes, offset).into()))
+    }
+}
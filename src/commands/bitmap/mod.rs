@@ -0,0 +1,16 @@
+mod bitcount;
+mod bitfield;
+mod bitfield_ro;
+mod bitop;
+mod bitpos;
+mod bits;
+mod getbit;
+mod setbit;
+
+pub use bitcount::BitCount;
+pub use bitfield::BitField;
+pub use bitfield_ro::BitFieldRo;
+pub use bitop::BitOp;
+pub use bitpos::BitPos;
+pub use getbit::GetBit;
+pub use setbit::SetBit;
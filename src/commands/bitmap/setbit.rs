@@ -0,0 +1,56 @@
+use super::bits;
+use crate::prelude::*;
+
+pub struct SetBit;
+
+#[async_trait]
+impl CommandTrait for SetBit {
+    fn name(&self) -> &str {
+        "SETBIT"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "bitmap"
+    }
+
+    fn summary(&self) -> &str {
+        "Sets or clears the bit at offset of the string value. Creates the key if it doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "2.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let offset = bits::parse_offset(&args.next()?, session.state.limits.max_bulk_len)?;
+
+        let on = match &args.next()?[..] {
+            b"0" => false,
+            b"1" => true,
+            _ => return Ok(value_error!("bit is not an integer or out of range")),
+        };
+
+        let mut store = session.state.store.write().await;
+        let bytes = bits::write(&mut store, key)?;
+
+        let prev = bits::get_bit(bytes, offset);
+
+        bits::grow_to(bytes, offset);
+        bits::set_bit(bytes, offset, on);
+
+        Ok(Frame::Integer(prev.into()))
+    }
+}
@@ -0,0 +1,407 @@
+use crate::commands::args::parse_int;
+use crate::commands::CommandRef;
+use crate::prelude::*;
+
+pub struct Command;
+
+#[async_trait]
+impl CommandTrait for Command {
+    fn name(&self) -> &str {
+        "COMMAND"
+    }
+
+    fn arity(&self) -> i64 {
+        -1
+    }
+
+    fn group(&self) -> &str {
+        "server"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns detailed information about all commands."
+    }
+
+    fn since(&self) -> &str {
+        "2.8.13"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Loading, CommandFlag::Stale]
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let commands = &session.state.commands;
+
+        if args.is_empty() {
+            let all = commands.all().await;
+
+            return Ok(Frame::Array(all.iter().map(info).collect()));
+        }
+
+        let subcommand = args.next()?;
+
+        match subcommand.to_ascii_uppercase().as_slice() {
+            b"COUNT" => {
+                if !args.is_empty() {
+                    return Err(ReplyError::wrong_arity("command|count").into());
+                }
+
+                Ok(Frame::Integer(commands.all().await.len() as i64))
+            }
+
+            b"INFO" => {
+                if args.is_empty() {
+                    let all = commands.all().await;
+
+                    return Ok(Frame::Array(all.iter().map(info).collect()));
+                }
+
+                let mut response = VecDeque::with_capacity(args.len());
+
+                // unknown names are answered with a nil in their place
+                for name in args.iter() {
+                    response.push_back(match commands.get(name).await {
+                        Some(command) => info(&command),
+                        None => Frame::Nil,
+                    });
+                }
+
+                Ok(Frame::Array(response))
+            }
+
+            b"DOCS" => {
+                let selected = if args.is_empty() {
+                    commands.all().await
+                } else {
+                    let mut selected = Vec::with_capacity(args.len());
+
+                    // unlike INFO, unknown names are left out
+                    for name in args.iter() {
+                        if let Some(command) = commands.get(name).await {
+                            selected.push(command);
+                        }
+                    }
+
+                    selected
+                };
+
+                Ok(Frame::Map(
+                    selected
+                        .iter()
+                        .map(|command| (Frame::from(command.name().to_lowercase()), docs(command)))
+                        .collect(),
+                ))
+            }
+
+            b"GETKEYS" => {
+                let Some(name) = args.front() else {
+                    return Err(ReplyError::wrong_arity("command|getkeys").into());
+                };
+
+                let Some(command) = commands.get(name).await else {
+                    return Ok(value_error!("Invalid command specified"));
+                };
+
+                if command.key_range().0 == 0 && command.numkeys_index() == 0 {
+                    return Ok(value_error!("The command has no key arguments"));
+                }
+
+                let argc = args.len() as i64;
+                let arity = command.arity();
+
+                if (arity > 0 && argc != arity) || argc < -arity {
+                    return Ok(value_error!(
+                        "Invalid number of arguments specified for command"
+                    ));
+                }
+
+                let mut keys = key_positions(command.key_range(), argc);
+
+                // a count that runs past the arguments names no keys at all
+                match numkeys_positions(command.numkeys_index(), args) {
+                    Some(counted) => keys.extend(counted),
+                    None => keys.clear(),
+                }
+
+                if keys.is_empty() {
+                    return Ok(value_error!("Invalid arguments specified for command"));
+                }
+
+                Ok(Frame::Array(
+                    keys.into_iter()
+                        .map(|position| Frame::Bulk(args[position].to_vec()))
+                        .collect(),
+                ))
+            }
+
+            b"HELP" => {
+                if !args.is_empty() {
+                    return Err(ReplyError::wrong_arity("command|help").into());
+                }
+
+                Ok(Frame::Array(
+                    HELP.iter()
+                        .map(|line| Frame::Simple((*line).into()))
+                        .collect(),
+                ))
+            }
+
+            _ => Err(ReplyError::unknown_subcommand(&subcommand, self.name()).into()),
+        }
+    }
+}
+
+const HELP: &[&str] = &[
+    "COMMAND <subcommand> [<arg> [value] [opt] ...]. Subcommands are:",
+    "(no subcommand)",
+    "    Return details about all commands.",
+    "COUNT",
+    "    Return the total number of commands in this server.",
+    "INFO [<command-name> ...]",
+    "    Return details about multiple commands.",
+    "    If no command names are given, details for all commands are returned.",
+    "DOCS [<command-name> ...]",
+    "    Return documentation details about multiple commands.",
+    "    If no command names are given, documentation details for all",
+    "    commands are returned.",
+    "GETKEYS <full-command>",
+    "    Return the keys from a full command.",
+    "HELP",
+    "    Print this help.",
+];
+
+/// The entry `COMMAND INFO` returns for a command.
+fn info(command: &CommandRef) -> Frame {
+    let (first, last, step) = command.key_range();
+
+    let flags = command
+        .flags()
+        .iter()
+        .map(|flag| Frame::Simple(flag.as_str().into()))
+        .collect();
+
+    let categories = command
+        .acl_categories()
+        .into_iter()
+        .map(|category| Frame::Simple(category.into()))
+        .collect();
+
+    Frame::Array(VecDeque::from([
+        Frame::from(command.name().to_lowercase()),
+        Frame::Integer(command.arity()),
+        Frame::Set(flags),
+        Frame::Integer(first),
+        Frame::Integer(last),
+        Frame::Integer(step),
+        Frame::Set(categories),
+        // tips
+        Frame::Array(VecDeque::new()),
+        key_specs(command),
+        // subcommands
+        Frame::Array(VecDeque::new()),
+    ]))
+}
+
+/// The key specs for the key range and the `numkeys` counted keys.
+fn key_specs(command: &CommandRef) -> Frame {
+    let (first, last, step) = command.key_range();
+    let mut specs = VecDeque::new();
+
+    if first != 0 {
+        specs.push_back(range_spec(first, last, step));
+    }
+
+    let index = command.numkeys_index();
+
+    if index != 0 {
+        let find_keys = Frame::Map(vec![
+            (Frame::from("type"), Frame::from("keynum")),
+            (
+                Frame::from("spec"),
+                Frame::Map(vec![
+                    (Frame::from("keynumidx"), Frame::Integer(0)),
+                    (Frame::from("firstkey"), Frame::Integer(1)),
+                    (Frame::from("keystep"), Frame::Integer(1)),
+                ]),
+            ),
+        ]);
+
+        specs.push_back(key_spec(index, find_keys));
+    }
+
+    Frame::Array(specs)
+}
+
+/// The key spec equivalent to the legacy `(first, last, step)` triple.
+fn range_spec(first: i64, last: i64, step: i64) -> Frame {
+    // the range is expressed relative to the first key, negative stays relative to the end
+    let last = if last < 0 { last } else { last - first };
+
+    let find_keys = Frame::Map(vec![
+        (Frame::from("type"), Frame::from("range")),
+        (
+            Frame::from("spec"),
+            Frame::Map(vec![
+                (Frame::from("lastkey"), Frame::Integer(last)),
+                (Frame::from("keystep"), Frame::Integer(step)),
+                (Frame::from("limit"), Frame::Integer(0)),
+            ]),
+        ),
+    ]);
+
+    key_spec(first, find_keys)
+}
+
+/// A key spec whose search starts at a fixed position.
+fn key_spec(index: i64, find_keys: Frame) -> Frame {
+    let begin_search = Frame::Map(vec![
+        (Frame::from("type"), Frame::from("index")),
+        (
+            Frame::from("spec"),
+            Frame::Map(vec![(Frame::from("index"), Frame::Integer(index))]),
+        ),
+    ]);
+
+    Frame::Map(vec![
+        (Frame::from("flags"), Frame::Set(Vec::new())),
+        (Frame::from("begin_search"), begin_search),
+        (Frame::from("find_keys"), find_keys),
+    ])
+}
+
+/// The entry `COMMAND DOCS` returns for a command.
+fn docs(command: &CommandRef) -> Frame {
+    Frame::Map(vec![
+        (Frame::from("summary"), Frame::from(command.summary())),
+        (Frame::from("since"), Frame::from(command.since())),
+        (Frame::from("group"), Frame::from(command.group())),
+    ])
+}
+
+/// Indexes of the keys in a call with `argc` arguments, the command name included.
+fn key_positions((first, last, step): (i64, i64, i64), argc: i64) -> Vec<usize> {
+    if first == 0 || step <= 0 {
+        return Vec::new();
+    }
+
+    let last = if last < 0 { argc + last } else { last };
+
+    (first..=last.min(argc - 1))
+        .step_by(step as usize)
+        .map(|position| position as usize)
+        .collect()
+}
+
+/// Indexes of the keys following the `numkeys` argument at `index`, none for
+/// a command without one. `None` if the argument is missing or doesn't count
+/// a valid number of keys.
+fn numkeys_positions(index: i64, args: &VecDeque<Bytes>) -> Option<Vec<usize>> {
+    if index == 0 {
+        return Some(Vec::new());
+    }
+
+    let index = usize::try_from(index).ok()?;
+
+    let numkeys = args
+        .get(index)
+        .and_then(|numkeys| parse_int(numkeys).ok())
+        .and_then(|numkeys| usize::try_from(numkeys).ok())
+        .filter(|numkeys| index + numkeys < args.len())?;
+
+    Some((index + 1..=index + numkeys).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::testing::{bulks, run, session};
+
+    #[test]
+    fn test_key_positions() {
+        assert_eq!(key_positions((1, 1, 1), 2), vec![1]);
+        assert_eq!(key_positions((1, -1, 1), 4), vec![1, 2, 3]);
+        assert_eq!(key_positions((1, -1, 2), 5), vec![1, 3]);
+        assert_eq!(key_positions((0, 0, 0), 3), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_numkeys_positions() {
+        let args: VecDeque<Bytes> = ["zunionstore", "dst", "2", "a", "b", "WEIGHTS", "1", "2"]
+            .into_iter()
+            .map(Bytes::from)
+            .collect();
+
+        assert_eq!(numkeys_positions(2, &args), Some(vec![3, 4]));
+        assert_eq!(numkeys_positions(0, &args), Some(Vec::new()));
+
+        // a count running past the arguments is invalid
+        assert_eq!(numkeys_positions(7, &args), None);
+        assert_eq!(numkeys_positions(1, &args), None);
+        assert_eq!(numkeys_positions(8, &args), None);
+    }
+
+    #[tokio::test]
+    async fn test_command_getkeys() {
+        let session = session().await;
+
+        assert_eq!(
+            run(
+                &session,
+                "COMMAND GETKEYS zunionstore dst 2 a b WEIGHTS 1 2"
+            )
+            .await,
+            bulks(&["dst", "a", "b"])
+        );
+        assert_eq!(
+            run(&session, "COMMAND GETKEYS mset a 1 b 2").await,
+            bulks(&["a", "b"])
+        );
+
+        // a count past the arguments is invalid, not a call without counted keys
+        for line in [
+            "COMMAND GETKEYS zunionstore dst 5 a",
+            "COMMAND GETKEYS zunionstore dst x a",
+            "COMMAND GETKEYS lmpop 0 a LEFT",
+        ] {
+            assert_eq!(
+                run(&session, line).await,
+                value_error!("Invalid arguments specified for command")
+            );
+        }
+
+        assert_eq!(
+            run(&session, "COMMAND GETKEYS ping").await,
+            value_error!("The command has no key arguments")
+        );
+        assert_eq!(
+            run(&session, "COMMAND GETKEYS get").await,
+            value_error!("Invalid number of arguments specified for command")
+        );
+        assert_eq!(
+            run(&session, "COMMAND GETKEYS nosuch a").await,
+            value_error!("Invalid command specified")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_command_help() {
+        let session = session().await;
+
+        let Frame::Array(lines) = run(&session, "COMMAND HELP").await else {
+            panic!("expected an array");
+        };
+
+        assert_eq!(lines.len(), HELP.len());
+        assert_eq!(
+            run(&session, "COMMAND help extra").await,
+            Frame::from(ReplyError::wrong_arity("command|help"))
+        );
+
+        // the error of an unknown subcommand points at HELP
+        assert_eq!(
+            run(&session, "COMMAND NOSUCH").await,
+            Frame::from(ReplyError::unknown_subcommand(b"NOSUCH", "COMMAND"))
+        );
+    }
+}
@@ -8,34 +8,41 @@ impl CommandTrait for Del {
         "DEL"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
-        if args.len() < 1 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "generic"
+    }
+
+    fn summary(&self) -> &str {
+        "Deletes one or more keys."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write]
+    }
 
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, -1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
         let mut store = session.state.store.write().await;
+
         let mut count = 0;
-        while let Some(key) = args.pop_front() {
-            match key {
-                Value::String(k) => {
-                    if store.remove(&k).is_some() {
-                        count += 1;
-                    }
-                }
-
-                _ => {
-                    return value_error!("Invalid key").to_resp2(writer).await;
-                }
+
+        for key in args.rest() {
+            if store.remove(key.as_ref()).is_some() {
+                count += 1;
             }
         }
 
-        Value::Integer(count).to_resp2(writer).await
+        Ok(Frame::Integer(count))
     }
 }
@@ -8,38 +8,41 @@ impl CommandTrait for Exists {
         "EXISTS"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
-        if args.len() < 1 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "generic"
+    }
 
+    fn summary(&self) -> &str {
+        "Determines whether one or more keys exist."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, -1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
         let store = session.state.store.read().await;
 
         let mut count = 0;
-        while let Some(key) = args.pop_front() {
-            match key {
-                Value::String(k) => match store.get(&k) {
-                    // Expired keys are not counted
-                    Some(v) if v.expired() => {}
-                    Some(_) => {
-                        count += 1;
-                    }
-                    None => {}
-                },
-
-                _ => {
-                    return value_error!("Invalid key").to_resp2(writer).await;
-                }
+
+        for key in args.rest() {
+            if store.contains_key(&key) {
+                count += 1;
             }
         }
 
-        Value::Integer(count).to_resp2(writer).await
+        Ok(Frame::Integer(count))
     }
 }
@@ -1,3 +1,4 @@
+use crate::commands::args::Opt;
 use crate::prelude::*;
 
 pub struct FlushDB;
@@ -8,20 +9,32 @@ impl CommandTrait for FlushDB {
         "FLUSHDB"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
-        if args.len() > 1 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
+    fn arity(&self) -> i64 {
+        -1
+    }
+
+    fn group(&self) -> &str {
+        "server"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes all keys from the current database."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write]
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        // everything is freed in place, so both modes behave the same
+        args.options(&[Opt::flag("ASYNC", 1), Opt::flag("SYNC", 1)])?;
 
         session.state.store.write().await.clear();
 
-        Value::Ok.to_resp2(writer).await
+        Ok(Frame::Ok)
     }
 }
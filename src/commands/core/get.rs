@@ -8,28 +8,40 @@ impl CommandTrait for Get {
         "GET"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
-        if args.len() != 1 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
+    fn arity(&self) -> i64 {
+        2
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the string value of a key."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
 
-        let key = match args.pop_front() {
-            Some(Value::String(key)) => key,
-            _ => {
-                return value_error!("Invalid key").to_resp2(writer).await;
-            }
-        };
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
 
-        match session.state.store.read().await.get(&key) {
-            Some(value) => value.to_resp2(writer).await,
-            None => Value::Nil.to_resp2(writer).await,
+        match session.state.store.read().await.get(key.as_ref()) {
+            Some(Entry {
+                value: Value::String(value),
+                ..
+            }) => Ok(Frame::Bulk(value.to_vec())),
+            Some(_) => Ok(Frame::wrong_type()),
+            None => Ok(Frame::Nil),
         }
     }
 }
@@ -8,59 +8,68 @@ impl CommandTrait for Hello {
         "HELLO"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
+    fn arity(&self) -> i64 {
+        -1
+    }
+
+    fn group(&self) -> &str {
+        "connection"
+    }
+
+    fn summary(&self) -> &str {
+        "Handshakes with the Redis server."
+    }
+
+    fn since(&self) -> &str {
+        "6.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[
+            CommandFlag::NoScript,
+            CommandFlag::Loading,
+            CommandFlag::Stale,
+            CommandFlag::Fast,
+            CommandFlag::NoAuth,
+        ]
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
         if args.len() > 1 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
+            return Err(ReplyError::wrong_arity(self.name()).into());
         }
 
-        match args.pop_front() {
-            Some(Value::String(version)) => {
-                if version != "2" && version != "3" {
-                    return value_error!("Invalid version").to_resp2(writer).await;
+        if !args.is_empty() {
+            let version = match args.next_int() {
+                Ok(version @ (2 | 3)) => version as u8,
+                Ok(_) => return Err(ReplyError::NoProto.into()),
+                Err(_) => {
+                    return Ok(value_error!(
+                        "Protocol version is not an integer or out of range"
+                    ))
                 }
+            };
 
-                session.set_proto_version(version.parse().unwrap()).await;
-            }
-
-            _ => {}
+            session.set_proto_version(version);
         }
 
-        let response = Value::Map(HashMap::from([
-            (
-                Value::String("server".to_string()),
-                Value::String(env!("CARGO_PKG_NAME").to_string()),
-            ),
+        let response = Frame::Map(Vec::from([
+            (Frame::from("server"), Frame::from(env!("CARGO_PKG_NAME"))),
             (
-                Value::String("version".to_string()),
-                Value::String(env!("CARGO_PKG_VERSION").to_string()),
+                Frame::from("version"),
+                Frame::from(env!("CARGO_PKG_VERSION")),
             ),
             (
-                Value::String("proto".to_string()),
-                Value::Integer(session.get_proto_version().await),
+                Frame::from("proto"),
+                Frame::Integer(session.get_proto_version()),
             ),
             // TODO: fix no count of connected clients
-            (Value::String("id".to_string()), Value::Integer(0)),
-            (
-                Value::String("mode".to_string()),
-                Value::String("standalone".to_string()),
-            ),
-            (
-                Value::String("role".to_string()),
-                Value::String("master".to_string()),
-            ),
-            (
-                Value::String("modules".to_string()),
-                Value::Multi(Default::default()),
-            ),
+            (Frame::from("id"), Frame::Integer(0)),
+            (Frame::from("mode"), Frame::from("standalone")),
+            (Frame::from("role"), Frame::from("master")),
+            (Frame::from("modules"), Frame::Array(Default::default())),
         ]));
 
-        session.versioned_response(&response, writer).await
+        Ok(response)
     }
 }
@@ -8,20 +8,29 @@ impl CommandTrait for Info {
         "INFO"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        _session: SessionRef,
-    ) -> Result<()> {
-        if args.len() > 0 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
-
-        let response = Value::String("loading:0".to_string());
-
-        response.to_resp2(writer).await
+    fn arity(&self) -> i64 {
+        -1
+    }
+
+    fn group(&self) -> &str {
+        "server"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns information and statistics about the server."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Loading, CommandFlag::Stale]
+    }
+
+    async fn handle_command(&self, _args: &mut Args, _session: SessionRef) -> Result<Frame> {
+        let response = Frame::from("loading:0");
+
+        Ok(response)
     }
 }
@@ -0,0 +1,45 @@
+use crate::prelude::*;
+
+pub struct Type;
+
+#[async_trait]
+impl CommandTrait for Type {
+    fn name(&self) -> &str {
+        "TYPE"
+    }
+
+    fn arity(&self) -> i64 {
+        2
+    }
+
+    fn group(&self) -> &str {
+        "generic"
+    }
+
+    fn summary(&self) -> &str {
+        "Determines the type of value stored at a key."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let type_name = match session.state.store.read().await.get(key.as_ref()) {
+            Some(entry) => entry.value.type_name(),
+            None => "none",
+        };
+
+        Ok(Frame::Simple(type_name.to_string()))
+    }
+}
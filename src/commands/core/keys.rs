@@ -1,6 +1,5 @@
-use glob::Pattern;
-
 use crate::prelude::*;
+use crate::utils::glob;
 
 pub struct Keys;
 
@@ -10,33 +9,37 @@ impl CommandTrait for Keys {
         "KEYS"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
-        if args.len() != 1 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
+    fn arity(&self) -> i64 {
+        2
+    }
 
-        let pattern = match args.pop_front() {
-            Some(Value::String(s)) => Pattern::new(&s)?,
-            _ => {
-                return value_error!("Invalid pattern").to_resp2(writer).await;
-            }
-        };
+    fn group(&self) -> &str {
+        "generic"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns all key names that match a pattern."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let pattern = args.next()?;
 
         let mut keys = VecDeque::new();
 
         for key in session.state.store.read().await.keys() {
-            if pattern.matches(key) {
-                keys.push_back(Value::String(key.clone()));
+            if glob::matches(&pattern, key) {
+                keys.push_back(Frame::Bulk(key.clone()));
             }
         }
 
-        Value::Multi(keys).to_resp2(writer).await
+        Ok(Frame::Array(keys))
     }
 }
@@ -8,35 +8,46 @@ impl CommandTrait for MGet {
         "MGET"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
-        if args.len() < 1 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
+    fn arity(&self) -> i64 {
+        -2
+    }
 
-        let mut values = VecDeque::with_capacity(args.len());
+    fn group(&self) -> &str {
+        "string"
+    }
 
-        let store = session.state.store.write().await;
+    fn summary(&self) -> &str {
+        "Atomically returns the string values of one or more keys."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, -1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let mut values = VecDeque::with_capacity(args.len());
 
-        while let Some(key) = args.pop_front() {
-            match key {
-                Value::String(k) => match store.get(&k) {
-                    Some(value) => values.push_back(value.clone()),
-                    None => values.push_back(Value::Nil),
-                },
+        let store = session.state.store.read().await;
 
-                _ => {
-                    return value_error!("Invalid key").to_resp2(writer).await;
-                }
+        for key in args.rest() {
+            match store.get(key.as_ref()) {
+                Some(Entry {
+                    value: Value::String(value),
+                    ..
+                }) => values.push_back(Frame::Bulk(value.to_vec())),
+                // mget replies with nil for keys holding other types
+                _ => values.push_back(Frame::Nil),
             }
         }
 
-        Value::Multi(values).to_resp2(writer).await
+        Ok(Frame::Array(values))
     }
 }
@@ -1,21 +1,25 @@
+mod command;
 mod del;
 mod exists;
 mod flushdb;
 mod get;
 mod hello;
 mod info;
+mod key_type;
 mod keys;
 mod mget;
 mod mset;
 mod ping;
 mod set;
 
+pub use command::Command;
 pub use del::Del;
 pub use exists::Exists;
 pub use flushdb::FlushDB;
 pub use get::Get;
 pub use hello::Hello;
 pub use info::Info;
+pub use key_type::Type;
 pub use keys::Keys;
 pub use mget::MGet;
 pub use mset::MSet;
@@ -8,33 +8,39 @@ impl CommandTrait for MSet {
         "MSET"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
-        if args.len() < 2 || args.len() % 2 != 0 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Atomically creates or modifies the string values of one or more keys."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.1"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, -1, 2)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let pairs = args.pairs()?;
 
         let mut store = session.state.store.write().await;
 
-        while let Some(key) = args.pop_front() {
-            match key {
-                Value::String(k) => {
-                    // safe to unwrap because we checked the length of the args
-                    store.insert(k, args.pop_front().unwrap());
-                }
-
-                _ => {
-                    return value_error!("Invalid key").to_resp2(writer).await;
-                }
-            }
+        for (key, value) in pairs {
+            store.insert(key.to_vec(), Entry::new(Value::from(value.to_vec())));
         }
 
-        Value::Ok.to_resp2(writer).await
+        Ok(Frame::Ok)
     }
 }
@@ -8,23 +8,36 @@ impl CommandTrait for Ping {
         "PING"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        _session: SessionRef,
-    ) -> Result<()> {
+    fn arity(&self) -> i64 {
+        -1
+    }
+
+    fn group(&self) -> &str {
+        "connection"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the server's liveliness response."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Fast]
+    }
+
+    async fn handle_command(&self, args: &mut Args, _session: SessionRef) -> Result<Frame> {
         if args.len() > 1 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
+            return Err(ReplyError::wrong_arity(self.name()).into());
         }
 
-        let response = match args.pop_front() {
-            Some(s) => s,
-            _ => Value::Pong,
+        let response = match args.rest().next() {
+            Some(s) => Frame::Bulk(s.to_vec()),
+            _ => Frame::Pong,
         };
 
-        response.to_resp2(writer).await
+        Ok(response)
     }
 }
@@ -1,22 +1,18 @@
-use std::time::SystemTime;
-
-use tokio::time::{Duration, Instant};
-
+use crate::commands::args::{Opt, OptKind, TimeUnit};
 use crate::prelude::*;
 
 pub struct Set;
 
-#[derive(Default, Debug)]
-struct Arguments {
-    pub set_nx: bool,
-    pub set_xx: bool,
-    pub get: bool,
-    pub ex: Option<i64>,
-    pub px: Option<i64>,
-    pub exat: Option<i64>,
-    pub pxat: Option<i64>,
-    pub keepttl: bool,
-}
+const OPTIONS: &[Opt] = &[
+    Opt::flag("NX", 1),
+    Opt::flag("XX", 1),
+    Opt::flag("GET", 0),
+    Opt::new("EX", OptKind::Ttl(TimeUnit::Seconds), 2),
+    Opt::new("PX", OptKind::Ttl(TimeUnit::Millis), 2),
+    Opt::new("EXAT", OptKind::Ttl(TimeUnit::Seconds), 2),
+    Opt::new("PXAT", OptKind::Ttl(TimeUnit::Millis), 2),
+    Opt::flag("KEEPTTL", 2),
+];
 
 #[async_trait]
 impl CommandTrait for Set {
@@ -24,132 +20,80 @@ impl CommandTrait for Set {
         "SET"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
-        if args.len() < 2 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
+    fn arity(&self) -> i64 {
+        -3
+    }
 
-        let key = match args.pop_front() {
-            Some(Value::String(key)) => key,
-            Some(_) => {
-                return value_error!("Invalid key").to_resp2(writer).await;
-            }
-            None => {
-                return value_error!("Missing key").to_resp2(writer).await;
-            }
-        };
+    fn group(&self) -> &str {
+        "string"
+    }
 
-        let value = match args.pop_front() {
-            Some(value) => value,
-            _ => {
-                return value_error!("Missing value").to_resp2(writer).await;
-            }
-        };
+    fn summary(&self) -> &str {
+        "Sets the string value of a key, ignoring its type. The key is created if it doesn't exist."
+    }
 
-        let mut arguments = Arguments::default();
-        let mut prev_ex_arg = None;
-
-        for arg in args.iter() {
-            match arg {
-                Value::String(arg) => match arg.to_uppercase().as_str() {
-                    "NX" if !arguments.set_xx => {
-                        arguments.set_nx = true;
-                    }
-                    "XX" if !arguments.set_nx => {
-                        arguments.set_xx = true;
-                    }
-                    "GET" => arguments.get = true,
-                    "KEEPTTL" if prev_ex_arg.is_none() => {
-                        arguments.keepttl = true;
-                    }
-
-                    // also check if the previous argument was EX, PX, EXAT, PXAT
-                    "EX" | "PX" | "EXAT" | "PXAT" if !arguments.keepttl && prev_ex_arg.is_none() => {
-                        prev_ex_arg = Some(arg);
-                    }
-                    arg => {
-                        if let Some(prev) = prev_ex_arg {
-                            match prev.as_str() {
-                                "EX" => arguments.ex = arg.parse::<i64>().ok(),
-                                "PX" => arguments.px = arg.parse::<i64>().ok(),
-                                "EXAT" => arguments.exat = arg.parse::<i64>().ok(),
-                                "PXAT" => arguments.pxat = arg.parse::<i64>().ok(),
-                                _ => {}
-                            }
-
-                            continue;
-                        }
-
-                        return value_error!("Invalid argument {arg}")
-                            .to_resp2(writer)
-                            .await;
-                    }
-                },
-                _ => {
-                    return value_error!("Invalid argument").to_resp2(writer).await;
-                }
-            }
-        }
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let value = args.next()?.to_vec();
+        let options = args.options(OPTIONS)?;
+
+        let get = options.has("GET");
+
+        let deadline = options.deadline(self.name())?;
 
         let mut lock = session.state.store.write().await;
 
-        let prev_key = lock.entry(key.clone()).or_insert(Value::Nil.clone());
+        let prev = lock.get(&key);
 
-        if arguments.set_nx && prev_key.is_some() {
-            return Value::Nil.to_resp2(writer).await;
+        if options.has("NX") && prev.is_some() {
+            return Ok(Frame::Nil);
         }
 
-        if arguments.set_xx && prev_key.is_none() {
-            return Value::Nil.to_resp2(writer).await;
+        if options.has("XX") && prev.is_none() {
+            return Ok(Frame::Nil);
         }
 
-        let expire_at = if arguments.keepttl {
-            match prev_key {
-                Value::Expire((_, expire_at)) => Some(*expire_at),
-                _ => None,
-            }
-        } else {
-            match (arguments.ex, arguments.px, arguments.exat, arguments.pxat) {
-                (Some(ex), _, _, _) => Some(Instant::now() + Duration::from_secs(ex as u64)),
-                (_, Some(px), _, _) => Some(Instant::now() + Duration::from_millis(px as u64)),
-                // SAFETY: `SystemTime::now()` is always after `SystemTime::UNIX_EPOCH`. If it's not
-                // YOU HAVE A BIGGER PROBLEM
-                (_, _, Some(exat), _) => Some(
-                    Instant::now() + Duration::from_secs(exat as u64)
-                        - SystemTime::now()
-                            .duration_since(SystemTime::UNIX_EPOCH)
-                            .expect("SystemTime before UNIX_EPOCH"),
-                ),
-                (_, _, _, Some(pxat)) => Some(
-                    Instant::now() + Duration::from_millis(pxat as u64)
-                        - SystemTime::now()
-                            .duration_since(SystemTime::UNIX_EPOCH)
-                            .expect("SystemTime before UNIX_EPOCH"),
-                ),
-                _ => None,
-            }
+        let prev_value = match prev {
+            Some(Entry {
+                value: Value::String(prev),
+                ..
+            }) => Frame::Bulk(prev.to_vec()),
+            // GET only works with string values, without it the old value is simply replaced
+            Some(_) if get => return Ok(Frame::wrong_type()),
+            _ => Frame::Nil,
         };
 
-        if arguments.get {
-            prev_key.to_resp2(writer).await?;
+        let expire_at = if options.has("KEEPTTL") {
+            prev.and_then(|entry| entry.expire_at)
         } else {
-            Value::Ok.to_resp2(writer).await?;
-        }
+            deadline
+        };
 
-        *prev_key = if let Some(expire_at) = expire_at {
-            session.state.expire_keys.write().await.insert(key);
-            Value::Expire((Box::new(value), expire_at))
+        // a deadline in the past deletes the key
+        let entry = if let Some(expire_at) = expire_at {
+            Entry::with_expire(Value::from(value), expire_at)
         } else {
-            value
+            Entry::new(Value::from(value))
         };
 
-        Ok(())
+        lock.insert(key, entry);
+
+        if get {
+            Ok(prev_value)
+        } else {
+            Ok(Frame::Ok)
+        }
     }
 }
@@ -1,4 +1,6 @@
+use crate::commands::args::{Opt, TimeUnit};
 use crate::prelude::*;
+use crate::utils::time::unix_millis;
 
 pub struct Expire;
 
@@ -8,59 +10,169 @@ impl CommandTrait for Expire {
         "EXPIRE"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
-        if args.len() != 2 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
+    fn arity(&self) -> i64 {
+        -3
+    }
 
-        let key = match args.pop_front() {
-            Some(Value::String(key)) => key,
-            Some(_) => {
-                return value_error!("Invalid key").to_resp2(writer).await;
-            }
-            None => {
-                return value_error!("Missing key").to_resp2(writer).await;
-            }
-        };
-
-        let seconds = match args.pop_front() {
-            Some(Value::Integer(seconds)) => seconds,
-            Some(Value::String(seconds)) => seconds.parse::<i64>().unwrap_or(-1),
-            Some(_) => {
-                return value_error!("Invalid seconds").to_resp2(writer).await;
-            }
-            None => {
-                return value_error!("Missing seconds").to_resp2(writer).await;
-            }
-        };
-
-        if seconds < 0 {
-            return value_error!("Invalid seconds").to_resp2(writer).await;
-        }
+    fn group(&self) -> &str {
+        "generic"
+    }
+
+    fn summary(&self) -> &str {
+        "Sets the expiration time of a key in seconds."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
 
-        let mut store = session.state.store.write().await;
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        expire(self.name(), args, session, TimeUnit::Seconds, false).await
+    }
+}
+
+const OPTIONS: &[Opt] = &[
+    Opt::flag("NX", 0),
+    Opt::flag("XX", 0),
+    Opt::flag("GT", 0),
+    Opt::flag("LT", 0),
+];
 
-        let value = match store.get_mut(&key) {
-            Some(Value::Expire((inner, _))) => inner,
-            Some(inner) => inner,
-            _ => {
-                return value_error!("Key not found").to_resp2(writer).await;
-            }
-        };
+/// Shared by `EXPIRE`, `PEXPIRE`, `EXPIREAT` and `PEXPIREAT`: set the expire
+/// time of a key if the `NX | XX | GT | LT` condition holds.
+pub(super) async fn expire(
+    command: &str,
+    args: &mut Args,
+    session: SessionRef,
+    unit: TimeUnit,
+    absolute: bool,
+) -> Result<Frame> {
+    let key = args.next()?;
+    let time = args.next_int()?;
+    let options = args.options(OPTIONS)?;
+
+    let (nx, xx, gt, lt) = (
+        options.has("NX"),
+        options.has("XX"),
+        options.has("GT"),
+        options.has("LT"),
+    );
+
+    if nx && (xx || gt || lt) {
+        return Ok(value_error!(
+            "NX and XX, GT or LT options at the same time are not compatible"
+        ));
+    }
 
-        *value = Value::Expire((
-            Box::new(value.to_owned()),
-            tokio::time::Instant::now() + tokio::time::Duration::from_secs(seconds as u64),
+    if gt && lt {
+        return Ok(value_error!(
+            "GT and LT options at the same time are not compatible"
         ));
-        session.state.expire_keys.write().await.insert(key);
+    }
+
+    let base = if absolute { 0 } else { unix_millis() };
+
+    let expire_at = unit
+        .to_millis(time)
+        .and_then(|millis| millis.checked_add(base))
+        .ok_or_else(|| ReplyError::invalid_expire_time(command))?;
+
+    let mut store = session.state.store.write().await;
+
+    let Some(entry) = store.get(&key) else {
+        return Ok(Frame::Integer(0));
+    };
+
+    // a key without an expire time never expires, so it counts as the largest ttl
+    let allowed = match entry.expire_at {
+        None => !(xx || gt),
+        Some(current) => !nx && (!gt || expire_at > current) && (!lt || expire_at < current),
+    };
+
+    if !allowed {
+        return Ok(Frame::Integer(0));
+    }
+
+    // a deadline in the past deletes the key
+    store.set_expire(&key, Some(expire_at));
+
+    Ok(Frame::Integer(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_expire_conditions() {
+        let session = session().await;
+
+        run(&session, "SET k v").await;
+
+        assert_eq!(run(&session, "EXPIRE missing 100").await, Frame::Integer(0));
+
+        // without a ttl XX and GT fail, LT succeeds since no ttl counts as infinite
+        assert_eq!(run(&session, "EXPIRE k 100 XX").await, Frame::Integer(0));
+        assert_eq!(run(&session, "EXPIRE k 100 GT").await, Frame::Integer(0));
+        assert_eq!(run(&session, "TTL k").await, Frame::Integer(-1));
+        assert_eq!(run(&session, "EXPIRE k 100 LT").await, Frame::Integer(1));
+
+        assert_eq!(run(&session, "EXPIRE k 200 NX").await, Frame::Integer(0));
+        assert_eq!(run(&session, "EXPIRE k 50 GT").await, Frame::Integer(0));
+        assert_eq!(run(&session, "EXPIRE k 200 GT").await, Frame::Integer(1));
+        assert_eq!(run(&session, "EXPIRE k 300 LT").await, Frame::Integer(0));
+        assert_eq!(run(&session, "EXPIRE k 150 XX LT").await, Frame::Integer(1));
+        assert_eq!(run(&session, "TTL k").await, Frame::Integer(150));
+
+        run(&session, "PERSIST k").await;
+        assert_eq!(run(&session, "PEXPIRE k 5000 NX").await, Frame::Integer(1));
+        assert!(matches!(
+            run(&session, "PTTL k").await,
+            Frame::Integer(4900..=5000)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_expire_incompatible_options() {
+        let session = session().await;
+
+        run(&session, "SET k v").await;
+
+        for options in ["NX XX", "NX GT", "NX LT"] {
+            assert_eq!(
+                run(&session, &format!("EXPIRE k 100 {options}")).await,
+                value_error!("NX and XX, GT or LT options at the same time are not compatible")
+            );
+        }
+
+        assert_eq!(
+            run(&session, "EXPIRE k 100 GT LT").await,
+            value_error!("GT and LT options at the same time are not compatible")
+        );
+        assert_eq!(run(&session, "TTL k").await, Frame::Integer(-1));
+    }
+
+    #[tokio::test]
+    async fn test_expire_in_the_past() {
+        let session = session().await;
+
+        run(&session, "SET a v").await;
+        run(&session, "SET b v").await;
+        run(&session, "SET c v").await;
 
-        Value::Ok.to_resp2(writer).await
+        // a deadline that already passed deletes the key
+        assert_eq!(run(&session, "EXPIRE a -1").await, Frame::Integer(1));
+        assert_eq!(run(&session, "PEXPIREAT b 1").await, Frame::Integer(1));
+        assert_eq!(run(&session, "EXPIREAT c 0 NX").await, Frame::Integer(1));
+        assert_eq!(run(&session, "EXISTS a b c").await, Frame::Integer(0));
     }
 }
@@ -0,0 +1,40 @@
+use super::ttl::ttl;
+use crate::commands::args::TimeUnit;
+use crate::prelude::*;
+
+pub struct ExpireTime;
+
+#[async_trait]
+impl CommandTrait for ExpireTime {
+    fn name(&self) -> &str {
+        "EXPIRETIME"
+    }
+
+    fn arity(&self) -> i64 {
+        2
+    }
+
+    fn group(&self) -> &str {
+        "generic"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the expiration time of a key as a Unix timestamp."
+    }
+
+    fn since(&self) -> &str {
+        "7.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        ttl(args, session, TimeUnit::Seconds, true).await
+    }
+}
@@ -1,7 +1,24 @@
+#[allow(clippy::module_inception)]
 mod expire;
+mod expireat;
+mod expiretime;
+mod persist;
+mod pexpire;
+mod pexpireat;
+mod pexpiretime;
+mod psetex;
+mod pttl;
 mod setex;
 mod ttl;
 
 pub use expire::Expire;
+pub use expireat::ExpireAt;
+pub use expiretime::ExpireTime;
+pub use persist::Persist;
+pub use pexpire::PExpire;
+pub use pexpireat::PExpireAt;
+pub use pexpiretime::PExpireTime;
+pub use psetex::PSetEx;
+pub use pttl::PTtl;
 pub use setex::SetEx;
 pub use ttl::Ttl;
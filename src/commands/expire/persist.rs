@@ -0,0 +1,49 @@
+use crate::prelude::*;
+
+pub struct Persist;
+
+#[async_trait]
+impl CommandTrait for Persist {
+    fn name(&self) -> &str {
+        "PERSIST"
+    }
+
+    fn arity(&self) -> i64 {
+        2
+    }
+
+    fn group(&self) -> &str {
+        "generic"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes the expiration time of a key."
+    }
+
+    fn since(&self) -> &str {
+        "2.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let mut store = session.state.store.write().await;
+
+        // only a key that had an expire time counts
+        if store.get(&key).and_then(|entry| entry.expire_at).is_none() {
+            return Ok(Frame::Integer(0));
+        }
+
+        store.set_expire(&key, None);
+
+        Ok(Frame::Integer(1))
+    }
+}
@@ -0,0 +1,40 @@
+use super::expire::expire;
+use crate::commands::args::TimeUnit;
+use crate::prelude::*;
+
+pub struct PExpireAt;
+
+#[async_trait]
+impl CommandTrait for PExpireAt {
+    fn name(&self) -> &str {
+        "PEXPIREAT"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "generic"
+    }
+
+    fn summary(&self) -> &str {
+        "Sets the expiration time of a key to a Unix milliseconds timestamp."
+    }
+
+    fn since(&self) -> &str {
+        "2.6.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        expire(self.name(), args, session, TimeUnit::Millis, true).await
+    }
+}
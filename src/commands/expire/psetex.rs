@@ -0,0 +1,72 @@
+use super::setex::setex;
+use crate::commands::args::TimeUnit;
+use crate::prelude::*;
+
+pub struct PSetEx;
+
+#[async_trait]
+impl CommandTrait for PSetEx {
+    fn name(&self) -> &str {
+        "PSETEX"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Sets both string value and expiration time in milliseconds of a key. The key is created if it doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "2.6.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        setex(self.name(), args, session, TimeUnit::Millis).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_psetex() {
+        let session = session().await;
+
+        assert_eq!(run(&session, "PSETEX k 5000 v").await, Frame::Ok);
+        assert_eq!(run(&session, "GET k").await, Frame::from("v"));
+        assert!(matches!(
+            run(&session, "PTTL k").await,
+            Frame::Integer(4900..=5000)
+        ));
+
+        // the old value stays when the time is invalid
+        for ttl in ["0", "-5", "abc"] {
+            assert!(matches!(
+                run(&session, &format!("PSETEX k {ttl} w")).await,
+                Frame::Error(_)
+            ));
+        }
+
+        assert_eq!(
+            run(&session, "PSETEX k 0 w").await,
+            Frame::from(ReplyError::invalid_expire_time("psetex"))
+        );
+        assert_eq!(run(&session, "GET k").await, Frame::from("v"));
+    }
+}
@@ -0,0 +1,40 @@
+use super::ttl::ttl;
+use crate::commands::args::TimeUnit;
+use crate::prelude::*;
+
+pub struct PTtl;
+
+#[async_trait]
+impl CommandTrait for PTtl {
+    fn name(&self) -> &str {
+        "PTTL"
+    }
+
+    fn arity(&self) -> i64 {
+        2
+    }
+
+    fn group(&self) -> &str {
+        "generic"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the expiration time in milliseconds of a key."
+    }
+
+    fn since(&self) -> &str {
+        "2.6.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        ttl(args, session, TimeUnit::Millis, false).await
+    }
+}
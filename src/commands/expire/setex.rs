@@ -1,4 +1,6 @@
+use crate::commands::args::TimeUnit;
 use crate::prelude::*;
+use crate::utils::time::unix_millis;
 
 pub struct SetEx;
 
@@ -8,59 +10,55 @@ impl CommandTrait for SetEx {
         "SETEX"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
-        if args.len() != 3 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
+    fn arity(&self) -> i64 {
+        4
+    }
 
-        let key = match args.pop_front() {
-            Some(Value::String(key)) => key,
-            Some(_) => {
-                return value_error!("Invalid key").to_resp2(writer).await;
-            }
-            None => {
-                return value_error!("Missing key").to_resp2(writer).await;
-            }
-        };
+    fn group(&self) -> &str {
+        "string"
+    }
 
-        let seconds = match args.pop_front() {
-            Some(Value::Integer(seconds)) => seconds,
-            Some(Value::String(seconds)) => seconds.parse::<i64>().unwrap_or(-1),
-            Some(_) => {
-                return value_error!("Invalid seconds").to_resp2(writer).await;
-            }
-            None => {
-                return value_error!("Missing seconds").to_resp2(writer).await;
-            }
-        };
+    fn summary(&self) -> &str {
+        "Sets the string value and expiration time of a key. Creates the key if it doesn't exist."
+    }
 
-        if seconds < 0 {
-            return value_error!("Invalid seconds").to_resp2(writer).await;
-        }
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
 
-        let value = match args.pop_front() {
-            Some(value) => value,
-            _ => {
-                return value_error!("Missing value").to_resp2(writer).await;
-            }
-        };
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
 
-        session.state.store.write().await.insert(
-            key.clone(),
-            Value::Expire((
-                Box::new(value),
-                tokio::time::Instant::now() + tokio::time::Duration::from_secs(seconds as u64),
-            )),
-        );
-        session.state.expire_keys.write().await.insert(key);
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
 
-        Value::Ok.to_resp2(writer).await
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        setex(self.name(), args, session, TimeUnit::Seconds).await
     }
 }
+
+/// Shared by `SETEX` and `PSETEX`.
+pub(super) async fn setex(
+    command: &str,
+    args: &mut Args,
+    session: SessionRef,
+    unit: TimeUnit,
+) -> Result<Frame> {
+    let key = args.next()?.to_vec();
+    let expire_at = args
+        .next_ttl(unit)?
+        .checked_add(unix_millis())
+        .ok_or_else(|| ReplyError::invalid_expire_time(command))?;
+    let value = args.next()?.to_vec();
+
+    session
+        .state
+        .store
+        .write()
+        .await
+        .insert(key, Entry::with_expire(Value::from(value), expire_at));
+
+    Ok(Frame::Ok)
+}
@@ -1,4 +1,6 @@
+use crate::commands::args::TimeUnit;
 use crate::prelude::*;
+use crate::utils::time::unix_millis;
 
 pub struct Ttl;
 
@@ -8,48 +10,68 @@ impl CommandTrait for Ttl {
         "TTL"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
-        if args.len() != 1 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
+    fn arity(&self) -> i64 {
+        2
+    }
 
-        let key = match args.pop_front() {
-            Some(Value::String(key)) => key,
-            Some(_) => {
-                return value_error!("Invalid key").to_resp2(writer).await;
-            }
-            None => {
-                return value_error!("Missing key").to_resp2(writer).await;
-            }
-        };
+    fn group(&self) -> &str {
+        "generic"
+    }
 
-        let store = session.state.store.read().await;
+    fn summary(&self) -> &str {
+        "Returns the expiration time in seconds of a key."
+    }
 
-        let duration = match store.get(&key) {
-            Some(Value::Expire((_, ttl))) => {
-                let duration = ttl.duration_since(tokio::time::Instant::now()).as_secs() as i64;
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
 
-                if duration != 0 {
-                    duration
-                } else {
-                    -2
-                }
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        ttl(args, session, TimeUnit::Seconds, false).await
+    }
+}
+
+/// Shared by `TTL`, `PTTL`, `EXPIRETIME` and `PEXPIRETIME`: -2 for a missing
+/// key, -1 for a key without an expire time.
+pub(super) async fn ttl(
+    args: &mut Args,
+    session: SessionRef,
+    unit: TimeUnit,
+    absolute: bool,
+) -> Result<Frame> {
+    let key = args.next()?;
+
+    let store = session.state.store.read().await;
+
+    let reply = match store.get(&key).map(|entry| entry.expire_at) {
+        Some(Some(expire_at)) => {
+            let millis = if absolute {
+                expire_at
+            } else {
+                (expire_at - unix_millis()).max(0)
+            };
+
+            match unit {
+                // rounded to the nearest second
+                TimeUnit::Seconds => (millis + 500) / 1000,
+                TimeUnit::Millis => millis,
             }
+        }
 
-            // non-expire keys should return -1
-            Some(_) => -1,
+        // non-expire keys should return -1
+        Some(None) => -1,
 
-            // not found keys should return -2
-            None => -2,
-        };
+        // not found keys should return -2
+        None => -2,
+    };
 
-        Value::Integer(duration).to_resp2(writer).await
-    }
+    Ok(Frame::Integer(reply))
 }
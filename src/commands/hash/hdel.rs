@@ -0,0 +1,81 @@
+use crate::prelude::*;
+
+pub struct HDel;
+
+#[async_trait]
+impl CommandTrait for HDel {
+    fn name(&self) -> &str {
+        "HDEL"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Deletes one or more fields and their values from a hash. Deletes the hash if no fields remain."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let mut store = session.state.store.write().await;
+
+        let fields = match store.get_mut(&key) {
+            Some(Entry {
+                value: Value::Hash(fields),
+                ..
+            }) => fields,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => return Ok(Frame::Integer(0)),
+        };
+
+        let removed = args
+            .rest()
+            .filter(|field| fields.remove(&field[..]).is_some())
+            .count();
+
+        if fields.is_empty() {
+            store.remove(&key);
+        }
+
+        Ok(Frame::Integer(removed as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_hdel() {
+        let session = session().await;
+
+        run(&session, "HSET h a 1 b 2").await;
+
+        assert_eq!(run(&session, "HDEL h a x").await, Frame::Integer(1));
+        assert_eq!(run(&session, "EXISTS h").await, Frame::Integer(1));
+
+        // removing the last field deletes the key
+        assert_eq!(run(&session, "HDEL h b a").await, Frame::Integer(1));
+        assert_eq!(run(&session, "EXISTS h").await, Frame::Integer(0));
+        assert_eq!(run(&session, "HDEL h b").await, Frame::Integer(0));
+    }
+}
@@ -0,0 +1,50 @@
+use crate::prelude::*;
+
+pub struct HExists;
+
+#[async_trait]
+impl CommandTrait for HExists {
+    fn name(&self) -> &str {
+        "HEXISTS"
+    }
+
+    fn arity(&self) -> i64 {
+        3
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Determines whether a field exists in a hash."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let field = args.next()?;
+
+        let store = session.state.store.read().await;
+
+        match store.get(&key) {
+            Some(Entry {
+                value: Value::Hash(fields),
+                ..
+            }) => Ok(Frame::Integer(fields.contains_key(&field[..]).into())),
+            Some(_) => Ok(Frame::wrong_type()),
+            None => Ok(Frame::Integer(0)),
+        }
+    }
+}
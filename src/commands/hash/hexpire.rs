@@ -0,0 +1,290 @@
+use crate::commands::args::{parse_int, Opt, TimeUnit};
+use crate::prelude::*;
+use crate::storage::db::Db;
+use crate::utils::time::unix_millis;
+
+pub struct HExpire;
+
+#[async_trait]
+impl CommandTrait for HExpire {
+    fn name(&self) -> &str {
+        "HEXPIRE"
+    }
+
+    fn arity(&self) -> i64 {
+        -6
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Set expiry for hash field using relative time to expire (seconds)"
+    }
+
+    fn since(&self) -> &str {
+        "7.4.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        hexpire(self.name(), args, session, TimeUnit::Seconds, false).await
+    }
+}
+
+const OPTIONS: &[Opt] = &[
+    Opt::flag("NX", 1),
+    Opt::flag("XX", 1),
+    Opt::flag("GT", 1),
+    Opt::flag("LT", 1),
+];
+
+/// Shared by `HEXPIRE`, `HPEXPIRE`, `HEXPIREAT` and `HPEXPIREAT`: set the
+/// expire time of each field if the `NX | XX | GT | LT` condition holds.
+///
+/// Replies a status per field: -2 if there's no such field, 0 if the
+/// condition didn't hold, 1 if the expire time was set and 2 if a deadline
+/// in the past deleted the field.
+pub(super) async fn hexpire(
+    command: &str,
+    args: &mut Args,
+    session: SessionRef,
+    unit: TimeUnit,
+    absolute: bool,
+) -> Result<Frame> {
+    let key = args.next()?;
+    let time = args.next_int()?;
+    let options = args.options_before(OPTIONS, Some("FIELDS"))?;
+    let fields = parse_fields(args, 1)?;
+
+    if time < 0 {
+        return Ok(value_error!("invalid expire time, must be >= 0"));
+    }
+
+    let (nx, xx, gt, lt) = (
+        options.has("NX"),
+        options.has("XX"),
+        options.has("GT"),
+        options.has("LT"),
+    );
+
+    let base = if absolute { 0 } else { unix_millis() };
+
+    let expire_at = unit
+        .to_millis(time)
+        .and_then(|millis| millis.checked_add(base))
+        .ok_or_else(|| ReplyError::invalid_expire_time(command))?;
+
+    let mut store = session.state.store.write().await;
+
+    let hash = match store.get_mut(&key) {
+        Some(Entry {
+            value: Value::Hash(hash),
+            ..
+        }) => hash,
+        Some(_) => return Ok(Frame::wrong_type()),
+        None => {
+            return Ok(Frame::Array(
+                fields.iter().map(|_| Frame::Integer(-2)).collect(),
+            ))
+        }
+    };
+
+    let mut reply = VecDeque::with_capacity(fields.len());
+
+    for field in &fields {
+        // a field without an expire time never expires, so it counts as the largest ttl
+        let allowed = match hash.expire_at(field) {
+            None => {
+                reply.push_back(Frame::Integer(-2));
+                continue;
+            }
+            Some(None) => !(xx || gt),
+            Some(Some(current)) => {
+                !nx && (!gt || expire_at > current) && (!lt || expire_at < current)
+            }
+        };
+
+        let status = match allowed {
+            false => 0,
+            true if expire_at <= unix_millis() => {
+                hash.remove(field);
+                2
+            }
+            true => {
+                hash.set_expire(field, Some(expire_at));
+                1
+            }
+        };
+
+        reply.push_back(Frame::Integer(status));
+    }
+
+    settle(&mut store, &key);
+
+    Ok(Frame::Array(reply))
+}
+
+/// Parse the trailing `FIELDS numfields field [field ...]`, with `width`
+/// arguments per field. Returns the arguments after `numfields`.
+pub(super) fn parse_fields(args: &mut Args, width: usize) -> Result<Vec<Bytes>> {
+    if !args.next_value()?.eq_ignore_ascii_case(b"FIELDS") {
+        return Err(ReplyError::Generic(
+            "mandatory argument FIELDS is missing or not at the right position".into(),
+        )
+        .into());
+    }
+
+    let numfields = parse_int(&args.next_value()?)
+        .ok()
+        .and_then(|numfields| usize::try_from(numfields).ok())
+        .filter(|numfields| *numfields > 0);
+
+    let Some(numfields) = numfields else {
+        return Err(
+            ReplyError::Generic("Parameter `numFields` should be greater than 0".into()).into(),
+        );
+    };
+
+    if numfields.checked_mul(width) != Some(args.len()) {
+        return Err(ReplyError::Generic(
+            "The `numfields` parameter must match the number of arguments".into(),
+        )
+        .into());
+    }
+
+    Ok(args.rest().collect())
+}
+
+/// Bring the keyspace up to date after field expire times changed: a hash
+/// left without fields is deleted, otherwise its next deadline is announced
+/// to the active expire cycle.
+pub(super) fn settle(store: &mut Db, key: &[u8]) {
+    if store.expire_if_needed(key) {
+        return;
+    }
+
+    if let Some(Entry {
+        value: Value::Hash(hash),
+        ..
+    }) = store.get(key)
+    {
+        if let Some(next) = hash.next_expire() {
+            store.track_field_expire(key, next);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{ints, run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_hexpire() {
+        let session = session().await;
+
+        run(&session, "HSET h a 1 b 2 c 3").await;
+
+        // -2 for a missing field, 1 once set, 2 when a deadline in the past deletes it
+        assert_eq!(
+            run(&session, "HEXPIRE h 100 FIELDS 2 a x").await,
+            ints(&[1, -2])
+        );
+        assert_eq!(run(&session, "HTTL h FIELDS 2 a b").await, ints(&[100, -1]));
+        assert_eq!(run(&session, "HEXPIRE h 0 FIELDS 1 b").await, ints(&[2]));
+        assert_eq!(run(&session, "HEXISTS h b").await, Frame::Integer(0));
+        assert_eq!(
+            run(&session, "HEXPIRE missing 100 FIELDS 2 a b").await,
+            ints(&[-2, -2])
+        );
+
+        // the last field going away takes the hash with it
+        assert_eq!(
+            run(&session, "HPEXPIREAT h 1 FIELDS 2 a c").await,
+            ints(&[2, 2])
+        );
+        assert_eq!(run(&session, "EXISTS h").await, Frame::Integer(0));
+    }
+
+    #[tokio::test]
+    async fn test_hexpire_conditions() {
+        let session = session().await;
+
+        run(&session, "HSET h a 1 b 2").await;
+        run(&session, "HEXPIRE h 100 FIELDS 1 a").await;
+
+        // 0 when the condition doesn't hold, no expire time counts as the largest
+        assert_eq!(
+            run(&session, "HEXPIRE h 50 NX FIELDS 2 a b").await,
+            ints(&[0, 1])
+        );
+        assert_eq!(run(&session, "HPERSIST h FIELDS 1 b").await, ints(&[1]));
+        assert_eq!(
+            run(&session, "HEXPIRE h 200 XX FIELDS 2 a b").await,
+            ints(&[1, 0])
+        );
+        assert_eq!(
+            run(&session, "HEXPIRE h 100 GT FIELDS 2 a b").await,
+            ints(&[0, 0])
+        );
+        assert_eq!(
+            run(&session, "HEXPIRE h 300 GT FIELDS 2 a b").await,
+            ints(&[1, 0])
+        );
+        assert_eq!(
+            run(&session, "HEXPIRE h 400 LT FIELDS 2 a b").await,
+            ints(&[0, 1])
+        );
+        assert_eq!(
+            run(&session, "HEXPIRE h 100 LT FIELDS 2 a b").await,
+            ints(&[1, 1])
+        );
+        assert_eq!(
+            run(&session, "HTTL h FIELDS 2 a b").await,
+            ints(&[100, 100])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hexpire_errors() {
+        let session = session().await;
+
+        run(&session, "HSET h a 1").await;
+        run(&session, "SET text abc").await;
+
+        assert_eq!(
+            run(&session, "HEXPIRE h 100 NX XX FIELDS 1 a").await,
+            Frame::from(ReplyError::Syntax)
+        );
+        assert_eq!(
+            run(&session, "HEXPIRE h -1 FIELDS 1 a").await,
+            value_error!("invalid expire time, must be >= 0")
+        );
+        assert_eq!(
+            run(&session, "HEXPIRE h 100 FIELDS 2 a").await,
+            Frame::from(ReplyError::Generic(
+                "The `numfields` parameter must match the number of arguments".into()
+            ))
+        );
+        assert_eq!(
+            run(&session, "HEXPIRE h 100 FIELDS 0 a").await,
+            Frame::from(ReplyError::Generic(
+                "Parameter `numFields` should be greater than 0".into()
+            ))
+        );
+        assert_eq!(
+            run(&session, "HEXPIRE text 100 FIELDS 1 a").await,
+            Frame::wrong_type()
+        );
+        assert_eq!(run(&session, "HTTL h FIELDS 1 a").await, ints(&[-1]));
+    }
+}
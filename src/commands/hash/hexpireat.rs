@@ -0,0 +1,40 @@
+use super::hexpire::hexpire;
+use crate::commands::args::TimeUnit;
+use crate::prelude::*;
+
+pub struct HExpireAt;
+
+#[async_trait]
+impl CommandTrait for HExpireAt {
+    fn name(&self) -> &str {
+        "HEXPIREAT"
+    }
+
+    fn arity(&self) -> i64 {
+        -6
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Set expiry for hash field using an absolute Unix timestamp (seconds)"
+    }
+
+    fn since(&self) -> &str {
+        "7.4.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        hexpire(self.name(), args, session, TimeUnit::Seconds, true).await
+    }
+}
@@ -0,0 +1,40 @@
+use super::httl::httl;
+use crate::commands::args::TimeUnit;
+use crate::prelude::*;
+
+pub struct HExpireTime;
+
+#[async_trait]
+impl CommandTrait for HExpireTime {
+    fn name(&self) -> &str {
+        "HEXPIRETIME"
+    }
+
+    fn arity(&self) -> i64 {
+        -5
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the expiration time of a hash field as a Unix timestamp, in seconds."
+    }
+
+    fn since(&self) -> &str {
+        "7.4.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        httl(args, session, TimeUnit::Seconds, true).await
+    }
+}
@@ -0,0 +1,53 @@
+use crate::prelude::*;
+
+pub struct HGet;
+
+#[async_trait]
+impl CommandTrait for HGet {
+    fn name(&self) -> &str {
+        "HGET"
+    }
+
+    fn arity(&self) -> i64 {
+        3
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the value of a field in a hash."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let field = args.next()?;
+
+        let store = session.state.store.read().await;
+
+        match store.get(&key) {
+            Some(Entry {
+                value: Value::Hash(fields),
+                ..
+            }) => Ok(fields
+                .get(&field[..])
+                .cloned()
+                .map_or(Frame::Nil, Frame::Bulk)),
+            Some(_) => Ok(Frame::wrong_type()),
+            None => Ok(Frame::Nil),
+        }
+    }
+}
@@ -8,36 +8,49 @@ impl CommandTrait for HGetAll {
         "HGETALL"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
-        if args.len() != 1 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
-
-        let key = match args.pop_front() {
-            Some(Value::String(key)) => key,
-            Some(_) => {
-                return value_error!("Invalid key").to_resp2(writer).await;
-            }
-            None => {
-                return value_error!("Missing key").to_resp2(writer).await;
-            }
-        };
+    fn arity(&self) -> i64 {
+        2
+    }
 
-        let store = session.state.store.read().await;
+    fn group(&self) -> &str {
+        "hash"
+    }
 
-        match store.get(&key) {
-            Some(value @ Value::Map(_)) => session.versioned_response(value, writer).await,
+    fn summary(&self) -> &str {
+        "Returns all fields and values in a hash."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
 
-            Some(_) => value_error!("Key is not a hashmap").to_resp2(writer).await,
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let store = session.state.store.read().await;
+
+        let fields = match store.get(key.as_ref()) {
+            Some(Entry {
+                value: Value::Hash(fields),
+                ..
+            }) => fields
+                .iter()
+                .map(|(field, value)| {
+                    (Frame::from(field.as_slice()), Frame::from(value.as_slice()))
+                })
+                .collect(),
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => Vec::new(),
+        };
 
-            None => session.versioned_response(&Value::Nil, writer).await,
-        }
+        Ok(Frame::Map(fields))
     }
 }
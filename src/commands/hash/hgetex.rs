@@ -0,0 +1,136 @@
+use super::hexpire::{parse_fields, settle};
+use crate::commands::args::{Opt, OptKind, TimeUnit};
+use crate::prelude::*;
+
+pub struct HGetEx;
+
+#[async_trait]
+impl CommandTrait for HGetEx {
+    fn name(&self) -> &str {
+        "HGETEX"
+    }
+
+    fn arity(&self) -> i64 {
+        -5
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Get the value of one or more fields of a given hash key, and optionally set their expiration."
+    }
+
+    fn since(&self) -> &str {
+        "8.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let options = args.options_before(OPTIONS, Some("FIELDS"))?;
+        let fields = parse_fields(args, 1)?;
+
+        let deadline = options.deadline(self.name())?;
+
+        let mut store = session.state.store.write().await;
+
+        let hash = match store.get_mut(&key) {
+            Some(Entry {
+                value: Value::Hash(hash),
+                ..
+            }) => hash,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => return Ok(Frame::Array(fields.iter().map(|_| Frame::Nil).collect())),
+        };
+
+        let mut reply = VecDeque::with_capacity(fields.len());
+
+        for field in &fields {
+            let value = hash.get(field).cloned();
+
+            // without an option this is a plain HMGET, a deadline in the past deletes the field
+            if value.is_some() {
+                if deadline.is_some() {
+                    hash.set_expire(field, deadline);
+                } else if options.has("PERSIST") {
+                    hash.set_expire(field, None);
+                }
+            }
+
+            reply.push_back(value.map_or(Frame::Nil, Frame::Bulk));
+        }
+
+        settle(&mut store, &key);
+
+        Ok(Frame::Array(reply))
+    }
+}
+
+const OPTIONS: &[Opt] = &[
+    Opt::new("EX", OptKind::Ttl(TimeUnit::Seconds), 1),
+    Opt::new("PX", OptKind::Ttl(TimeUnit::Millis), 1),
+    Opt::new("EXAT", OptKind::Ttl(TimeUnit::Seconds), 1),
+    Opt::new("PXAT", OptKind::Ttl(TimeUnit::Millis), 1),
+    Opt::flag("PERSIST", 1),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{ints, run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_hgetex() {
+        let session = session().await;
+
+        run(&session, "HSET h a 1 b 2").await;
+
+        // without an option this is a plain HMGET
+        assert_eq!(
+            run(&session, "HGETEX h FIELDS 2 a x").await,
+            Frame::Array(VecDeque::from([Frame::from("1"), Frame::Nil]))
+        );
+        assert_eq!(run(&session, "HTTL h FIELDS 1 a").await, ints(&[-1]));
+
+        assert_eq!(
+            run(&session, "HGETEX h EX 100 FIELDS 2 a b").await,
+            Frame::Array(VecDeque::from([Frame::from("1"), Frame::from("2")]))
+        );
+        assert_eq!(
+            run(&session, "HTTL h FIELDS 2 a b").await,
+            ints(&[100, 100])
+        );
+
+        // PERSIST clears the expire time of the fields given
+        assert_eq!(
+            run(&session, "HGETEX h PERSIST FIELDS 1 a").await,
+            Frame::Array(VecDeque::from([Frame::from("1")]))
+        );
+        assert_eq!(run(&session, "HTTL h FIELDS 2 a b").await, ints(&[-1, 100]));
+
+        // a deadline in the past deletes the fields after handing them out
+        assert_eq!(
+            run(&session, "HGETEX h PXAT 1 FIELDS 2 a b").await,
+            Frame::Array(VecDeque::from([Frame::from("1"), Frame::from("2")]))
+        );
+        assert_eq!(run(&session, "EXISTS h").await, Frame::Integer(0));
+        assert_eq!(
+            run(&session, "HGETEX h PERSIST FIELDS 1 a").await,
+            Frame::Array(VecDeque::from([Frame::Nil]))
+        );
+
+        assert_eq!(
+            run(&session, "HGETEX h EX 100 PERSIST FIELDS 1 a").await,
+            Frame::from(ReplyError::Syntax)
+        );
+    }
+}
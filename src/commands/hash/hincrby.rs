@@ -0,0 +1,108 @@
+use crate::prelude::*;
+use crate::storage::hash::HashValue;
+use crate::utils::number::parse_integer;
+
+pub struct HIncrBy;
+
+#[async_trait]
+impl CommandTrait for HIncrBy {
+    fn name(&self) -> &str {
+        "HINCRBY"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Increments the integer value of a field in a hash by a number. Uses 0 as initial value if the field doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let field = args.next()?.to_vec();
+        let increment = args.next_int()?;
+
+        let mut store = session.state.store.write().await;
+
+        let entry = store.get_or_insert_with(key, || Entry::new(Value::Hash(HashValue::new())));
+
+        let Value::Hash(fields) = &mut entry.value else {
+            return Ok(Frame::wrong_type());
+        };
+
+        let current = match fields.get(&field) {
+            Some(value) => match parse_integer(value) {
+                Some(current) => current,
+                None => return Ok(value_error!("hash value is not an integer")),
+            },
+            None => 0,
+        };
+
+        // starting from 0 can't overflow, so a new hash is never left empty
+        let Some(next) = current.checked_add(increment) else {
+            return Ok(value_error!("increment or decrement would overflow"));
+        };
+
+        let text = next.to_string().into_bytes();
+
+        // an existing field keeps its expire time
+        match fields.get_mut(&field) {
+            Some(value) => *value = text,
+            None => {
+                fields.insert(field, text);
+            }
+        }
+
+        Ok(Frame::Integer(next))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_hincrby() {
+        let session = session().await;
+
+        assert_eq!(run(&session, "HINCRBY h f 5").await, Frame::Integer(5));
+        assert_eq!(run(&session, "HINCRBY h f -7").await, Frame::Integer(-2));
+
+        run(&session, "HSET h max 9223372036854775807 text abc").await;
+
+        assert_eq!(
+            run(&session, "HINCRBY h max 1").await,
+            value_error!("increment or decrement would overflow")
+        );
+        assert_eq!(
+            run(&session, "HINCRBY h text 1").await,
+            value_error!("hash value is not an integer")
+        );
+        assert_eq!(
+            run(&session, "HINCRBY h f 1.5").await,
+            Frame::from(ReplyError::NotInteger)
+        );
+        assert_eq!(
+            run(&session, "HGET h max").await,
+            Frame::Bulk(b"9223372036854775807".to_vec())
+        );
+    }
+}
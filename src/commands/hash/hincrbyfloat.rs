@@ -0,0 +1,134 @@
+use crate::commands::args::parse_float;
+use crate::prelude::*;
+use crate::storage::hash::HashValue;
+use crate::utils::number::format_human;
+
+pub struct HIncrByFloat;
+
+#[async_trait]
+impl CommandTrait for HIncrByFloat {
+    fn name(&self) -> &str {
+        "HINCRBYFLOAT"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Increments the floating point value of a field by a number. Uses 0 as initial value if the field doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "2.6.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let field = args.next()?.to_vec();
+        let increment = args.next_float()?;
+
+        let mut store = session.state.store.write().await;
+
+        let current = match store.get(&key) {
+            Some(Entry {
+                value: Value::Hash(fields),
+                ..
+            }) => match fields.get(&field) {
+                Some(value) => match parse_float(value) {
+                    Ok(current) => current,
+                    Err(_) => return Ok(value_error!("hash value is not a float")),
+                },
+                None => 0.0,
+            },
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => 0.0,
+        };
+
+        let next = current + increment;
+
+        // checked before the hash is created, an infinite increment would leave it empty
+        if !next.is_finite() {
+            return Ok(value_error!("increment would produce NaN or Infinity"));
+        }
+
+        // always written out in full, never with an exponent
+        let text = format_human(next).into_bytes();
+
+        let entry = store.get_or_insert_with(key, || Entry::new(Value::Hash(HashValue::new())));
+
+        // an existing field keeps its expire time
+        if let Value::Hash(fields) = &mut entry.value {
+            match fields.get_mut(&field) {
+                Some(value) => *value = text.clone(),
+                None => {
+                    fields.insert(field, text.clone());
+                }
+            }
+        }
+
+        Ok(Frame::Bulk(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_hincrbyfloat() {
+        let session = session().await;
+
+        run(&session, "HSET h f 0.2 text abc").await;
+
+        assert_eq!(
+            run(&session, "HINCRBYFLOAT h f 0.1").await,
+            Frame::Bulk(b"0.3".to_vec())
+        );
+        assert_eq!(
+            run(&session, "HINCRBYFLOAT h n 5.0e3").await,
+            Frame::Bulk(b"5000".to_vec())
+        );
+        assert_eq!(
+            run(&session, "HINCRBYFLOAT h f -1").await,
+            Frame::Bulk(b"-0.7".to_vec())
+        );
+
+        // nothing is written when the result isn't finite
+        assert_eq!(
+            run(&session, "HINCRBYFLOAT h f inf").await,
+            value_error!("increment would produce NaN or Infinity")
+        );
+        assert_eq!(
+            run(&session, "HINCRBYFLOAT h f nan").await,
+            Frame::from(ReplyError::NotFloat)
+        );
+        assert_eq!(
+            run(&session, "HINCRBYFLOAT h text 1").await,
+            value_error!("hash value is not a float")
+        );
+        assert_eq!(
+            run(&session, "HGET h f").await,
+            Frame::Bulk(b"-0.7".to_vec())
+        );
+
+        assert_eq!(
+            run(&session, "HINCRBYFLOAT new f inf").await,
+            value_error!("increment would produce NaN or Infinity")
+        );
+        assert_eq!(run(&session, "EXISTS new").await, Frame::Integer(0));
+    }
+}
@@ -0,0 +1,54 @@
+use crate::prelude::*;
+
+pub struct HKeys;
+
+#[async_trait]
+impl CommandTrait for HKeys {
+    fn name(&self) -> &str {
+        "HKEYS"
+    }
+
+    fn arity(&self) -> i64 {
+        2
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns all fields in a hash."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let store = session.state.store.read().await;
+
+        match store.get(&key) {
+            Some(Entry {
+                value: Value::Hash(fields),
+                ..
+            }) => Ok(Frame::Array(
+                fields
+                    .keys()
+                    .map(|field| Frame::Bulk(field.clone()))
+                    .collect(),
+            )),
+            Some(_) => Ok(Frame::wrong_type()),
+            None => Ok(Frame::Array(VecDeque::new())),
+        }
+    }
+}
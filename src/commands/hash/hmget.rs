@@ -0,0 +1,56 @@
+use crate::prelude::*;
+
+pub struct HMGet;
+
+#[async_trait]
+impl CommandTrait for HMGet {
+    fn name(&self) -> &str {
+        "HMGET"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the values of all fields in a hash."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let store = session.state.store.read().await;
+
+        let fields = match store.get(&key) {
+            Some(Entry {
+                value: Value::Hash(fields),
+                ..
+            }) => Some(fields),
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => None,
+        };
+
+        Ok(Frame::Array(
+            args.rest()
+                .map(|field| fields.and_then(|fields| fields.get(&field[..])))
+                .map(|value| value.cloned().map_or(Frame::Nil, Frame::Bulk))
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,54 @@
+use crate::prelude::*;
+use crate::storage::hash::HashValue;
+
+pub struct HMSet;
+
+#[async_trait]
+impl CommandTrait for HMSet {
+    fn name(&self) -> &str {
+        "HMSET"
+    }
+
+    fn arity(&self) -> i64 {
+        -4
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Sets the values of multiple fields."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let pairs = args.pairs()?;
+
+        let mut store = session.state.store.write().await;
+
+        let entry = store.get_or_insert_with(key, || Entry::new(Value::Hash(HashValue::new())));
+
+        let Value::Hash(fields) = &mut entry.value else {
+            return Ok(Frame::wrong_type());
+        };
+
+        for (field, value) in pairs {
+            fields.insert(field.to_vec(), value.to_vec());
+        }
+
+        Ok(Frame::Ok)
+    }
+}
@@ -0,0 +1,67 @@
+use super::hexpire::parse_fields;
+use crate::prelude::*;
+
+pub struct HPersist;
+
+#[async_trait]
+impl CommandTrait for HPersist {
+    fn name(&self) -> &str {
+        "HPERSIST"
+    }
+
+    fn arity(&self) -> i64 {
+        -5
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes the expiration time for each specified field"
+    }
+
+    fn since(&self) -> &str {
+        "7.4.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let fields = parse_fields(args, 1)?;
+
+        let mut store = session.state.store.write().await;
+
+        let hash = match store.get_mut(&key) {
+            Some(Entry {
+                value: Value::Hash(hash),
+                ..
+            }) => hash,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => {
+                return Ok(Frame::Array(
+                    fields.iter().map(|_| Frame::Integer(-2)).collect(),
+                ))
+            }
+        };
+
+        // -2 for a missing field, -1 for one without an expire time
+        let reply = fields.iter().map(|field| match hash.expire_at(field) {
+            Some(Some(_)) => {
+                hash.set_expire(field, None);
+                Frame::Integer(1)
+            }
+            Some(None) => Frame::Integer(-1),
+            None => Frame::Integer(-2),
+        });
+
+        Ok(Frame::Array(reply.collect()))
+    }
+}
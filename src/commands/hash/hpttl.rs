@@ -0,0 +1,40 @@
+use super::httl::httl;
+use crate::commands::args::TimeUnit;
+use crate::prelude::*;
+
+pub struct HPTtl;
+
+#[async_trait]
+impl CommandTrait for HPTtl {
+    fn name(&self) -> &str {
+        "HPTTL"
+    }
+
+    fn arity(&self) -> i64 {
+        -5
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the TTL in milliseconds of a hash field."
+    }
+
+    fn since(&self) -> &str {
+        "7.4.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        httl(args, session, TimeUnit::Millis, false).await
+    }
+}
@@ -0,0 +1,175 @@
+use crate::prelude::*;
+use crate::utils::random::{random_index, shuffle_front};
+
+pub struct HRandField;
+
+#[async_trait]
+impl CommandTrait for HRandField {
+    fn name(&self) -> &str {
+        "HRANDFIELD"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns one or more random fields from a hash."
+    }
+
+    fn since(&self) -> &str {
+        "6.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        if args.len() > 3 {
+            return Err(ReplyError::Syntax.into());
+        }
+
+        let key = args.next()?;
+        let count = if args.is_empty() {
+            None
+        } else {
+            Some(args.next_int()?)
+        };
+
+        let with_values = match args.rest().next() {
+            Some(arg) if arg.eq_ignore_ascii_case(b"WITHVALUES") => true,
+            Some(_) => return Err(ReplyError::Syntax.into()),
+            None => false,
+        };
+
+        let store = session.state.store.read().await;
+
+        let fields = match store.get(&key) {
+            Some(Entry {
+                value: Value::Hash(fields),
+                ..
+            }) => fields,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None if count.is_some() => return Ok(Frame::Array(VecDeque::new())),
+            None => return Ok(Frame::Nil),
+        };
+
+        let mut entries: Vec<_> = fields.iter().collect();
+
+        // every field may have expired before the active expire cycle got to the hash
+        if entries.is_empty() {
+            return Ok(match count {
+                Some(_) => Frame::Array(VecDeque::new()),
+                None => Frame::Nil,
+            });
+        }
+
+        let Some(count) = count else {
+            let (field, _) = entries[random_index(entries.len())];
+            return Ok(Frame::Bulk(field.to_vec()));
+        };
+
+        // a negative count may return the same field more than once
+        let picked = if count >= 0 {
+            let count = usize::try_from(count).unwrap_or(usize::MAX);
+
+            shuffle_front(&mut entries, count);
+            entries.truncate(count);
+
+            entries
+        } else {
+            let count = usize::try_from(count.unsigned_abs()).unwrap_or(usize::MAX);
+
+            if count > session.state.limits.max_multibulk_len {
+                return Ok(value_error!("value is out of range"));
+            }
+
+            (0..count)
+                .map(|_| entries[random_index(entries.len())])
+                .collect()
+        };
+
+        let resp3 = session.get_proto_version() == 3;
+        let mut reply = VecDeque::new();
+
+        // RESP3 pairs each field up with its value, RESP2 interleaves them
+        for (field, value) in picked {
+            let field = Frame::Bulk(field.clone());
+
+            match (with_values, resp3) {
+                (false, _) => reply.push_back(field),
+                (true, false) => reply.extend([field, Frame::Bulk(value.clone())]),
+                (true, true) => reply.push_back(Frame::Array(VecDeque::from([
+                    field,
+                    Frame::Bulk(value.clone()),
+                ]))),
+            }
+        }
+
+        Ok(Frame::Array(reply))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    fn len(frame: &Frame) -> usize {
+        match frame {
+            Frame::Array(items) => items.len(),
+            other => panic!("expected an array, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hrandfield() {
+        let session = session().await;
+
+        run(&session, "HSET h a 1 b 2 c 3").await;
+
+        // a negative count may repeat fields, a positive one never does
+        assert_eq!(len(&run(&session, "HRANDFIELD h -10").await), 10);
+        assert_eq!(len(&run(&session, "HRANDFIELD h 10").await), 3);
+        assert_eq!(len(&run(&session, "HRANDFIELD h 2").await), 2);
+        assert_eq!(len(&run(&session, "HRANDFIELD h 0").await), 0);
+
+        // RESP2 interleaves each field with its value
+        let Frame::Array(pairs) = run(&session, "HRANDFIELD h -4 WITHVALUES").await else {
+            panic!("expected an array");
+        };
+
+        assert_eq!(pairs.len(), 8);
+
+        for pair in Vec::from(pairs).chunks(2) {
+            let (Frame::Bulk(field), Frame::Bulk(value)) = (&pair[0], &pair[1]) else {
+                panic!("expected bulk strings");
+            };
+
+            let expected = match &field[..] {
+                b"a" => b"1",
+                b"b" => b"2",
+                b"c" => b"3",
+                other => panic!("unknown field {other:?}"),
+            };
+
+            assert_eq!(value, expected);
+        }
+
+        assert_eq!(run(&session, "HRANDFIELD missing").await, Frame::Nil);
+        assert_eq!(len(&run(&session, "HRANDFIELD missing -3").await), 0);
+        assert_eq!(
+            run(&session, "HRANDFIELD h 1 WITHVALUES junk").await,
+            Frame::from(ReplyError::Syntax)
+        );
+    }
+}
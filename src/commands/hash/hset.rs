@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::storage::hash::HashValue;
 
 pub struct HSet;
 
@@ -8,54 +9,53 @@ impl CommandTrait for HSet {
         "HSET"
     }
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()> {
-        // HSET key field value [field value ...]
-        // so the number of arguments should be at least 3 and odd
-        if args.len() < 3 || args.len() % 2 != 1 {
-            return value_error!("Invalid number of arguments")
-                .to_resp2(writer)
-                .await;
-        }
+    fn arity(&self) -> i64 {
+        -4
+    }
 
-        let key = match args.pop_front() {
-            Some(Value::String(key)) => key,
-            Some(_) => {
-                return value_error!("Invalid key").to_resp2(writer).await;
-            }
-            None => {
-                return value_error!("Missing key").to_resp2(writer).await;
-            }
-        };
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Creates or modifies the value of a field in a hash."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        // HSET key field value [field value ...]
+        let key = args.next()?.to_vec();
+        let pairs = args.pairs()?;
 
         let mut store = session.state.store.write().await;
 
+        let entry = store.get_or_insert_with(key, || Entry::new(Value::Hash(HashValue::new())));
+
+        let fields = match &mut entry.value {
+            Value::Hash(fields) => fields,
+            _ => return Ok(Frame::wrong_type()),
+        };
+
         let mut count = 0;
 
-        while let Some(field) = args.pop_front() {
-            // SAFETY: we know that we have a field, so we can unwrap
-            let value = args.pop_front().unwrap();
-
-            let fields = store
-                .entry(key.clone())
-                .or_insert_with(|| Value::Map(HashMap::new()));
-
-            match fields {
-                Value::Map(fields) => {
-                    fields.insert(field, value);
-                }
-                _ => {
-                    return value_error!("Key is not a hashmap").to_resp2(writer).await;
-                }
+        for (field, value) in pairs {
+            // only newly created fields are counted
+            if fields.insert(field.to_vec(), value.to_vec()).is_none() {
+                count += 1;
             }
-
-            count += 1;
         }
 
-        Value::Integer(count).to_resp2(writer).await
+        Ok(Frame::Integer(count))
     }
 }
@@ -0,0 +1,197 @@
+use super::hexpire::{parse_fields, settle};
+use crate::commands::args::{Opt, OptKind, TimeUnit};
+use crate::prelude::*;
+use crate::storage::hash::HashValue;
+
+pub struct HSetEx;
+
+#[async_trait]
+impl CommandTrait for HSetEx {
+    fn name(&self) -> &str {
+        "HSETEX"
+    }
+
+    fn arity(&self) -> i64 {
+        -6
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Set the value of one or more fields of a given hash key, and optionally set their expiration."
+    }
+
+    fn since(&self) -> &str {
+        "8.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let options = args.options_before(OPTIONS, Some("FIELDS"))?;
+        let pairs = parse_fields(args, 2)?;
+
+        let deadline = options.deadline(self.name())?;
+        let keep_ttl = options.has("KEEPTTL");
+
+        let mut store = session.state.store.write().await;
+
+        let hash = match store.get(&key) {
+            Some(Entry {
+                value: Value::Hash(hash),
+                ..
+            }) => Some(hash),
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => None,
+        };
+
+        let exists = |field: &Bytes| hash.is_some_and(|hash| hash.contains_key(field));
+
+        // FNX only sets fields if none of them exist, FXX only if all of them do
+        let allowed = if options.has("FNX") {
+            pairs.iter().step_by(2).all(|field| !exists(field))
+        } else if options.has("FXX") {
+            pairs.iter().step_by(2).all(exists)
+        } else {
+            true
+        };
+
+        if !allowed {
+            return Ok(Frame::Integer(0));
+        }
+
+        let entry =
+            store.get_or_insert_with(key.clone(), || Entry::new(Value::Hash(HashValue::new())));
+
+        let Value::Hash(hash) = &mut entry.value else {
+            return Ok(Frame::wrong_type());
+        };
+
+        for pair in pairs.chunks_exact(2) {
+            let (field, value) = (&pair[0], pair[1].to_vec());
+
+            // a plain set clears the expire time of the field
+            match hash.get_mut(field) {
+                Some(current) if keep_ttl => *current = value,
+                _ => {
+                    hash.insert(field.to_vec(), value);
+                }
+            }
+
+            // a deadline in the past deletes the field right away
+            if deadline.is_some() {
+                hash.set_expire(field, deadline);
+            }
+        }
+
+        settle(&mut store, &key);
+
+        Ok(Frame::Integer(1))
+    }
+}
+
+const OPTIONS: &[Opt] = &[
+    Opt::flag("FNX", 1),
+    Opt::flag("FXX", 1),
+    Opt::new("EX", OptKind::Ttl(TimeUnit::Seconds), 2),
+    Opt::new("PX", OptKind::Ttl(TimeUnit::Millis), 2),
+    Opt::new("EXAT", OptKind::Ttl(TimeUnit::Seconds), 2),
+    Opt::new("PXAT", OptKind::Ttl(TimeUnit::Millis), 2),
+    Opt::flag("KEEPTTL", 2),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{bulks, ints, run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_hsetex() {
+        let session = session().await;
+
+        assert_eq!(
+            run(&session, "HSETEX h EX 100 FIELDS 2 a 1 b 2").await,
+            Frame::Integer(1)
+        );
+        assert_eq!(
+            run(&session, "HTTL h FIELDS 2 a b").await,
+            ints(&[100, 100])
+        );
+
+        // KEEPTTL keeps the expire time of fields that exist, a plain set clears it
+        assert_eq!(
+            run(&session, "HSETEX h KEEPTTL FIELDS 2 a 3 c 4").await,
+            Frame::Integer(1)
+        );
+        assert_eq!(
+            run(&session, "HSETEX h FIELDS 1 b 5").await,
+            Frame::Integer(1)
+        );
+        assert_eq!(
+            run(&session, "HTTL h FIELDS 3 a b c").await,
+            ints(&[100, -1, -1])
+        );
+        assert_eq!(
+            run(&session, "HMGET h a b c").await,
+            bulks(&["3", "5", "4"])
+        );
+
+        // a deadline in the past deletes the fields, and the emptied hash
+        assert_eq!(
+            run(&session, "HSETEX new PXAT 1 FIELDS 1 a 1").await,
+            Frame::Integer(1)
+        );
+        assert_eq!(run(&session, "EXISTS new").await, Frame::Integer(0));
+    }
+
+    #[tokio::test]
+    async fn test_hsetex_conditions() {
+        let session = session().await;
+
+        run(&session, "HSET h a 1").await;
+
+        // FNX only sets if none of the fields exist, FXX only if all of them do
+        assert_eq!(
+            run(&session, "HSETEX h FNX FIELDS 2 a 2 b 2").await,
+            Frame::Integer(0)
+        );
+        assert_eq!(
+            run(&session, "HSETEX h FXX FIELDS 2 a 2 b 2").await,
+            Frame::Integer(0)
+        );
+        assert_eq!(
+            run(&session, "HMGET h a b").await,
+            Frame::Array(VecDeque::from([Frame::from("1"), Frame::Nil]))
+        );
+        assert_eq!(
+            run(&session, "HSETEX h FNX FIELDS 1 b 2").await,
+            Frame::Integer(1)
+        );
+        assert_eq!(
+            run(&session, "HSETEX h FXX EX 100 FIELDS 2 a 3 b 3").await,
+            Frame::Integer(1)
+        );
+        assert_eq!(run(&session, "HMGET h a b").await, bulks(&["3", "3"]));
+        assert_eq!(
+            run(&session, "HSETEX missing FXX FIELDS 1 a 1").await,
+            Frame::Integer(0)
+        );
+        assert_eq!(run(&session, "EXISTS missing").await, Frame::Integer(0));
+
+        for options in ["FNX FXX", "EX 100 KEEPTTL", "EX 100 PX 100"] {
+            assert_eq!(
+                run(&session, &format!("HSETEX h {options} FIELDS 1 a 1")).await,
+                Frame::from(ReplyError::Syntax)
+            );
+        }
+    }
+}
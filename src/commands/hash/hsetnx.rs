@@ -0,0 +1,78 @@
+use crate::prelude::*;
+use crate::storage::hash::HashValue;
+
+pub struct HSetNx;
+
+#[async_trait]
+impl CommandTrait for HSetNx {
+    fn name(&self) -> &str {
+        "HSETNX"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Sets the value of a field in a hash only when the field doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let field = args.next()?.to_vec();
+        let value = args.next()?.to_vec();
+
+        let mut store = session.state.store.write().await;
+
+        let entry = store.get_or_insert_with(key, || Entry::new(Value::Hash(HashValue::new())));
+
+        let Value::Hash(fields) = &mut entry.value else {
+            return Ok(Frame::wrong_type());
+        };
+
+        if fields.contains_key(&field) {
+            return Ok(Frame::Integer(0));
+        }
+
+        fields.insert(field, value);
+
+        Ok(Frame::Integer(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_hsetnx() {
+        let session = session().await;
+
+        assert_eq!(run(&session, "HSETNX h f one").await, Frame::Integer(1));
+        assert_eq!(run(&session, "HSETNX h f two").await, Frame::Integer(0));
+        assert_eq!(
+            run(&session, "HGET h f").await,
+            Frame::Bulk(b"one".to_vec())
+        );
+
+        run(&session, "SET s value").await;
+        assert_eq!(run(&session, "HSETNX s f one").await, Frame::wrong_type());
+    }
+}
@@ -0,0 +1,52 @@
+use crate::prelude::*;
+
+pub struct HStrLen;
+
+#[async_trait]
+impl CommandTrait for HStrLen {
+    fn name(&self) -> &str {
+        "HSTRLEN"
+    }
+
+    fn arity(&self) -> i64 {
+        3
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the length of the value of a field."
+    }
+
+    fn since(&self) -> &str {
+        "3.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let field = args.next()?;
+
+        let store = session.state.store.read().await;
+
+        match store.get(&key) {
+            Some(Entry {
+                value: Value::Hash(fields),
+                ..
+            }) => Ok(Frame::Integer(
+                fields.get(&field[..]).map_or(0, Vec::len) as i64
+            )),
+            Some(_) => Ok(Frame::wrong_type()),
+            None => Ok(Frame::Integer(0)),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use super::hexpire::parse_fields;
+use crate::commands::args::TimeUnit;
+use crate::prelude::*;
+use crate::utils::time::unix_millis;
+
+pub struct HTtl;
+
+#[async_trait]
+impl CommandTrait for HTtl {
+    fn name(&self) -> &str {
+        "HTTL"
+    }
+
+    fn arity(&self) -> i64 {
+        -5
+    }
+
+    fn group(&self) -> &str {
+        "hash"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the TTL in seconds of a hash field."
+    }
+
+    fn since(&self) -> &str {
+        "7.4.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        httl(args, session, TimeUnit::Seconds, false).await
+    }
+}
+
+/// Shared by `HTTL`, `HPTTL`, `HEXPIRETIME` and `HPEXPIRETIME`: per field -2
+/// if there's no such field, -1 if it has no expire time.
+pub(super) async fn httl(
+    args: &mut Args,
+    session: SessionRef,
+    unit: TimeUnit,
+    absolute: bool,
+) -> Result<Frame> {
+    let key = args.next()?;
+    let fields = parse_fields(args, 1)?;
+
+    let store = session.state.store.read().await;
+
+    let hash = match store.get(&key) {
+        Some(Entry {
+            value: Value::Hash(hash),
+            ..
+        }) => Some(hash),
+        Some(_) => return Ok(Frame::wrong_type()),
+        None => None,
+    };
+
+    let reply = fields.iter().map(|field| {
+        let ttl = match hash.and_then(|hash| hash.expire_at(field)) {
+            Some(Some(expire_at)) => {
+                let millis = if absolute {
+                    expire_at
+                } else {
+                    (expire_at - unix_millis()).max(0)
+                };
+
+                match unit {
+                    // rounded to the nearest second
+                    TimeUnit::Seconds => (millis + 500) / 1000,
+                    TimeUnit::Millis => millis,
+                }
+            }
+            Some(None) => -1,
+            None => -2,
+        };
+
+        Frame::Integer(ttl)
+    });
+
+    Ok(Frame::Array(reply.collect()))
+}
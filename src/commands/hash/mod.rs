@@ -1,5 +1,53 @@
-pub mod hgetall;
-pub mod hset;
+mod hdel;
+mod hexists;
+mod hexpire;
+mod hexpireat;
+mod hexpiretime;
+mod hget;
+mod hgetall;
+mod hgetex;
+mod hincrby;
+mod hincrbyfloat;
+mod hkeys;
+mod hlen;
+mod hmget;
+mod hmset;
+mod hpersist;
+mod hpexpire;
+mod hpexpireat;
+mod hpexpiretime;
+mod hpttl;
+mod hrandfield;
+mod hset;
+mod hsetex;
+mod hsetnx;
+mod hstrlen;
+mod httl;
+mod hvals;
 
+pub use hdel::HDel;
+pub use hexists::HExists;
+pub use hexpire::HExpire;
+pub use hexpireat::HExpireAt;
+pub use hexpiretime::HExpireTime;
+pub use hget::HGet;
 pub use hgetall::HGetAll;
+pub use hgetex::HGetEx;
+pub use hincrby::HIncrBy;
+pub use hincrbyfloat::HIncrByFloat;
+pub use hkeys::HKeys;
+pub use hlen::HLen;
+pub use hmget::HMGet;
+pub use hmset::HMSet;
+pub use hpersist::HPersist;
+pub use hpexpire::HPExpire;
+pub use hpexpireat::HPExpireAt;
+pub use hpexpiretime::HPExpireTime;
+pub use hpttl::HPTtl;
+pub use hrandfield::HRandField;
 pub use hset::HSet;
+pub use hsetex::HSetEx;
+pub use hsetnx::HSetNx;
+pub use hstrlen::HStrLen;
+pub use httl::HTtl;
+pub use hvals::HVals;
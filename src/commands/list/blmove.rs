@@ -0,0 +1,56 @@
+use super::lmove::{lmove, parse_end};
+use crate::prelude::*;
+
+pub struct BLMove;
+
+#[async_trait]
+impl CommandTrait for BLMove {
+    fn name(&self) -> &str {
+        "BLMOVE"
+    }
+
+    fn arity(&self) -> i64 {
+        6
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Pops an element from a list, pushes it to another list and returns it. Blocks until an element is available otherwise. Deletes the list if the last element was moved."
+    }
+
+    fn since(&self) -> &str {
+        "6.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[
+            CommandFlag::Write,
+            CommandFlag::DenyOom,
+            CommandFlag::Blocking,
+        ]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 2, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let source = args.next()?.to_vec();
+        let destination = args.next()?.to_vec();
+        let from_front = parse_end(&args.next()?)?;
+        let to_front = parse_end(&args.next()?)?;
+        let timeout = args.next_timeout()?;
+
+        session
+            .block_on(vec![source.clone()], timeout, move |store| {
+                let moved = lmove(store, &source, destination.clone(), from_front, to_front)?;
+
+                Ok(moved.map(Frame::Bulk))
+            })
+            .await
+            .map(|reply| reply.unwrap_or(Frame::Nil))
+    }
+}
@@ -0,0 +1,51 @@
+use super::lmpop::MPopArgs;
+use crate::prelude::*;
+
+pub struct BLMPop;
+
+#[async_trait]
+impl CommandTrait for BLMPop {
+    fn name(&self) -> &str {
+        "BLMPOP"
+    }
+
+    fn arity(&self) -> i64 {
+        -5
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Pops the first element from one of multiple lists. Blocks until an element is available otherwise. Deletes the list if the last element was popped."
+    }
+
+    fn since(&self) -> &str {
+        "7.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[
+            CommandFlag::Write,
+            CommandFlag::Blocking,
+            CommandFlag::MovableKeys,
+        ]
+    }
+
+    fn numkeys_index(&self) -> i64 {
+        2
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let timeout = args.next_timeout()?;
+        let mpop = MPopArgs::parse(args)?;
+
+        let keys = mpop.keys.iter().map(|key| key.to_vec()).collect();
+
+        session
+            .block_on(keys, timeout, move |store| mpop.pop(store))
+            .await
+            .map(|reply| reply.unwrap_or(Frame::NullArray))
+    }
+}
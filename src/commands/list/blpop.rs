@@ -0,0 +1,83 @@
+use crate::commands::args::parse_timeout;
+use crate::prelude::*;
+
+pub struct BLPop;
+
+#[async_trait]
+impl CommandTrait for BLPop {
+    fn name(&self) -> &str {
+        "BLPOP"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes and returns the first element in a list. Blocks until an element is available otherwise. Deletes the list if the last element was popped."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Blocking]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, -2, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        bpop(args, session, true).await
+    }
+}
+
+/// Pop an element off the front or back of the first non empty list among
+/// the keys, blocking until there is one. The timeout comes last.
+pub(super) async fn bpop(args: &mut Args, session: SessionRef, front: bool) -> Result<Frame> {
+    let mut keys: Vec<Vec<u8>> = args.rest().map(|key| key.to_vec()).collect();
+    let timeout = parse_timeout(&keys.pop().unwrap_or_default())?;
+
+    session
+        .block_on(keys.clone(), timeout, move |store| {
+            for key in &keys {
+                let list = match store.get_mut(key) {
+                    Some(Entry {
+                        value: Value::List(list),
+                        ..
+                    }) => list,
+                    Some(_) => return Err(ReplyError::WrongType.into()),
+                    None => continue,
+                };
+
+                let popped = if front {
+                    list.pop_front()
+                } else {
+                    list.pop_back()
+                };
+
+                let Some(value) = popped else {
+                    continue;
+                };
+
+                if list.is_empty() {
+                    store.remove(key);
+                }
+
+                return Ok(Some(Frame::Array(VecDeque::from([
+                    Frame::Bulk(key.clone()),
+                    Frame::Bulk(value),
+                ]))));
+            }
+
+            Ok(None)
+        })
+        .await
+        .map(|reply| reply.unwrap_or(Frame::NullArray))
+}
@@ -0,0 +1,39 @@
+use super::blpop::bpop;
+use crate::prelude::*;
+
+pub struct BRPop;
+
+#[async_trait]
+impl CommandTrait for BRPop {
+    fn name(&self) -> &str {
+        "BRPOP"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes and returns the last element in a list. Blocks until an element is available otherwise. Deletes the list if the last element was popped."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Blocking]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, -2, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        bpop(args, session, false).await
+    }
+}
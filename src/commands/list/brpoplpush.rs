@@ -0,0 +1,54 @@
+use super::lmove::lmove;
+use crate::prelude::*;
+
+pub struct BRPopLPush;
+
+#[async_trait]
+impl CommandTrait for BRPopLPush {
+    fn name(&self) -> &str {
+        "BRPOPLPUSH"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Pops an element from a list, pushes it to another list and returns it. Block until an element is available otherwise. Deletes the list if the last element was popped."
+    }
+
+    fn since(&self) -> &str {
+        "2.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[
+            CommandFlag::Write,
+            CommandFlag::DenyOom,
+            CommandFlag::Blocking,
+        ]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 2, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let source = args.next()?.to_vec();
+        let destination = args.next()?.to_vec();
+        let timeout = args.next_timeout()?;
+
+        session
+            .block_on(vec![source.clone()], timeout, move |store| {
+                let moved = lmove(store, &source, destination.clone(), false, true)?;
+
+                Ok(moved.map(Frame::Bulk))
+            })
+            .await
+            .map(|reply| reply.unwrap_or(Frame::Nil))
+    }
+}
@@ -0,0 +1,61 @@
+use crate::prelude::*;
+
+pub struct LIndex;
+
+#[async_trait]
+impl CommandTrait for LIndex {
+    fn name(&self) -> &str {
+        "LINDEX"
+    }
+
+    fn arity(&self) -> i64 {
+        3
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns an element from a list by its index."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let index = args.next_int()?;
+
+        let store = session.state.store.read().await;
+
+        let list = match store.get(&key) {
+            Some(Entry {
+                value: Value::List(list),
+                ..
+            }) => list,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => return Ok(Frame::Nil),
+        };
+
+        let value = index_of(index, list.len()).and_then(|index| list.get(index));
+
+        Ok(value.map_or(Frame::Nil, |value| Frame::Bulk(value.clone())))
+    }
+}
+
+/// Resolve an index over `len` elements, negative ones count from the back.
+pub(super) fn index_of(index: i64, len: usize) -> Option<usize> {
+    let index = if index < 0 { len as i64 + index } else { index };
+
+    usize::try_from(index).ok().filter(|index| *index < len)
+}
@@ -0,0 +1,109 @@
+use crate::prelude::*;
+
+pub struct LInsert;
+
+#[async_trait]
+impl CommandTrait for LInsert {
+    fn name(&self) -> &str {
+        "LINSERT"
+    }
+
+    fn arity(&self) -> i64 {
+        5
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Inserts an element before or after another element in a list."
+    }
+
+    fn since(&self) -> &str {
+        "2.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let position = args.next()?;
+        let pivot = args.next()?;
+        let value = args.next()?.to_vec();
+
+        let after = if position.eq_ignore_ascii_case(b"BEFORE") {
+            false
+        } else if position.eq_ignore_ascii_case(b"AFTER") {
+            true
+        } else {
+            return Err(ReplyError::Syntax.into());
+        };
+
+        let mut store = session.state.store.write().await;
+
+        let list = match store.get_mut(&key) {
+            Some(Entry {
+                value: Value::List(list),
+                ..
+            }) => list,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => return Ok(Frame::Integer(0)),
+        };
+
+        let Some(index) = list.iter().position(|value| *value == pivot) else {
+            return Ok(Frame::Integer(-1));
+        };
+
+        list.insert(index + usize::from(after), value);
+
+        Ok(Frame::Integer(list.len() as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{bulks, run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_linsert() {
+        let session = session().await;
+
+        run(&session, "RPUSH l a c c").await;
+
+        // lands next to the first match of the pivot
+        assert_eq!(
+            run(&session, "LINSERT l BEFORE c b").await,
+            Frame::Integer(4)
+        );
+        assert_eq!(
+            run(&session, "LINSERT l after c d").await,
+            Frame::Integer(5)
+        );
+        assert_eq!(
+            run(&session, "LRANGE l 0 -1").await,
+            bulks(&["a", "b", "c", "d", "c"])
+        );
+
+        assert_eq!(
+            run(&session, "LINSERT l BEFORE x y").await,
+            Frame::Integer(-1)
+        );
+        assert_eq!(
+            run(&session, "LINSERT missing BEFORE a b").await,
+            Frame::Integer(0)
+        );
+        assert_eq!(run(&session, "EXISTS missing").await, Frame::Integer(0));
+        assert_eq!(
+            run(&session, "LINSERT l MIDDLE a b").await,
+            Frame::from(ReplyError::Syntax)
+        );
+    }
+}
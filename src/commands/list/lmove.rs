@@ -0,0 +1,196 @@
+use crate::prelude::*;
+use crate::storage::db::Db;
+use crate::storage::quicklist::QuickList;
+
+pub struct LMove;
+
+#[async_trait]
+impl CommandTrait for LMove {
+    fn name(&self) -> &str {
+        "LMOVE"
+    }
+
+    fn arity(&self) -> i64 {
+        5
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns an element after popping it from one list and pushing it to another. Deletes the list if the last element was moved."
+    }
+
+    fn since(&self) -> &str {
+        "6.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 2, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let source = args.next()?;
+        let destination = args.next()?.to_vec();
+        let from_front = parse_end(&args.next()?)?;
+        let to_front = parse_end(&args.next()?)?;
+
+        let mut store = session.state.store.write().await;
+
+        let moved = lmove(&mut store, &source, destination, from_front, to_front)?;
+
+        Ok(moved.map_or(Frame::Nil, Frame::Bulk))
+    }
+}
+
+/// Parse a `LEFT|RIGHT` argument, returns whether it's `LEFT`.
+pub(super) fn parse_end(value: &[u8]) -> Result<bool> {
+    if value.eq_ignore_ascii_case(b"LEFT") {
+        Ok(true)
+    } else if value.eq_ignore_ascii_case(b"RIGHT") {
+        Ok(false)
+    } else {
+        Err(ReplyError::Syntax.into())
+    }
+}
+
+/// Pop an element off one end of the list at `source` and push it onto one
+/// end of the list at `destination`, which is created when missing. An
+/// emptied source is deleted. `None` when there's nothing to move.
+pub(super) fn lmove(
+    store: &mut Db,
+    source: &[u8],
+    destination: Vec<u8>,
+    from_front: bool,
+    to_front: bool,
+) -> Result<Option<Vec<u8>>> {
+    match store.get(source) {
+        Some(Entry {
+            value: Value::List(_),
+            ..
+        }) => {}
+        Some(_) => return Err(ReplyError::WrongType.into()),
+        None => return Ok(None),
+    }
+
+    // both ends are type checked before anything is popped
+    if store
+        .get(&destination)
+        .is_some_and(|entry| !matches!(entry.value, Value::List(_)))
+    {
+        return Err(ReplyError::WrongType.into());
+    }
+
+    let Some(Entry {
+        value: Value::List(list),
+        ..
+    }) = store.get_mut(source)
+    else {
+        return Ok(None);
+    };
+
+    let popped = if from_front {
+        list.pop_front()
+    } else {
+        list.pop_back()
+    };
+
+    let Some(value) = popped else {
+        return Ok(None);
+    };
+
+    if list.is_empty() {
+        store.remove(source);
+    }
+
+    let entry = store.get_or_insert_with(destination, || Entry::new(Value::List(QuickList::new())));
+
+    if let Value::List(list) = &mut entry.value {
+        if to_front {
+            list.push_front(value.clone());
+        } else {
+            list.push_back(value.clone());
+        }
+    }
+
+    Ok(Some(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{bulks, run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_lmove() {
+        let session = session().await;
+
+        run(&session, "RPUSH src a b c").await;
+
+        assert_eq!(
+            run(&session, "LMOVE src dst LEFT RIGHT").await,
+            Frame::from("a")
+        );
+        assert_eq!(
+            run(&session, "LMOVE src dst RIGHT LEFT").await,
+            Frame::from("c")
+        );
+        assert_eq!(run(&session, "LRANGE dst 0 -1").await, bulks(&["c", "a"]));
+        assert_eq!(
+            run(&session, "LMOVE missing dst LEFT LEFT").await,
+            Frame::Nil
+        );
+
+        run(&session, "SET text abc").await;
+
+        // nothing is popped when the destination has the wrong type
+        assert_eq!(
+            run(&session, "LMOVE src text LEFT LEFT").await,
+            Frame::wrong_type()
+        );
+        assert_eq!(run(&session, "LLEN src").await, Frame::Integer(1));
+        assert_eq!(
+            run(&session, "LMOVE src dst UP LEFT").await,
+            Frame::from(ReplyError::Syntax)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lmove_same_list() {
+        let session = session().await;
+
+        run(&session, "RPUSH l a b c").await;
+
+        // rotates the list
+        assert_eq!(
+            run(&session, "LMOVE l l LEFT RIGHT").await,
+            Frame::from("a")
+        );
+        assert_eq!(
+            run(&session, "LRANGE l 0 -1").await,
+            bulks(&["b", "c", "a"])
+        );
+        assert_eq!(
+            run(&session, "LMOVE l l RIGHT LEFT").await,
+            Frame::from("a")
+        );
+        assert_eq!(
+            run(&session, "LRANGE l 0 -1").await,
+            bulks(&["a", "b", "c"])
+        );
+
+        // a single element list isn't lost when it's emptied on the way
+        run(&session, "RPUSH one x").await;
+
+        assert_eq!(
+            run(&session, "LMOVE one one LEFT LEFT").await,
+            Frame::from("x")
+        );
+        assert_eq!(run(&session, "LRANGE one 0 -1").await, bulks(&["x"]));
+    }
+}
@@ -0,0 +1,116 @@
+use super::lmove::parse_end;
+use super::lpop::pop_many;
+use crate::commands::args::{parse_int, Opt, OptKind};
+use crate::prelude::*;
+use crate::storage::db::Db;
+
+pub struct LMPop;
+
+#[async_trait]
+impl CommandTrait for LMPop {
+    fn name(&self) -> &str {
+        "LMPOP"
+    }
+
+    fn arity(&self) -> i64 {
+        -4
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns multiple elements from a list after removing them. Deletes the list if the last element was popped."
+    }
+
+    fn since(&self) -> &str {
+        "7.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::MovableKeys]
+    }
+
+    fn numkeys_index(&self) -> i64 {
+        1
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let mpop = MPopArgs::parse(args)?;
+
+        let mut store = session.state.store.write().await;
+
+        Ok(mpop.pop(&mut store)?.unwrap_or(Frame::NullArray))
+    }
+}
+
+/// The arguments of `LMPOP` after the timeout of `BLMPOP`:
+/// `numkeys key [key ...] LEFT|RIGHT [COUNT count]`.
+pub(super) struct MPopArgs {
+    pub keys: Vec<Bytes>,
+    pub front: bool,
+    pub count: usize,
+}
+
+impl MPopArgs {
+    pub fn parse(args: &mut Args) -> Result<Self> {
+        let numkeys = parse_int(&args.next()?)
+            .ok()
+            .and_then(|numkeys| usize::try_from(numkeys).ok())
+            .filter(|numkeys| *numkeys > 0);
+
+        let Some(numkeys) = numkeys else {
+            return Err(ReplyError::Generic("numkeys should be greater than 0".into()).into());
+        };
+
+        // the keys have to be followed by LEFT or RIGHT
+        if numkeys >= args.len() {
+            return Err(ReplyError::Syntax.into());
+        }
+
+        let keys = (0..numkeys).map(|_| args.next()).collect::<Result<_>>()?;
+        let front = parse_end(&args.next()?)?;
+        let options = args.options(OPTIONS)?;
+
+        let count = match options.int("COUNT").map(usize::try_from) {
+            None => 1,
+            Some(Ok(count)) if count > 0 => count,
+            Some(_) => {
+                return Err(ReplyError::Generic("count should be greater than 0".into()).into())
+            }
+        };
+
+        Ok(Self { keys, front, count })
+    }
+
+    /// Pop from the first key holding a non empty list, replies the key and
+    /// the popped elements or `None` if every list is empty.
+    pub fn pop(&self, store: &mut Db) -> Result<Option<Frame>> {
+        for key in &self.keys {
+            let list = match store.get_mut(key) {
+                Some(Entry {
+                    value: Value::List(list),
+                    ..
+                }) => list,
+                Some(_) => return Err(ReplyError::WrongType.into()),
+                None => continue,
+            };
+
+            let popped = pop_many(list, self.count, self.front);
+
+            if list.is_empty() {
+                store.remove(key);
+            }
+
+            return Ok(Some(Frame::Array(VecDeque::from([
+                Frame::Bulk(key.to_vec()),
+                Frame::Array(popped),
+            ]))));
+        }
+
+        Ok(None)
+    }
+}
+
+const OPTIONS: &[Opt] = &[Opt::new("COUNT", OptKind::Int, 0)];
@@ -0,0 +1,133 @@
+use crate::prelude::*;
+use crate::storage::quicklist::QuickList;
+
+pub struct LPop;
+
+#[async_trait]
+impl CommandTrait for LPop {
+    fn name(&self) -> &str {
+        "LPOP"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the first elements in a list after removing it. Deletes the list if the last element was popped."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        pop(self.name(), args, session, true).await
+    }
+}
+
+/// Pop one element, or up to `count` when given, off the front or back of the
+/// list at `key`. An emptied list is deleted.
+pub(super) async fn pop(
+    command: &str,
+    args: &mut Args,
+    session: SessionRef,
+    front: bool,
+) -> Result<Frame> {
+    if args.len() > 2 {
+        return Err(ReplyError::wrong_arity(command).into());
+    }
+
+    let key = args.next()?;
+
+    let count = if args.is_empty() {
+        None
+    } else {
+        match usize::try_from(args.next_int()?) {
+            Ok(count) => Some(count),
+            Err(_) => return Ok(value_error!("value is out of range, must be positive")),
+        }
+    };
+
+    let mut store = session.state.store.write().await;
+
+    let list = match store.get_mut(&key) {
+        Some(Entry {
+            value: Value::List(list),
+            ..
+        }) => list,
+        Some(_) => return Ok(Frame::wrong_type()),
+        None if count.is_some() => return Ok(Frame::NullArray),
+        None => return Ok(Frame::Nil),
+    };
+
+    let mut popped = pop_many(list, count.unwrap_or(1), front);
+
+    if list.is_empty() {
+        store.remove(&key);
+    }
+
+    match count {
+        Some(_) => Ok(Frame::Array(popped)),
+        None => Ok(popped.pop_front().unwrap_or(Frame::Nil)),
+    }
+}
+
+/// Pop up to `count` elements off one end of the list.
+pub(super) fn pop_many(list: &mut QuickList, count: usize, front: bool) -> VecDeque<Frame> {
+    (0..count)
+        .map_while(|_| {
+            if front {
+                list.pop_front()
+            } else {
+                list.pop_back()
+            }
+        })
+        .map(Frame::Bulk)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{bulks, run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_pop() {
+        let session = session().await;
+
+        run(&session, "RPUSH l a b c d e").await;
+
+        assert_eq!(run(&session, "LPOP l").await, Frame::from("a"));
+        assert_eq!(run(&session, "RPOP l 2").await, bulks(&["e", "d"]));
+        assert_eq!(run(&session, "LPOP l 0").await, bulks(&[]));
+        assert_eq!(run(&session, "LPOP l 5").await, bulks(&["b", "c"]));
+        assert_eq!(run(&session, "EXISTS l").await, Frame::Integer(0));
+
+        // a count on a missing key replies a null array, not an empty one
+        assert_eq!(run(&session, "LPOP missing").await, Frame::Nil);
+        assert_eq!(run(&session, "LPOP missing 2").await, Frame::NullArray);
+        assert_eq!(run(&session, "RPOP missing 2").await, Frame::NullArray);
+
+        assert_eq!(
+            run(&session, "LPOP l -1").await,
+            value_error!("value is out of range, must be positive")
+        );
+        assert_eq!(
+            run(&session, "LPOP l 1 2").await,
+            Frame::from(ReplyError::wrong_arity("lpop"))
+        );
+    }
+}
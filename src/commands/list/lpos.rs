@@ -0,0 +1,177 @@
+use crate::commands::args::{Opt, OptKind};
+use crate::prelude::*;
+
+pub struct LPos;
+
+#[async_trait]
+impl CommandTrait for LPos {
+    fn name(&self) -> &str {
+        "LPOS"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the index of matching elements in a list."
+    }
+
+    fn since(&self) -> &str {
+        "6.0.6"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let value = args.next()?;
+        let options = args.options(OPTIONS)?;
+
+        let rank = options.int("RANK").unwrap_or(1);
+        let count = options.int("COUNT");
+        let max_len = options.int("MAXLEN").unwrap_or(0);
+
+        if rank == 0 {
+            return Ok(value_error!(
+                "RANK can't be zero: use 1 to start from the first match, 2 from the second ... or use negative to start from the end of the list"
+            ));
+        }
+
+        if rank == i64::MIN {
+            return Ok(value_error!(
+                "value is out of range, value must between -9223372036854775807 and 9223372036854775807"
+            ));
+        }
+
+        if count.is_some_and(|count| count < 0) {
+            return Ok(value_error!("COUNT can't be negative"));
+        }
+
+        if max_len < 0 {
+            return Ok(value_error!("MAXLEN can't be negative"));
+        }
+
+        let store = session.state.store.read().await;
+
+        let list = match store.get(&key) {
+            Some(Entry {
+                value: Value::List(list),
+                ..
+            }) => list,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None if count.is_some() => return Ok(Frame::Array(VecDeque::new())),
+            None => return Ok(Frame::Nil),
+        };
+
+        let len = list.len();
+
+        // a negative rank scans from the back, zero for COUNT and MAXLEN means no limit
+        let scanned: Box<dyn Iterator<Item = (usize, &Vec<u8>)>> = if rank > 0 {
+            Box::new(list.iter().enumerate())
+        } else {
+            Box::new(
+                list.iter()
+                    .rev()
+                    .enumerate()
+                    .map(|(i, value)| (len - 1 - i, value)),
+            )
+        };
+
+        let limit = match max_len {
+            0 => len,
+            max_len => usize::try_from(max_len).unwrap_or(len),
+        };
+
+        let mut matches = scanned
+            .take(limit)
+            .filter(|(_, element)| **element == value)
+            .skip(usize::try_from(rank.unsigned_abs() - 1).unwrap_or(usize::MAX))
+            .map(|(index, _)| Frame::Integer(index as i64));
+
+        match count {
+            Some(0) => Ok(Frame::Array(matches.collect())),
+            Some(count) => Ok(Frame::Array(
+                matches
+                    .take(usize::try_from(count).unwrap_or(usize::MAX))
+                    .collect(),
+            )),
+            None => Ok(matches.next().unwrap_or(Frame::Nil)),
+        }
+    }
+}
+
+const OPTIONS: &[Opt] = &[
+    Opt::new("RANK", OptKind::Int, 0),
+    Opt::new("COUNT", OptKind::Int, 0),
+    Opt::new("MAXLEN", OptKind::Int, 0),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{ints, run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_lpos() {
+        let session = session().await;
+
+        run(&session, "RPUSH l a b c 1 2 3 c c").await;
+
+        assert_eq!(run(&session, "LPOS l c").await, Frame::Integer(2));
+        assert_eq!(run(&session, "LPOS l x").await, Frame::Nil);
+
+        // RANK skips matches, a negative one from the back
+        assert_eq!(run(&session, "LPOS l c RANK 2").await, Frame::Integer(6));
+        assert_eq!(run(&session, "LPOS l c RANK -1").await, Frame::Integer(7));
+        assert_eq!(run(&session, "LPOS l c RANK 4").await, Frame::Nil);
+
+        // COUNT 0 means all of them
+        assert_eq!(run(&session, "LPOS l c COUNT 2").await, ints(&[2, 6]));
+        assert_eq!(run(&session, "LPOS l c COUNT 0").await, ints(&[2, 6, 7]));
+        assert_eq!(
+            run(&session, "LPOS l c RANK -2 COUNT 0").await,
+            ints(&[6, 2])
+        );
+        assert_eq!(run(&session, "LPOS l x COUNT 0").await, ints(&[]));
+
+        // MAXLEN only compares that many elements
+        assert_eq!(run(&session, "LPOS l c MAXLEN 2").await, Frame::Nil);
+        assert_eq!(
+            run(&session, "LPOS l c COUNT 0 MAXLEN 7").await,
+            ints(&[2, 6])
+        );
+        assert_eq!(
+            run(&session, "LPOS l c RANK -1 COUNT 0 MAXLEN 2").await,
+            ints(&[7, 6])
+        );
+
+        assert_eq!(run(&session, "LPOS missing c").await, Frame::Nil);
+        assert_eq!(run(&session, "LPOS missing c COUNT 1").await, ints(&[]));
+
+        assert_eq!(
+            run(&session, "LPOS l c RANK 0").await,
+            value_error!(
+                "RANK can't be zero: use 1 to start from the first match, 2 from the second ... or use negative to start from the end of the list"
+            )
+        );
+        assert_eq!(
+            run(&session, "LPOS l c COUNT -1").await,
+            value_error!("COUNT can't be negative")
+        );
+        assert_eq!(
+            run(&session, "LPOS l c MAXLEN -1").await,
+            value_error!("MAXLEN can't be negative")
+        );
+    }
+}
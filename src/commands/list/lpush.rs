@@ -0,0 +1,79 @@
+use crate::prelude::*;
+use crate::storage::quicklist::QuickList;
+
+pub struct LPush;
+
+#[async_trait]
+impl CommandTrait for LPush {
+    fn name(&self) -> &str {
+        "LPUSH"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Prepends one or more elements to a list. Creates the key if it doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        push(args, session, true, false).await
+    }
+}
+
+/// Push the remaining arguments one by one onto the front or back of the list
+/// at `key`. With `existing` nothing is created. Replies the new length.
+pub(super) async fn push(
+    args: &mut Args,
+    session: SessionRef,
+    front: bool,
+    existing: bool,
+) -> Result<Frame> {
+    let key = args.next()?.to_vec();
+    let values: Vec<Bytes> = args.rest().collect();
+
+    let mut store = session.state.store.write().await;
+
+    match store.get(&key) {
+        Some(Entry {
+            value: Value::List(_),
+            ..
+        }) => {}
+        Some(_) => return Ok(Frame::wrong_type()),
+        None if existing => return Ok(Frame::Integer(0)),
+        None => {}
+    }
+
+    let entry = store.get_or_insert_with(key, || Entry::new(Value::List(QuickList::new())));
+
+    let Value::List(list) = &mut entry.value else {
+        return Ok(Frame::wrong_type());
+    };
+
+    for value in values {
+        if front {
+            list.push_front(value.to_vec());
+        } else {
+            list.push_back(value.to_vec());
+        }
+    }
+
+    Ok(Frame::Integer(list.len() as i64))
+}
@@ -0,0 +1,39 @@
+use super::lpush::push;
+use crate::prelude::*;
+
+pub struct LPushX;
+
+#[async_trait]
+impl CommandTrait for LPushX {
+    fn name(&self) -> &str {
+        "LPUSHX"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Prepends one or more elements to a list only when the list exists."
+    }
+
+    fn since(&self) -> &str {
+        "2.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        push(args, session, true, true).await
+    }
+}
@@ -0,0 +1,63 @@
+use crate::commands::args::range;
+use crate::prelude::*;
+
+pub struct LRange;
+
+#[async_trait]
+impl CommandTrait for LRange {
+    fn name(&self) -> &str {
+        "LRANGE"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns a range of elements from a list."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let start = args.next_int()?;
+        let end = args.next_int()?;
+
+        let store = session.state.store.read().await;
+
+        let list = match store.get(&key) {
+            Some(Entry {
+                value: Value::List(list),
+                ..
+            }) => list,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => return Ok(Frame::Array(VecDeque::new())),
+        };
+
+        let Some((start, end)) = range(start, end, list.len()) else {
+            return Ok(Frame::Array(VecDeque::new()));
+        };
+
+        Ok(Frame::Array(
+            list.iter_from(start)
+                .take(end - start + 1)
+                .map(|value| Frame::Bulk(value.clone()))
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,101 @@
+use crate::prelude::*;
+
+pub struct LRem;
+
+#[async_trait]
+impl CommandTrait for LRem {
+    fn name(&self) -> &str {
+        "LREM"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes elements from a list. Deletes the list if the last element was removed."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let count = args.next_int()?;
+        let value = args.next()?;
+
+        let mut store = session.state.store.write().await;
+
+        let list = match store.get_mut(&key) {
+            Some(Entry {
+                value: Value::List(list),
+                ..
+            }) => list,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => return Ok(Frame::Integer(0)),
+        };
+
+        // a negative count removes from the back, zero removes every match
+        let limit = match count {
+            0 => usize::MAX,
+            count => usize::try_from(count.unsigned_abs()).unwrap_or(usize::MAX),
+        };
+
+        let removed = list.remove_matching(&value, limit, count < 0);
+
+        if list.is_empty() {
+            store.remove(&key);
+        }
+
+        Ok(Frame::Integer(removed as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{bulks, run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_lrem() {
+        let session = session().await;
+
+        run(&session, "RPUSH l x a x b x c x").await;
+
+        // a negative count removes from the back
+        assert_eq!(run(&session, "LREM l -2 x").await, Frame::Integer(2));
+        assert_eq!(
+            run(&session, "LRANGE l 0 -1").await,
+            bulks(&["x", "a", "x", "b", "c"])
+        );
+
+        assert_eq!(run(&session, "LREM l 1 x").await, Frame::Integer(1));
+        assert_eq!(
+            run(&session, "LRANGE l 0 -1").await,
+            bulks(&["a", "x", "b", "c"])
+        );
+
+        assert_eq!(run(&session, "LREM l 0 x").await, Frame::Integer(1));
+        assert_eq!(run(&session, "LREM l 0 y").await, Frame::Integer(0));
+        assert_eq!(run(&session, "LREM missing 0 x").await, Frame::Integer(0));
+
+        // an emptied list is deleted
+        run(&session, "RPUSH same y y").await;
+
+        assert_eq!(run(&session, "LREM same -5 y").await, Frame::Integer(2));
+        assert_eq!(run(&session, "EXISTS same").await, Frame::Integer(0));
+    }
+}
@@ -0,0 +1,57 @@
+use super::lindex::index_of;
+use crate::prelude::*;
+
+pub struct LSet;
+
+#[async_trait]
+impl CommandTrait for LSet {
+    fn name(&self) -> &str {
+        "LSET"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Sets the value of an element in a list by its index."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let index = args.next_int()?;
+        let value = args.next()?.to_vec();
+
+        let mut store = session.state.store.write().await;
+
+        let list = match store.get_mut(&key) {
+            Some(Entry {
+                value: Value::List(list),
+                ..
+            }) => list,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => return Ok(value_error!("no such key")),
+        };
+
+        match index_of(index, list.len()) {
+            Some(index) if list.set(index, value) => Ok(Frame::Ok),
+            _ => Ok(value_error!("index out of range")),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::commands::args::range;
+use crate::prelude::*;
+
+pub struct LTrim;
+
+#[async_trait]
+impl CommandTrait for LTrim {
+    fn name(&self) -> &str {
+        "LTRIM"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes elements from both ends a list. Deletes the list if all elements were trimmed."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let start = args.next_int()?;
+        let end = args.next_int()?;
+
+        let mut store = session.state.store.write().await;
+
+        let list = match store.get_mut(&key) {
+            Some(Entry {
+                value: Value::List(list),
+                ..
+            }) => list,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => return Ok(Frame::Ok),
+        };
+
+        match range(start, end, list.len()) {
+            Some((start, end)) => list.trim(start, end),
+            None => list.clear(),
+        }
+
+        if list.is_empty() {
+            store.remove(&key);
+        }
+
+        Ok(Frame::Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{bulks, run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_ltrim() {
+        let session = session().await;
+
+        run(&session, "RPUSH l a b c d e").await;
+
+        assert_eq!(run(&session, "LTRIM l 1 -2").await, Frame::Ok);
+        assert_eq!(
+            run(&session, "LRANGE l 0 -1").await,
+            bulks(&["b", "c", "d"])
+        );
+        assert_eq!(run(&session, "LTRIM l -100 100").await, Frame::Ok);
+        assert_eq!(run(&session, "LLEN l").await, Frame::Integer(3));
+
+        // an empty range deletes the key
+        assert_eq!(run(&session, "LTRIM l 2 1").await, Frame::Ok);
+        assert_eq!(run(&session, "EXISTS l").await, Frame::Integer(0));
+
+        run(&session, "RPUSH l a b").await;
+
+        assert_eq!(run(&session, "LTRIM l 5 10").await, Frame::Ok);
+        assert_eq!(run(&session, "EXISTS l").await, Frame::Integer(0));
+        assert_eq!(run(&session, "LTRIM missing 0 1").await, Frame::Ok);
+    }
+}
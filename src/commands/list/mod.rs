@@ -0,0 +1,45 @@
+mod blmove;
+mod blmpop;
+mod blpop;
+mod brpop;
+mod brpoplpush;
+mod lindex;
+mod linsert;
+mod llen;
+mod lmove;
+mod lmpop;
+mod lpop;
+mod lpos;
+mod lpush;
+mod lpushx;
+mod lrange;
+mod lrem;
+mod lset;
+mod ltrim;
+mod rpop;
+mod rpoplpush;
+mod rpush;
+mod rpushx;
+
+pub use blmove::BLMove;
+pub use blmpop::BLMPop;
+pub use blpop::BLPop;
+pub use brpop::BRPop;
+pub use brpoplpush::BRPopLPush;
+pub use lindex::LIndex;
+pub use linsert::LInsert;
+pub use llen::LLen;
+pub use lmove::LMove;
+pub use lmpop::LMPop;
+pub use lpop::LPop;
+pub use lpos::LPos;
+pub use lpush::LPush;
+pub use lpushx::LPushX;
+pub use lrange::LRange;
+pub use lrem::LRem;
+pub use lset::LSet;
+pub use ltrim::LTrim;
+pub use rpop::RPop;
+pub use rpoplpush::RPopLPush;
+pub use rpush::RPush;
+pub use rpushx::RPushX;
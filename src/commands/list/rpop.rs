@@ -0,0 +1,39 @@
+use super::lpop::pop;
+use crate::prelude::*;
+
+pub struct RPop;
+
+#[async_trait]
+impl CommandTrait for RPop {
+    fn name(&self) -> &str {
+        "RPOP"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns and removes the last elements of the list. Deletes the list if the last element was popped."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        pop(self.name(), args, session, false).await
+    }
+}
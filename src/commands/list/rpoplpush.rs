@@ -0,0 +1,46 @@
+use super::lmove::lmove;
+use crate::prelude::*;
+
+pub struct RPopLPush;
+
+#[async_trait]
+impl CommandTrait for RPopLPush {
+    fn name(&self) -> &str {
+        "RPOPLPUSH"
+    }
+
+    fn arity(&self) -> i64 {
+        3
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the last element of a list after removing and pushing it to another list. Deletes the list if the last element was popped."
+    }
+
+    fn since(&self) -> &str {
+        "1.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 2, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let source = args.next()?;
+        let destination = args.next()?.to_vec();
+
+        let mut store = session.state.store.write().await;
+
+        let moved = lmove(&mut store, &source, destination, false, true)?;
+
+        Ok(moved.map_or(Frame::Nil, Frame::Bulk))
+    }
+}
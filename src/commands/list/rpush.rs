@@ -0,0 +1,39 @@
+use super::lpush::push;
+use crate::prelude::*;
+
+pub struct RPush;
+
+#[async_trait]
+impl CommandTrait for RPush {
+    fn name(&self) -> &str {
+        "RPUSH"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "list"
+    }
+
+    fn summary(&self) -> &str {
+        "Appends one or more elements to a list. Creates the key if it doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        push(args, session, false, false).await
+    }
+}
@@ -1,6 +1,12 @@
+pub mod args;
+pub mod bitmap;
 pub mod core;
 pub mod expire;
 pub mod hash;
+pub mod list;
+pub mod set;
+pub mod string;
+pub mod zset;
 
 use std::sync::Arc;
 
@@ -8,16 +14,112 @@ use tokio::sync::RwLock;
 
 use crate::prelude::*;
 
+/// Traits advertised through `COMMAND`, named like the Redis command flags.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommandFlag {
+    Write,
+    ReadOnly,
+    DenyOom,
+    NoScript,
+    Loading,
+    Stale,
+    Fast,
+    NoAuth,
+    Blocking,
+    /// The keys can't be told by the key range alone, see
+    /// [`CommandTrait::numkeys_index`].
+    MovableKeys,
+}
+
+impl CommandFlag {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Write => "write",
+            Self::ReadOnly => "readonly",
+            Self::DenyOom => "denyoom",
+            Self::NoScript => "noscript",
+            Self::Loading => "loading",
+            Self::Stale => "stale",
+            Self::Fast => "fast",
+            Self::NoAuth => "no_auth",
+            Self::Blocking => "blocking",
+            Self::MovableKeys => "movablekeys",
+        }
+    }
+}
+
 #[async_trait]
 pub trait CommandTrait {
     fn name(&self) -> &str;
 
-    async fn handle_command(
-        &self,
-        writer: &mut WriteHalf,
-        args: &mut VecDeque<Value>,
-        session: SessionRef,
-    ) -> Result<()>;
+    /// Number of arguments including the command name, a negative arity means
+    /// at least that many.
+    fn arity(&self) -> i64;
+
+    /// The documentation group, e.g. `string` or `generic`.
+    fn group(&self) -> &str;
+
+    /// One line description shown by `COMMAND DOCS`.
+    fn summary(&self) -> &str;
+
+    /// The Redis version the command first appeared in.
+    fn since(&self) -> &str;
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[]
+    }
+
+    /// Positions of the keys as `(first, last, step)`, counted from the command
+    /// name. A negative last key counts from the end, `(0, 0, 0)` means none.
+    fn key_range(&self) -> (i64, i64, i64) {
+        (0, 0, 0)
+    }
+
+    /// For commands flagged `MovableKeys`, the position of the `numkeys`
+    /// argument the keys follow, counted from the command name. These are on
+    /// top of the ones in the key range, 0 means none.
+    fn numkeys_index(&self) -> i64 {
+        0
+    }
+
+    /// ACL categories, by default derived from the flags and the group.
+    fn acl_categories(&self) -> Vec<&'static str> {
+        let flags = self.flags();
+        let mut categories = Vec::new();
+
+        for flag in flags {
+            match flag {
+                CommandFlag::Write => categories.push("@write"),
+                CommandFlag::ReadOnly => categories.push("@read"),
+                CommandFlag::Blocking => categories.push("@blocking"),
+                _ => {}
+            }
+        }
+
+        if flags.contains(&CommandFlag::Fast) {
+            categories.push("@fast");
+        } else {
+            categories.push("@slow");
+        }
+
+        match self.group() {
+            "generic" => categories.push("@keyspace"),
+            "string" => categories.push("@string"),
+            "list" => categories.push("@list"),
+            "set" => categories.push("@set"),
+            "sorted-set" => categories.push("@sortedset"),
+            "hash" => categories.push("@hash"),
+            "bitmap" => categories.push("@bitmap"),
+            "connection" => categories.push("@connection"),
+            _ => {}
+        }
+
+        categories
+    }
+
+    /// Run the command and produce its reply, the caller encodes it for the
+    /// protocol version negotiated by the session.
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame>;
 }
 
 macro_rules! register_commands {
@@ -28,9 +130,11 @@ macro_rules! register_commands {
     };
 }
 
+pub type CommandRef = Arc<dyn CommandTrait + Send + Sync>;
+
 #[derive(Clone, Default)]
 pub struct CommandHandler {
-    commands: Arc<RwLock<HashMap<String, Box<dyn CommandTrait + Send + Sync>>>>,
+    commands: Arc<RwLock<HashMap<String, CommandRef>>>,
 }
 
 impl CommandHandler {
@@ -48,11 +152,166 @@ impl CommandHandler {
             core::Exists,
             core::FlushDB,
             core::Info,
+            core::Type,
+            core::Command,
+        );
+
+        register_commands!(
+            self,
+            expire::Expire,
+            expire::PExpire,
+            expire::ExpireAt,
+            expire::PExpireAt,
+            expire::Ttl,
+            expire::PTtl,
+            expire::ExpireTime,
+            expire::PExpireTime,
+            expire::Persist,
+            expire::SetEx,
+            expire::PSetEx,
+        );
+
+        register_commands!(
+            self,
+            string::Incr,
+            string::Decr,
+            string::IncrBy,
+            string::DecrBy,
+            string::IncrByFloat,
+            string::Append,
+            string::StrLen,
+            string::GetRange,
+            string::SetRange,
+            string::GetDel,
+            string::GetEx,
+            string::SetNx,
+            string::MSetNx,
+            string::Lcs,
         );
 
-        register_commands!(self, expire::Expire, expire::Ttl, expire::SetEx);
+        register_commands!(
+            self,
+            bitmap::SetBit,
+            bitmap::GetBit,
+            bitmap::BitCount,
+            bitmap::BitPos,
+            bitmap::BitOp,
+            bitmap::BitField,
+            bitmap::BitFieldRo,
+        );
 
-        register_commands!(self, hash::HSet, hash::HGetAll);
+        register_commands!(
+            self,
+            list::LPush,
+            list::RPush,
+            list::LPushX,
+            list::RPushX,
+            list::LPop,
+            list::RPop,
+            list::LLen,
+            list::LRange,
+            list::LIndex,
+            list::LSet,
+            list::LRem,
+            list::LTrim,
+            list::LInsert,
+            list::LPos,
+            list::LMove,
+            list::RPopLPush,
+            list::LMPop,
+            list::BLPop,
+            list::BRPop,
+            list::BLMove,
+            list::BRPopLPush,
+            list::BLMPop,
+        );
+
+        register_commands!(
+            self,
+            set::SAdd,
+            set::SRem,
+            set::SMembers,
+            set::SIsMember,
+            set::SMIsMember,
+            set::SCard,
+            set::SPop,
+            set::SRandMember,
+            set::SMove,
+            set::SInter,
+            set::SUnion,
+            set::SDiff,
+            set::SInterStore,
+            set::SUnionStore,
+            set::SDiffStore,
+            set::SInterCard,
+        );
+
+        register_commands!(
+            self,
+            zset::ZAdd,
+            zset::ZRem,
+            zset::ZScore,
+            zset::ZMScore,
+            zset::ZIncrBy,
+            zset::ZCard,
+            zset::ZRank,
+            zset::ZRevRank,
+            zset::ZCount,
+            zset::ZLexCount,
+            zset::ZRange,
+            zset::ZRevRange,
+            zset::ZRangeByScore,
+            zset::ZRevRangeByScore,
+            zset::ZRangeByLex,
+            zset::ZRevRangeByLex,
+            zset::ZRangeStore,
+            zset::ZRemRangeByRank,
+            zset::ZRemRangeByScore,
+            zset::ZRemRangeByLex,
+            zset::ZUnion,
+            zset::ZInter,
+            zset::ZDiff,
+            zset::ZUnionStore,
+            zset::ZInterStore,
+            zset::ZDiffStore,
+            zset::ZPopMin,
+            zset::ZPopMax,
+            zset::BZPopMin,
+            zset::BZPopMax,
+            zset::ZMPop,
+            zset::BZMPop,
+            zset::ZRandMember,
+        );
+
+        register_commands!(
+            self,
+            hash::HSet,
+            hash::HGetAll,
+            hash::HGet,
+            hash::HMGet,
+            hash::HDel,
+            hash::HExists,
+            hash::HLen,
+            hash::HKeys,
+            hash::HVals,
+            hash::HSetNx,
+            hash::HStrLen,
+            hash::HIncrBy,
+            hash::HIncrByFloat,
+            hash::HRandField,
+            hash::HMSet,
+            hash::HExpire,
+            hash::HPExpire,
+            hash::HExpireAt,
+            hash::HPExpireAt,
+            hash::HTtl,
+            hash::HPTtl,
+            hash::HExpireTime,
+            hash::HPExpireTime,
+            hash::HPersist,
+            hash::HGetEx,
+            hash::HSetEx,
+        );
     }
 
     async fn register_command<C>(&mut self, command: C)
@@ -62,101 +321,97 @@ impl CommandHandler {
         self.commands
             .write()
             .await
-            .insert(command.name().to_uppercase(), Box::new(command));
+            .insert(command.name().to_uppercase(), Arc::new(command));
+    }
+
+    /// Look up a command by name, case insensitively.
+    pub async fn get(&self, name: &[u8]) -> Option<CommandRef> {
+        self.commands
+            .read()
+            .await
+            .get(&String::from_utf8_lossy(name).to_uppercase())
+            .cloned()
+    }
+
+    /// Whether the command may block the client, an unknown one doesn't.
+    pub async fn is_blocking(&self, name: &[u8]) -> bool {
+        self.get(name)
+            .await
+            .is_some_and(|command| command.flags().contains(&CommandFlag::Blocking))
+    }
+
+    /// Every registered command, sorted by name.
+    pub async fn all(&self) -> Vec<CommandRef> {
+        let mut commands: Vec<_> = self.commands.read().await.values().cloned().collect();
+        commands.sort_by(|a, b| a.name().cmp(b.name()));
+
+        commands
     }
 
     pub async fn handle_command(
         &self,
-        writer: &mut WriteHalf<'_>,
-        args: &mut VecDeque<Value>,
+        mut args: VecDeque<Bytes>,
         session: SessionRef,
-    ) -> Result<()> {
-        let command = match args.pop_front() {
-            Some(Value::String(command)) => command.to_uppercase(),
-            _ => return value_error!("Invalid command").to_resp2(writer).await,
+    ) -> Result<Frame> {
+        let Some(name) = args.pop_front() else {
+            return Ok(value_error!("empty command"));
+        };
+
+        let Some(command) = self.get(&name).await else {
+            return Ok(ReplyError::unknown_command(&name, &args).into());
         };
 
-        match self.commands.read().await.get(&command) {
-            Some(command) => command.handle_command(writer, args, session).await,
-            None => value_error!("Unknown command").to_resp2(writer).await,
+        // the arity counts the command name as well
+        let argc = args.len() as i64 + 1;
+        let arity = command.arity();
+
+        if (arity > 0 && argc != arity) || argc < -arity {
+            return Ok(ReplyError::wrong_arity(command.name()).into());
+        }
+
+        let mut args = Args::new(command.name(), args);
+
+        // failed commands are answered with an error reply, anything else ends the connection
+        match command.handle_command(&mut args, session).await {
+            Err(Error::Reply(e)) => Ok(e.into()),
+            result => result,
         }
     }
 }
 
-// pub async fn handle_command(
-//     command: &str,
-//     args: &mut VecDeque<Value>,
-//     store: &mut Store,
-// ) -> Result<Value> {
-//     let response = match command {
-
-//         "HGET" => {
-//             if args.len() != 2 {
-//                 return Ok(value_error!("Invalid number of arguments"));
-//             }
-
-//             let key = match args.pop_front() {
-//                 Some(Value::String(key)) => key,
-//                 _ => {
-//                     return Ok(value_error!("Invalid key"));
-//                 }
-//             };
-
-//             let field = match args.pop_front() {
-//                 Some(Value::String(field)) => field,
-//                 _ => {
-//                     return Ok(value_error!("Invalid field"));
-//                 }
-//             };
-
-//             store.hget(&key, &field).await
-//         }
-
-//         "HSET" => {
-//             if args.len() < 3 || args.len() % 2 != 1 {
-//                 return Ok(value_error!("Invalid number of arguments"));
-//             }
-
-//             let key = match args.pop_front() {
-//                 Some(Value::String(key)) => key,
-//                 _ => {
-//                     return Ok(value_error!("Invalid key"));
-//                 }
-//             };
-
-//             let mut hashmap = HashMap::new();
-
-//             for kv in args.iter().collect::<Vec<_>>().chunks_exact(2) {
-//                 let field = match kv[0].to_owned() {
-//                     Value::String(field) => field,
-//                     _ => {
-//                         return Ok(value_error!("Invalid field"));
-//                     }
-//                 };
-
-//                 hashmap.insert(field, kv[1].to_owned());
-//             }
-
-//             store.hset(key, hashmap).await
-//         }
-
-//         "HGETALL" => {
-//             if args.len() != 1 {
-//                 return Ok(value_error!("Invalid number of arguments"));
-//             }
-
-//             let key = match args.pop_front() {
-//                 Some(Value::String(key)) => key,
-//                 _ => {
-//                     return Ok(value_error!("Invalid key"));
-//                 }
-//             };
-
-//             store.hgetall(&key).await
-//         }
-
-//         _ => value_error!("Unknown command"),
-//     };
-
-//     Ok(response)
-// }
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+    use crate::protocol::Limits;
+    use crate::session::state::State;
+
+    /// A session on an empty keyspace with every command registered.
+    pub async fn session() -> SessionRef {
+        Session::new(State::new(Limits::default()).await)
+    }
+
+    /// Run a command line, split on spaces, and give its reply.
+    pub async fn run(session: &SessionRef, line: &str) -> Frame {
+        let args = line
+            .split(' ')
+            .map(|arg| Bytes::copy_from_slice(arg.as_bytes()))
+            .collect();
+
+        session
+            .state
+            .commands
+            .handle_command(args, session.clone())
+            .await
+            .expect("only reply errors are expected")
+    }
+
+    /// An array of bulk strings, the reply of most multi-element commands.
+    pub fn bulks(items: &[&str]) -> Frame {
+        Frame::Array(items.iter().map(|item| Frame::from(*item)).collect())
+    }
+
+    /// An array of integers.
+    pub fn ints(items: &[i64]) -> Frame {
+        Frame::Array(items.iter().copied().map(Frame::Integer).collect())
+    }
+}
@@ -0,0 +1,33 @@
+mod sadd;
+mod scard;
+mod sdiff;
+mod sdiffstore;
+mod sinter;
+mod sintercard;
+mod sinterstore;
+mod sismember;
+mod smembers;
+mod smismember;
+mod smove;
+mod spop;
+mod srandmember;
+mod srem;
+mod sunion;
+mod sunionstore;
+
+pub use sadd::SAdd;
+pub use scard::SCard;
+pub use sdiff::SDiff;
+pub use sdiffstore::SDiffStore;
+pub use sinter::SInter;
+pub use sintercard::SInterCard;
+pub use sinterstore::SInterStore;
+pub use sismember::SIsMember;
+pub use smembers::SMembers;
+pub use smismember::SMIsMember;
+pub use smove::SMove;
+pub use spop::SPop;
+pub use srandmember::SRandMember;
+pub use srem::SRem;
+pub use sunion::SUnion;
+pub use sunionstore::SUnionStore;
@@ -0,0 +1,62 @@
+use crate::prelude::*;
+use crate::storage::set::SetValue;
+
+pub struct SAdd;
+
+#[async_trait]
+impl CommandTrait for SAdd {
+    fn name(&self) -> &str {
+        "SADD"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "set"
+    }
+
+    fn summary(&self) -> &str {
+        "Adds one or more members to a set. Creates the key if it doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let members: Vec<Bytes> = args.rest().collect();
+
+        let mut store = session.state.store.write().await;
+
+        if store
+            .get(&key)
+            .is_some_and(|entry| !matches!(entry.value, Value::Set(_)))
+        {
+            return Ok(Frame::wrong_type());
+        }
+
+        let entry = store.get_or_insert_with(key, || Entry::new(Value::Set(SetValue::new())));
+
+        let Value::Set(set) = &mut entry.value else {
+            return Ok(Frame::wrong_type());
+        };
+
+        let added = members
+            .into_iter()
+            .filter(|member| set.insert(member.to_vec()))
+            .count();
+
+        Ok(Frame::Integer(added as i64))
+    }
+}
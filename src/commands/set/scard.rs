@@ -0,0 +1,49 @@
+use crate::prelude::*;
+
+pub struct SCard;
+
+#[async_trait]
+impl CommandTrait for SCard {
+    fn name(&self) -> &str {
+        "SCARD"
+    }
+
+    fn arity(&self) -> i64 {
+        2
+    }
+
+    fn group(&self) -> &str {
+        "set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the number of members in a set."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let store = session.state.store.read().await;
+
+        match store.get(&key) {
+            Some(Entry {
+                value: Value::Set(set),
+                ..
+            }) => Ok(Frame::Integer(set.len() as i64)),
+            Some(_) => Ok(Frame::wrong_type()),
+            None => Ok(Frame::Integer(0)),
+        }
+    }
+}
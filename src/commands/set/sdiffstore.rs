@@ -0,0 +1,39 @@
+use super::sinter::{store_combined, SetOp};
+use crate::prelude::*;
+
+pub struct SDiffStore;
+
+#[async_trait]
+impl CommandTrait for SDiffStore {
+    fn name(&self) -> &str {
+        "SDIFFSTORE"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "set"
+    }
+
+    fn summary(&self) -> &str {
+        "Stores the difference of multiple sets in a key."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, -1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        store_combined(args, session, SetOp::Diff).await
+    }
+}
@@ -0,0 +1,227 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use crate::prelude::*;
+use crate::storage::db::Db;
+use crate::storage::set::SetValue;
+
+pub struct SInter;
+
+#[async_trait]
+impl CommandTrait for SInter {
+    fn name(&self) -> &str {
+        "SINTER"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the intersect of multiple sets."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, -1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        reply_combined(args, session, SetOp::Inter).await
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum SetOp {
+    Inter,
+    Union,
+    Diff,
+}
+
+/// Combine the sets at `keys`, a missing key counts as an empty set. An
+/// intersection stops once it has `limit` members.
+///
+/// Intersections walk the smallest set and look its members up in the others,
+/// so the work is bounded by the smallest set rather than the largest.
+pub(super) fn combine<'a>(
+    store: &'a Db,
+    keys: &[Bytes],
+    op: SetOp,
+    limit: usize,
+) -> Result<Vec<Cow<'a, [u8]>>> {
+    // every key is type checked before anything is computed
+    let mut sets = Vec::with_capacity(keys.len());
+
+    for key in keys {
+        match store.get(key) {
+            Some(Entry {
+                value: Value::Set(set),
+                ..
+            }) => sets.push(Some(set)),
+            Some(_) => return Err(ReplyError::WrongType.into()),
+            None => sets.push(None),
+        }
+    }
+
+    match op {
+        SetOp::Inter => {
+            let Some(mut sets) = sets.into_iter().collect::<Option<Vec<&SetValue>>>() else {
+                return Ok(Vec::new());
+            };
+
+            sets.sort_by_key(|set| set.len());
+
+            let Some((smallest, rest)) = sets.split_first() else {
+                return Ok(Vec::new());
+            };
+
+            Ok(smallest
+                .iter()
+                .filter(|member| rest.iter().all(|set| set.contains(member)))
+                .take(limit)
+                .collect())
+        }
+        SetOp::Union => {
+            let mut members = HashSet::new();
+
+            for set in sets.into_iter().flatten() {
+                members.extend(set.iter());
+            }
+
+            Ok(members.into_iter().collect())
+        }
+        SetOp::Diff => {
+            let Some((Some(first), rest)) = sets.split_first() else {
+                return Ok(Vec::new());
+            };
+
+            Ok(first
+                .iter()
+                .filter(|member| rest.iter().flatten().all(|set| !set.contains(member)))
+                .collect())
+        }
+    }
+}
+
+/// Reply with the combination of the sets at the remaining arguments.
+pub(super) async fn reply_combined(
+    args: &mut Args,
+    session: SessionRef,
+    op: SetOp,
+) -> Result<Frame> {
+    let keys: Vec<Bytes> = args.rest().collect();
+
+    let store = session.state.store.read().await;
+
+    let members = combine(&store, &keys, op, usize::MAX)?;
+
+    Ok(Frame::Set(
+        members
+            .into_iter()
+            .map(|member| Frame::Bulk(member.into_owned()))
+            .collect(),
+    ))
+}
+
+/// Store the combination of the sets at the remaining arguments in the first
+/// one, replacing whatever it held. An empty result deletes it.
+pub(super) async fn store_combined(
+    args: &mut Args,
+    session: SessionRef,
+    op: SetOp,
+) -> Result<Frame> {
+    let destination = args.next()?.to_vec();
+    let keys: Vec<Bytes> = args.rest().collect();
+
+    let mut store = session.state.store.write().await;
+
+    let set: SetValue = combine(&store, &keys, op, usize::MAX)?
+        .into_iter()
+        .map(Cow::into_owned)
+        .collect();
+
+    let len = set.len();
+
+    if set.is_empty() {
+        store.remove(&destination);
+    } else {
+        store.insert(destination, Entry::new(Value::Set(set)));
+    }
+
+    Ok(Frame::Integer(len as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert(store: &mut Db, key: &str, members: &[&str]) {
+        let set = members
+            .iter()
+            .map(|member| member.as_bytes().to_vec())
+            .collect();
+        store.insert(key.as_bytes().to_vec(), Entry::new(Value::Set(set)));
+    }
+
+    fn sorted(store: &Db, keys: &[&str], op: SetOp, limit: usize) -> Vec<String> {
+        let keys: Vec<Bytes> = keys
+            .iter()
+            .map(|key| Bytes::from(key.to_string()))
+            .collect();
+
+        let mut members: Vec<String> = combine(store, &keys, op, limit)
+            .unwrap()
+            .iter()
+            .map(|member| String::from_utf8_lossy(member).into_owned())
+            .collect();
+
+        members.sort();
+        members
+    }
+
+    #[test]
+    fn test_set_combine() {
+        let mut store = Db::default();
+
+        insert(&mut store, "a", &["1", "2", "3", "x"]);
+        insert(&mut store, "b", &["2", "3", "y"]);
+        insert(&mut store, "c", &["3", "x", "2"]);
+
+        assert_eq!(
+            sorted(&store, &["a", "b", "c"], SetOp::Inter, usize::MAX),
+            ["2", "3"]
+        );
+        assert_eq!(sorted(&store, &["a", "b", "c"], SetOp::Inter, 1).len(), 1);
+        assert!(sorted(&store, &["a", "missing"], SetOp::Inter, usize::MAX).is_empty());
+
+        assert_eq!(
+            sorted(&store, &["b", "missing", "c"], SetOp::Union, usize::MAX),
+            ["2", "3", "x", "y"]
+        );
+        assert_eq!(
+            sorted(&store, &["a", "b", "missing"], SetOp::Diff, usize::MAX),
+            ["1", "x"]
+        );
+        assert!(sorted(&store, &["missing", "a"], SetOp::Diff, usize::MAX).is_empty());
+
+        store.insert(b"s".to_vec(), Entry::new(Value::from(b"v".to_vec())));
+        assert!(combine(
+            &store,
+            &[Bytes::from("missing"), Bytes::from("s")],
+            SetOp::Inter,
+            1
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use super::sinter::{combine, SetOp};
+use crate::commands::args::{parse_int, Opt, OptKind};
+use crate::prelude::*;
+
+pub struct SInterCard;
+
+#[async_trait]
+impl CommandTrait for SInterCard {
+    fn name(&self) -> &str {
+        "SINTERCARD"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the number of members of the intersect of multiple sets."
+    }
+
+    fn since(&self) -> &str {
+        "7.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::MovableKeys]
+    }
+
+    fn numkeys_index(&self) -> i64 {
+        1
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let numkeys = parse_int(&args.next()?)
+            .ok()
+            .and_then(|numkeys| usize::try_from(numkeys).ok())
+            .filter(|numkeys| *numkeys > 0);
+
+        let Some(numkeys) = numkeys else {
+            return Ok(value_error!("numkeys should be greater than 0"));
+        };
+
+        if numkeys > args.len() {
+            return Ok(value_error!(
+                "Number of keys can't be greater than number of args"
+            ));
+        }
+
+        let keys: Vec<Bytes> = (0..numkeys).map(|_| args.next()).collect::<Result<_>>()?;
+        let options = args.options(OPTIONS)?;
+
+        // a limit of zero means no limit
+        let limit = match options.int("LIMIT").map(usize::try_from) {
+            None | Some(Ok(0)) => usize::MAX,
+            Some(Ok(limit)) => limit,
+            Some(Err(_)) => return Ok(value_error!("LIMIT can't be negative")),
+        };
+
+        let store = session.state.store.read().await;
+
+        let members = combine(&store, &keys, SetOp::Inter, limit)?;
+
+        Ok(Frame::Integer(members.len() as i64))
+    }
+}
+
+const OPTIONS: &[Opt] = &[Opt::new("LIMIT", OptKind::Int, 0)];
@@ -0,0 +1,53 @@
+use crate::prelude::*;
+
+pub struct SMembers;
+
+#[async_trait]
+impl CommandTrait for SMembers {
+    fn name(&self) -> &str {
+        "SMEMBERS"
+    }
+
+    fn arity(&self) -> i64 {
+        2
+    }
+
+    fn group(&self) -> &str {
+        "set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns all members of a set."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let store = session.state.store.read().await;
+
+        match store.get(&key) {
+            Some(Entry {
+                value: Value::Set(set),
+                ..
+            }) => Ok(Frame::Set(
+                set.iter()
+                    .map(|member| Frame::Bulk(member.into_owned()))
+                    .collect(),
+            )),
+            Some(_) => Ok(Frame::wrong_type()),
+            None => Ok(Frame::Set(Vec::new())),
+        }
+    }
+}
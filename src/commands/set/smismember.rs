@@ -0,0 +1,86 @@
+use crate::prelude::*;
+
+pub struct SMIsMember;
+
+#[async_trait]
+impl CommandTrait for SMIsMember {
+    fn name(&self) -> &str {
+        "SMISMEMBER"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "set"
+    }
+
+    fn summary(&self) -> &str {
+        "Determines whether multiple members belong to a set."
+    }
+
+    fn since(&self) -> &str {
+        "6.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let store = session.state.store.read().await;
+
+        let set = match store.get(&key) {
+            Some(Entry {
+                value: Value::Set(set),
+                ..
+            }) => Some(set),
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => None,
+        };
+
+        Ok(Frame::Array(
+            args.rest()
+                .map(|member| set.is_some_and(|set| set.contains(&member)))
+                .map(|found| Frame::Integer(found.into()))
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{ints, run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_smismember() {
+        let session = session().await;
+
+        run(&session, "SADD s a 1 b").await;
+
+        assert_eq!(
+            run(&session, "SMISMEMBER s a x 1 b").await,
+            ints(&[1, 0, 1, 1])
+        );
+        assert_eq!(run(&session, "SMISMEMBER missing a b").await, ints(&[0, 0]));
+
+        run(&session, "SET text abc").await;
+
+        assert_eq!(
+            run(&session, "SMISMEMBER text a").await,
+            Frame::wrong_type()
+        );
+        assert_eq!(
+            run(&session, "SMISMEMBER s").await,
+            Frame::from(ReplyError::wrong_arity("smismember"))
+        );
+    }
+}
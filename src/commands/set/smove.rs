@@ -0,0 +1,123 @@
+use crate::prelude::*;
+use crate::storage::set::SetValue;
+
+pub struct SMove;
+
+#[async_trait]
+impl CommandTrait for SMove {
+    fn name(&self) -> &str {
+        "SMOVE"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "set"
+    }
+
+    fn summary(&self) -> &str {
+        "Moves a member from one set to another."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 2, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let source = args.next()?;
+        let destination = args.next()?.to_vec();
+        let member = args.next()?;
+
+        let mut store = session.state.store.write().await;
+
+        let found = match store.get(&source) {
+            Some(Entry {
+                value: Value::Set(set),
+                ..
+            }) => set.contains(&member),
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => false,
+        };
+
+        if store
+            .get(&destination)
+            .is_some_and(|entry| !matches!(entry.value, Value::Set(_)))
+        {
+            return Ok(Frame::wrong_type());
+        }
+
+        // moving within the same set changes nothing
+        if !found || source == destination {
+            return Ok(Frame::Integer(found.into()));
+        }
+
+        if let Some(Entry {
+            value: Value::Set(set),
+            ..
+        }) = store.get_mut(&source)
+        {
+            set.remove(&member);
+
+            if set.is_empty() {
+                store.remove(&source);
+            }
+        }
+
+        let entry =
+            store.get_or_insert_with(destination, || Entry::new(Value::Set(SetValue::new())));
+
+        if let Value::Set(set) = &mut entry.value {
+            set.insert(member.to_vec());
+        }
+
+        Ok(Frame::Integer(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_smove() {
+        let session = session().await;
+
+        run(&session, "SADD src a b").await;
+
+        assert_eq!(run(&session, "SMOVE src dst a").await, Frame::Integer(1));
+        assert_eq!(run(&session, "SISMEMBER dst a").await, Frame::Integer(1));
+        assert_eq!(run(&session, "SISMEMBER src a").await, Frame::Integer(0));
+        assert_eq!(run(&session, "SMOVE src dst x").await, Frame::Integer(0));
+        assert_eq!(
+            run(&session, "SMOVE missing dst a").await,
+            Frame::Integer(0)
+        );
+
+        // within the same set nothing changes
+        assert_eq!(run(&session, "SMOVE src src b").await, Frame::Integer(1));
+        assert_eq!(run(&session, "SCARD src").await, Frame::Integer(1));
+
+        // nothing is removed when the destination has the wrong type
+        run(&session, "SET text abc").await;
+
+        assert_eq!(run(&session, "SMOVE src text b").await, Frame::wrong_type());
+        assert_eq!(run(&session, "SMOVE text dst b").await, Frame::wrong_type());
+        assert_eq!(run(&session, "SISMEMBER src b").await, Frame::Integer(1));
+
+        // the emptied source is deleted
+        assert_eq!(run(&session, "SMOVE src dst b").await, Frame::Integer(1));
+        assert_eq!(run(&session, "EXISTS src").await, Frame::Integer(0));
+        assert_eq!(run(&session, "SCARD dst").await, Frame::Integer(2));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::prelude::*;
+
+pub struct SPop;
+
+#[async_trait]
+impl CommandTrait for SPop {
+    fn name(&self) -> &str {
+        "SPOP"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns one or more random members from a set after removing them. Deletes the set if the last member was popped."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        if args.len() > 2 {
+            return Err(ReplyError::Syntax.into());
+        }
+
+        let key = args.next()?;
+
+        let count = if args.is_empty() {
+            None
+        } else {
+            match usize::try_from(args.next_int()?) {
+                Ok(count) => Some(count),
+                Err(_) => return Ok(value_error!("value is out of range, must be positive")),
+            }
+        };
+
+        let mut store = session.state.store.write().await;
+
+        let set = match store.get_mut(&key) {
+            Some(Entry {
+                value: Value::Set(set),
+                ..
+            }) => set,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None if count.is_some() => return Ok(Frame::Set(Vec::new())),
+            None => return Ok(Frame::Nil),
+        };
+
+        let popped = set.pop(count.unwrap_or(1));
+
+        if set.is_empty() {
+            store.remove(&key);
+        }
+
+        let mut popped = popped.into_iter().map(Frame::Bulk);
+
+        match count {
+            Some(_) => Ok(Frame::Set(popped.collect())),
+            None => Ok(popped.next().unwrap_or(Frame::Nil)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    fn members(frame: Frame) -> Vec<Vec<u8>> {
+        let Frame::Set(members) = frame else {
+            panic!("expected a set, got {frame:?}");
+        };
+
+        members
+            .into_iter()
+            .map(|member| match member {
+                Frame::Bulk(member) => member,
+                other => panic!("expected a bulk string, got {other:?}"),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_spop() {
+        let session = session().await;
+
+        run(&session, "SADD s a b c d e").await;
+
+        let popped = members(run(&session, "SPOP s 2").await);
+        assert_eq!(popped.len(), 2);
+        assert_eq!(run(&session, "SCARD s").await, Frame::Integer(3));
+
+        for member in popped {
+            let line = format!("SISMEMBER s {}", String::from_utf8(member).unwrap());
+            assert_eq!(run(&session, &line).await, Frame::Integer(0));
+        }
+
+        assert!(matches!(run(&session, "SPOP s").await, Frame::Bulk(_)));
+        assert!(members(run(&session, "SPOP s 0").await).is_empty());
+
+        // popping everything deletes the key
+        assert_eq!(members(run(&session, "SPOP s 10").await).len(), 2);
+        assert_eq!(run(&session, "EXISTS s").await, Frame::Integer(0));
+
+        assert_eq!(run(&session, "SPOP s").await, Frame::Nil);
+        assert!(members(run(&session, "SPOP s 3").await).is_empty());
+        assert_eq!(
+            run(&session, "SPOP s -1").await,
+            value_error!("value is out of range, must be positive")
+        );
+        assert_eq!(
+            run(&session, "SPOP s 1 2").await,
+            Frame::from(ReplyError::Syntax)
+        );
+
+        // a few members out of a large set
+        let line = (0..1000).fold("SADD big".to_string(), |line, i| format!("{line} m{i}"));
+        run(&session, &line).await;
+
+        let mut popped = members(run(&session, "SPOP big 10").await);
+        popped.sort();
+        popped.dedup();
+
+        assert_eq!(popped.len(), 10);
+        assert_eq!(run(&session, "SCARD big").await, Frame::Integer(990));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::prelude::*;
+
+pub struct SRandMember;
+
+#[async_trait]
+impl CommandTrait for SRandMember {
+    fn name(&self) -> &str {
+        "SRANDMEMBER"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "set"
+    }
+
+    fn summary(&self) -> &str {
+        "Get one or multiple random members from a set"
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        if args.len() > 2 {
+            return Err(ReplyError::Syntax.into());
+        }
+
+        let key = args.next()?;
+        let count = if args.is_empty() {
+            None
+        } else {
+            Some(args.next_int()?)
+        };
+
+        let store = session.state.store.read().await;
+
+        let set = match store.get(&key) {
+            Some(Entry {
+                value: Value::Set(set),
+                ..
+            }) => set,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None if count.is_some() => return Ok(Frame::Array(VecDeque::new())),
+            None => return Ok(Frame::Nil),
+        };
+
+        let Some(count) = count else {
+            return Ok(set.random().map_or(Frame::Nil, Frame::Bulk));
+        };
+
+        // a negative count may return the same member more than once
+        let members = if count >= 0 {
+            set.sample(usize::try_from(count).unwrap_or(usize::MAX))
+        } else {
+            let count = usize::try_from(count.unsigned_abs()).unwrap_or(usize::MAX);
+
+            if count > session.state.limits.max_multibulk_len {
+                return Ok(value_error!("value is out of range"));
+            }
+
+            (0..count).filter_map(|_| set.random()).collect()
+        };
+
+        Ok(Frame::Array(members.into_iter().map(Frame::Bulk).collect()))
+    }
+}
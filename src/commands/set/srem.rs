@@ -0,0 +1,57 @@
+use crate::prelude::*;
+
+pub struct SRem;
+
+#[async_trait]
+impl CommandTrait for SRem {
+    fn name(&self) -> &str {
+        "SREM"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "set"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes one or more members from a set. Deletes the set if the last member was removed."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let mut store = session.state.store.write().await;
+
+        let set = match store.get_mut(&key) {
+            Some(Entry {
+                value: Value::Set(set),
+                ..
+            }) => set,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => return Ok(Frame::Integer(0)),
+        };
+
+        let removed = args.rest().filter(|member| set.remove(member)).count();
+
+        if set.is_empty() {
+            store.remove(&key);
+        }
+
+        Ok(Frame::Integer(removed as i64))
+    }
+}
@@ -0,0 +1,39 @@
+use super::sinter::{reply_combined, SetOp};
+use crate::prelude::*;
+
+pub struct SUnion;
+
+#[async_trait]
+impl CommandTrait for SUnion {
+    fn name(&self) -> &str {
+        "SUNION"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the union of multiple sets."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, -1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        reply_combined(args, session, SetOp::Union).await
+    }
+}
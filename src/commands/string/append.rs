@@ -0,0 +1,92 @@
+use crate::prelude::*;
+use crate::storage::value::StringValue;
+
+pub struct Append;
+
+#[async_trait]
+impl CommandTrait for Append {
+    fn name(&self) -> &str {
+        "APPEND"
+    }
+
+    fn arity(&self) -> i64 {
+        3
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Appends a string to the value of a key. Creates the key if it doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let suffix = args.next()?;
+
+        let mut store = session.state.store.write().await;
+
+        let entry = store.get_or_insert_with(key, || Entry::new(Value::from(Vec::new())));
+
+        let Value::String(value) = &mut entry.value else {
+            return Ok(Frame::wrong_type());
+        };
+
+        let mut bytes = std::mem::replace(value, StringValue::Raw(Vec::new())).into_vec();
+
+        if bytes.len() + suffix.len() > session.state.limits.max_bulk_len {
+            *value = bytes.into();
+            return Ok(value_error!(
+                "string exceeds maximum allowed size (proto-max-bulk-len)"
+            ));
+        }
+
+        bytes.extend_from_slice(&suffix);
+        let len = bytes.len();
+
+        *value = StringValue::Raw(bytes);
+
+        Ok(Frame::Integer(len as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_append() {
+        let session = session().await;
+
+        assert_eq!(run(&session, "APPEND s hello").await, Frame::Integer(5));
+        assert_eq!(run(&session, "APPEND s _world").await, Frame::Integer(11));
+        assert_eq!(run(&session, "GET s").await, Frame::from("hello_world"));
+
+        // integers are appended to as text
+        run(&session, "SET n 10").await;
+
+        assert_eq!(run(&session, "APPEND n 5").await, Frame::Integer(3));
+        assert_eq!(run(&session, "INCR n").await, Frame::Integer(106));
+
+        run(&session, "SET k v EX 100").await;
+        run(&session, "RPUSH list a").await;
+
+        assert_eq!(run(&session, "APPEND k w").await, Frame::Integer(2));
+        assert_eq!(run(&session, "TTL k").await, Frame::Integer(100));
+        assert_eq!(run(&session, "APPEND list a").await, Frame::wrong_type());
+    }
+}
@@ -0,0 +1,41 @@
+use super::incr::incr_by;
+use crate::prelude::*;
+
+pub struct Decr;
+
+#[async_trait]
+impl CommandTrait for Decr {
+    fn name(&self) -> &str {
+        "DECR"
+    }
+
+    fn arity(&self) -> i64 {
+        2
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Decrements the integer value of a key by one. Uses 0 as initial value if the key doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+
+        incr_by(session, key, -1).await
+    }
+}
@@ -0,0 +1,47 @@
+use super::incr::incr_by;
+use crate::prelude::*;
+
+pub struct DecrBy;
+
+#[async_trait]
+impl CommandTrait for DecrBy {
+    fn name(&self) -> &str {
+        "DECRBY"
+    }
+
+    fn arity(&self) -> i64 {
+        3
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Decrements a number from the integer value of a key. Uses 0 as initial value if the key doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let decrement = args.next_int()?;
+
+        // the negation of the smallest integer doesn't fit
+        let Some(delta) = decrement.checked_neg() else {
+            return Ok(value_error!("decrement would overflow"));
+        };
+
+        incr_by(session, key, delta).await
+    }
+}
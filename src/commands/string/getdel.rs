@@ -0,0 +1,79 @@
+use crate::prelude::*;
+
+pub struct GetDel;
+
+#[async_trait]
+impl CommandTrait for GetDel {
+    fn name(&self) -> &str {
+        "GETDEL"
+    }
+
+    fn arity(&self) -> i64 {
+        2
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the string value of a key after deleting the key."
+    }
+
+    fn since(&self) -> &str {
+        "6.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let mut store = session.state.store.write().await;
+
+        match store.get(&key) {
+            Some(Entry {
+                value: Value::String(_),
+                ..
+            }) => {}
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => return Ok(Frame::Nil),
+        }
+
+        match store.remove(&key) {
+            Some(Entry {
+                value: Value::String(value),
+                ..
+            }) => Ok(Frame::Bulk(value.into_vec())),
+            _ => Ok(Frame::Nil),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_getdel() {
+        let session = session().await;
+
+        run(&session, "SET k v").await;
+        run(&session, "RPUSH list a").await;
+
+        assert_eq!(run(&session, "GETDEL k").await, Frame::from("v"));
+        assert_eq!(run(&session, "EXISTS k").await, Frame::Integer(0));
+        assert_eq!(run(&session, "GETDEL k").await, Frame::Nil);
+
+        // a key of another type is left alone
+        assert_eq!(run(&session, "GETDEL list").await, Frame::wrong_type());
+        assert_eq!(run(&session, "EXISTS list").await, Frame::Integer(1));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::commands::args::{Opt, OptKind, TimeUnit};
+use crate::prelude::*;
+
+pub struct GetEx;
+
+#[async_trait]
+impl CommandTrait for GetEx {
+    fn name(&self) -> &str {
+        "GETEX"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the string value of a key after setting its expiration time."
+    }
+
+    fn since(&self) -> &str {
+        "6.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let options = args.options(OPTIONS)?;
+
+        let deadline = options.deadline(self.name())?;
+
+        let mut store = session.state.store.write().await;
+
+        let value = match store.get(&key) {
+            Some(Entry {
+                value: Value::String(value),
+                ..
+            }) => value.to_vec(),
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => return Ok(Frame::Nil),
+        };
+
+        // without an option this is a plain GET, a deadline in the past deletes the key
+        if deadline.is_some() {
+            store.set_expire(&key, deadline);
+        } else if options.has("PERSIST") {
+            store.set_expire(&key, None);
+        }
+
+        Ok(Frame::Bulk(value))
+    }
+}
+
+const OPTIONS: &[Opt] = &[
+    Opt::new("EX", OptKind::Ttl(TimeUnit::Seconds), 1),
+    Opt::new("PX", OptKind::Ttl(TimeUnit::Millis), 1),
+    Opt::new("EXAT", OptKind::Ttl(TimeUnit::Seconds), 1),
+    Opt::new("PXAT", OptKind::Ttl(TimeUnit::Millis), 1),
+    Opt::flag("PERSIST", 1),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_getex() {
+        let session = session().await;
+
+        run(&session, "SET k v").await;
+
+        // without options this is a plain GET
+        assert_eq!(run(&session, "GETEX k").await, Frame::from("v"));
+        assert_eq!(run(&session, "TTL k").await, Frame::Integer(-1));
+
+        assert_eq!(run(&session, "GETEX k EX 100").await, Frame::from("v"));
+        assert_eq!(run(&session, "TTL k").await, Frame::Integer(100));
+        assert_eq!(run(&session, "GETEX k PERSIST").await, Frame::from("v"));
+        assert_eq!(run(&session, "TTL k").await, Frame::Integer(-1));
+
+        // a deadline in the past deletes the key, after handing out the value
+        assert_eq!(run(&session, "GETEX k PXAT 1").await, Frame::from("v"));
+        assert_eq!(run(&session, "EXISTS k").await, Frame::Integer(0));
+        assert_eq!(run(&session, "GETEX k EX 100").await, Frame::Nil);
+
+        run(&session, "SET k v").await;
+        run(&session, "RPUSH list a").await;
+
+        assert_eq!(run(&session, "GETEX list").await, Frame::wrong_type());
+        assert_eq!(
+            run(&session, "GETEX k EX 0").await,
+            Frame::from(ReplyError::invalid_expire_time("getex"))
+        );
+        assert_eq!(
+            run(&session, "GETEX k EX 100 PERSIST").await,
+            Frame::from(ReplyError::Syntax)
+        );
+        assert_eq!(run(&session, "TTL k").await, Frame::Integer(-1));
+    }
+}
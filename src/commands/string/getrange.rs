@@ -0,0 +1,71 @@
+use crate::prelude::*;
+
+pub struct GetRange;
+
+#[async_trait]
+impl CommandTrait for GetRange {
+    fn name(&self) -> &str {
+        "GETRANGE"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns a substring of the string stored at a key."
+    }
+
+    fn since(&self) -> &str {
+        "2.4.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let start = args.next_int()?;
+        let end = args.next_int()?;
+
+        let store = session.state.store.read().await;
+
+        let value = match store.get(&key) {
+            Some(Entry {
+                value: Value::String(value),
+                ..
+            }) => value.to_vec(),
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => return Ok(Frame::Bulk(Vec::new())),
+        };
+
+        let len = value.len() as i64;
+
+        // negative offsets count from the end, both ends are inclusive
+        let start = if start < 0 {
+            (len + start).max(0)
+        } else {
+            start
+        };
+        let end = if end < 0 {
+            (len + end).max(0)
+        } else {
+            end.min(len - 1)
+        };
+
+        if len == 0 || start > end {
+            return Ok(Frame::Bulk(Vec::new()));
+        }
+
+        Ok(Frame::Bulk(value[start as usize..=end as usize].to_vec()))
+    }
+}
@@ -0,0 +1,139 @@
+use crate::prelude::*;
+use crate::storage::value::StringValue;
+
+pub struct Incr;
+
+#[async_trait]
+impl CommandTrait for Incr {
+    fn name(&self) -> &str {
+        "INCR"
+    }
+
+    fn arity(&self) -> i64 {
+        2
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Increments the integer value of a key by one. Uses 0 as initial value if the key doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+
+        incr_by(session, key, 1).await
+    }
+}
+
+/// Shared by the integer counters: add `delta` to the integer stored at the
+/// key, a missing key counts as 0. The expire time of the key is kept.
+pub(super) async fn incr_by(session: SessionRef, key: Vec<u8>, delta: i64) -> Result<Frame> {
+    let mut store = session.state.store.write().await;
+
+    // starting from 0 can't overflow, so a new key never has to be rolled back
+    let entry = store.get_or_insert_with(key, || Entry::new(Value::String(0.into())));
+
+    let Value::String(value) = &mut entry.value else {
+        return Ok(Frame::wrong_type());
+    };
+
+    let current = value.as_int().ok_or(ReplyError::NotInteger)?;
+
+    let Some(next) = current.checked_add(delta) else {
+        return Ok(value_error!("increment or decrement would overflow"));
+    };
+
+    *value = StringValue::Int(next);
+
+    Ok(Frame::Integer(next))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_incr_decr() {
+        let session = session().await;
+
+        assert_eq!(run(&session, "INCR n").await, Frame::Integer(1));
+        assert_eq!(run(&session, "INCRBY n 41").await, Frame::Integer(42));
+        assert_eq!(run(&session, "DECR n").await, Frame::Integer(41));
+        assert_eq!(run(&session, "DECRBY n 50").await, Frame::Integer(-9));
+        assert_eq!(run(&session, "DECRBY missing -3").await, Frame::Integer(3));
+        assert_eq!(run(&session, "GET n").await, Frame::from("-9"));
+
+        assert_eq!(
+            run(&session, "INCRBY n 1.5").await,
+            Frame::from(ReplyError::NotInteger)
+        );
+
+        run(&session, "SET text abc").await;
+        run(&session, "RPUSH list a").await;
+
+        assert_eq!(
+            run(&session, "INCR text").await,
+            Frame::from(ReplyError::NotInteger)
+        );
+        assert_eq!(run(&session, "DECR list").await, Frame::wrong_type());
+    }
+
+    #[tokio::test]
+    async fn test_incr_large_values() {
+        let session = session().await;
+
+        run(&session, "SET max 9223372036854775806").await;
+        run(&session, "SET min -9223372036854775807").await;
+
+        assert_eq!(run(&session, "INCR max").await, Frame::Integer(i64::MAX));
+        assert_eq!(run(&session, "DECR min").await, Frame::Integer(i64::MIN));
+
+        // nothing is written on overflow
+        for command in ["INCR max", "INCRBY max 1", "DECR min", "DECRBY min 1"] {
+            assert_eq!(
+                run(&session, command).await,
+                value_error!("increment or decrement would overflow")
+            );
+        }
+
+        assert_eq!(
+            run(&session, "INCRBY zero -9223372036854775808").await,
+            Frame::Integer(i64::MIN)
+        );
+        assert_eq!(
+            run(&session, "DECRBY zero -9223372036854775808").await,
+            value_error!("decrement would overflow")
+        );
+        assert_eq!(
+            run(&session, "GET max").await,
+            Frame::from("9223372036854775807")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_incr_keeps_ttl() {
+        let session = session().await;
+
+        run(&session, "SET n 10 EX 100").await;
+
+        assert_eq!(run(&session, "INCRBY n 5").await, Frame::Integer(15));
+        assert_eq!(run(&session, "DECR n").await, Frame::Integer(14));
+        assert_eq!(run(&session, "TTL n").await, Frame::Integer(100));
+    }
+}
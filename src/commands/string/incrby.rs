@@ -0,0 +1,42 @@
+use super::incr::incr_by;
+use crate::prelude::*;
+
+pub struct IncrBy;
+
+#[async_trait]
+impl CommandTrait for IncrBy {
+    fn name(&self) -> &str {
+        "INCRBY"
+    }
+
+    fn arity(&self) -> i64 {
+        3
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Increments the integer value of a key by a number. Uses 0 as initial value if the key doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let increment = args.next_int()?;
+
+        incr_by(session, key, increment).await
+    }
+}
@@ -0,0 +1,162 @@
+use crate::commands::args::parse_float;
+use crate::prelude::*;
+use crate::storage::value::StringValue;
+use crate::utils::number::format_human;
+
+pub struct IncrByFloat;
+
+#[async_trait]
+impl CommandTrait for IncrByFloat {
+    fn name(&self) -> &str {
+        "INCRBYFLOAT"
+    }
+
+    fn arity(&self) -> i64 {
+        3
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Increment the floating point value of a key by a number. Uses 0 as initial value if the key doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "2.6.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let increment = args.next_float()?;
+
+        let mut store = session.state.store.write().await;
+
+        let current = match store.get(&key) {
+            Some(Entry {
+                value: Value::String(StringValue::Int(value)),
+                ..
+            }) => *value as f64,
+            Some(Entry {
+                value: Value::String(StringValue::Raw(value)),
+                ..
+            }) => parse_float(value)?,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => 0.0,
+        };
+
+        let next = current + increment;
+
+        if !next.is_finite() {
+            return Ok(value_error!("increment would produce NaN or Infinity"));
+        }
+
+        // always written out in full, never with an exponent
+        let text = format_human(next).into_bytes();
+
+        // the expire time of an existing key is kept
+        match store.get_mut(&key) {
+            Some(entry) => entry.value = Value::from(text.clone()),
+            None => {
+                store.insert(key, Entry::new(Value::from(text.clone())));
+            }
+        }
+
+        Ok(Frame::Bulk(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_incrbyfloat() {
+        let session = session().await;
+
+        assert_eq!(run(&session, "INCRBYFLOAT n 0.1").await, Frame::from("0.1"));
+        assert_eq!(run(&session, "INCRBYFLOAT n 0.2").await, Frame::from("0.3"));
+        assert_eq!(
+            run(&session, "INCRBYFLOAT n 5.0e3").await,
+            Frame::from("5000.3")
+        );
+
+        run(&session, "SET int 10").await;
+
+        assert_eq!(
+            run(&session, "INCRBYFLOAT int -10.5").await,
+            Frame::from("-0.5")
+        );
+
+        run(&session, "SET text abc").await;
+        run(&session, "RPUSH list a").await;
+
+        assert_eq!(
+            run(&session, "INCRBYFLOAT text 1").await,
+            Frame::from(ReplyError::NotFloat)
+        );
+        assert_eq!(
+            run(&session, "INCRBYFLOAT list 1").await,
+            Frame::wrong_type()
+        );
+        assert_eq!(
+            run(&session, "INCRBYFLOAT n abc").await,
+            Frame::from(ReplyError::NotFloat)
+        );
+
+        // nothing is written when the result isn't finite
+        assert_eq!(
+            run(&session, "INCRBYFLOAT n inf").await,
+            value_error!("increment would produce NaN or Infinity")
+        );
+        assert_eq!(run(&session, "GET n").await, Frame::from("5000.3"));
+    }
+
+    #[tokio::test]
+    async fn test_incrbyfloat_large_values() {
+        let session = session().await;
+
+        run(&session, "SET n 1000000000000000").await;
+        run(&session, "SET m 123456789012345678").await;
+
+        // no digit the double holds is rounded away
+        assert_eq!(
+            run(&session, "INCRBYFLOAT n 0.5").await,
+            Frame::from("1000000000000000.5")
+        );
+        assert_eq!(
+            run(&session, "INCRBYFLOAT m 1").await,
+            Frame::from("123456789012345680")
+        );
+
+        // never written with an exponent
+        assert_eq!(
+            run(&session, "INCRBYFLOAT big 1e20").await,
+            Frame::from("100000000000000000000")
+        );
+        assert_eq!(
+            run(&session, "INCRBYFLOAT small 1.5e-10").await,
+            Frame::from("0.00000000015")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_incrbyfloat_keeps_ttl() {
+        let session = session().await;
+
+        run(&session, "SET n 1.5 EX 100").await;
+
+        assert_eq!(run(&session, "INCRBYFLOAT n 1").await, Frame::from("2.5"));
+        assert_eq!(run(&session, "TTL n").await, Frame::Integer(100));
+    }
+}
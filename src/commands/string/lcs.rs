@@ -0,0 +1,244 @@
+use crate::commands::args::{Opt, OptKind};
+use crate::prelude::*;
+
+pub struct Lcs;
+
+#[async_trait]
+impl CommandTrait for Lcs {
+    fn name(&self) -> &str {
+        "LCS"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Finds the longest common substring."
+    }
+
+    fn since(&self) -> &str {
+        "7.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 2, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let a = args.next()?;
+        let b = args.next()?;
+        let options = args.options(OPTIONS)?;
+
+        let (len, idx) = (options.has("LEN"), options.has("IDX"));
+
+        if len && idx {
+            return Ok(value_error!(
+                "If you want both the length and indexes, please just use IDX."
+            ));
+        }
+
+        let min_len = options.int("MINMATCHLEN").unwrap_or(0).max(0) as usize;
+        let with_len = options.has("WITHMATCHLEN");
+
+        let (a, b) = {
+            let store = session.state.store.read().await;
+
+            // missing keys count as empty strings
+            let fetch = |key: &[u8]| match store.get(key) {
+                Some(Entry {
+                    value: Value::String(value),
+                    ..
+                }) => Some(value.to_vec()),
+                Some(_) => None,
+                None => Some(Vec::new()),
+            };
+
+            let (Some(a), Some(b)) = (fetch(&a), fetch(&b)) else {
+                return Ok(value_error!(
+                    "The specified keys must contain string values"
+                ));
+            };
+
+            (a, b)
+        };
+
+        // the whole table is kept in memory, refuse what would be absurdly large
+        let cells = (a.len() + 1).checked_mul(b.len() + 1);
+
+        if cells.is_none_or(|cells| cells > session.state.limits.max_bulk_len / 4) {
+            return Ok(value_error!(
+                "Insufficient memory, transient memory for LCS exceeds proto-max-bulk-len"
+            ));
+        }
+
+        let table = Table::build(&a, &b);
+        let (common, matches) = backtrack(&a, &b, &table);
+
+        if len {
+            return Ok(Frame::Integer(common.len() as i64));
+        }
+
+        if !idx {
+            return Ok(Frame::Bulk(common));
+        }
+
+        let matches = matches
+            .into_iter()
+            .filter(|run| run.len() >= min_len)
+            .map(|run| {
+                let mut reply = VecDeque::from([
+                    Frame::Array(VecDeque::from([
+                        Frame::Integer(run.a.0 as i64),
+                        Frame::Integer(run.a.1 as i64),
+                    ])),
+                    Frame::Array(VecDeque::from([
+                        Frame::Integer(run.b.0 as i64),
+                        Frame::Integer(run.b.1 as i64),
+                    ])),
+                ]);
+
+                if with_len {
+                    reply.push_back(Frame::Integer(run.len() as i64));
+                }
+
+                Frame::Array(reply)
+            })
+            .collect();
+
+        Ok(Frame::Map(vec![
+            (Frame::from("matches"), Frame::Array(matches)),
+            (Frame::from("len"), Frame::Integer(common.len() as i64)),
+        ]))
+    }
+}
+
+const OPTIONS: &[Opt] = &[
+    Opt::flag("LEN", 0),
+    Opt::flag("IDX", 0),
+    Opt::new("MINMATCHLEN", OptKind::Int, 0),
+    Opt::flag("WITHMATCHLEN", 0),
+];
+
+/// The table of LCS lengths for every pair of prefixes, `table[i][j]` is the
+/// length for `a[..i]` and `b[..j]`.
+struct Table {
+    lengths: Vec<u32>,
+    width: usize,
+}
+
+impl Table {
+    fn build(a: &[u8], b: &[u8]) -> Self {
+        let width = b.len() + 1;
+        let mut lengths = vec![0; (a.len() + 1) * width];
+
+        for i in 1..=a.len() {
+            for j in 1..=b.len() {
+                lengths[i * width + j] = if a[i - 1] == b[j - 1] {
+                    lengths[(i - 1) * width + j - 1] + 1
+                } else {
+                    lengths[(i - 1) * width + j].max(lengths[i * width + j - 1])
+                };
+            }
+        }
+
+        Self { lengths, width }
+    }
+
+    fn get(&self, i: usize, j: usize) -> u32 {
+        self.lengths[i * self.width + j]
+    }
+}
+
+/// A run of matching bytes, both ranges inclusive.
+struct Match {
+    a: (usize, usize),
+    b: (usize, usize),
+}
+
+impl Match {
+    fn len(&self) -> usize {
+        self.a.1 - self.a.0 + 1
+    }
+}
+
+/// Walk the table back from the end, collecting the common subsequence and
+/// the contiguous runs it is made of, last run first like Redis.
+fn backtrack(a: &[u8], b: &[u8], table: &Table) -> (Vec<u8>, Vec<Match>) {
+    let mut common = vec![0; table.get(a.len(), b.len()) as usize];
+    let mut idx = common.len();
+    let mut matches = Vec::new();
+    let mut current: Option<Match> = None;
+
+    let (mut i, mut j) = (a.len(), b.len());
+
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            idx -= 1;
+            common[idx] = a[i - 1];
+
+            match &mut current {
+                // contiguous with the run being tracked, extend it backwards
+                Some(run) if run.a.0 == i && run.b.0 == j => {
+                    run.a.0 -= 1;
+                    run.b.0 -= 1;
+                }
+                Some(_) => matches.extend(current.take()),
+                None => {
+                    current = Some(Match {
+                        a: (i - 1, i - 1),
+                        b: (j - 1, j - 1),
+                    });
+                }
+            }
+
+            i -= 1;
+            j -= 1;
+
+            // the run can't go back any further
+            if current
+                .as_ref()
+                .is_some_and(|run| run.a.0 == 0 || run.b.0 == 0)
+            {
+                matches.extend(current.take());
+            }
+        } else {
+            if table.get(i - 1, j) > table.get(i, j - 1) {
+                i -= 1;
+            } else {
+                j -= 1;
+            }
+
+            matches.extend(current.take());
+        }
+    }
+
+    (common, matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lcs_backtrack() {
+        let (a, b) = (b"ohmytext", b"mynewtext");
+        let (common, matches) = backtrack(a, b, &Table::build(a, b));
+
+        assert_eq!(common, b"mytext");
+
+        let ranges: Vec<_> = matches.iter().map(|run| (run.a, run.b)).collect();
+        assert_eq!(ranges, vec![((4, 7), (5, 8)), ((2, 3), (0, 1))]);
+
+        let (common, matches) = backtrack(b"", b"abc", &Table::build(b"", b"abc"));
+        assert!(common.is_empty() && matches.is_empty());
+    }
+}
@@ -0,0 +1,29 @@
+mod append;
+mod decr;
+mod decrby;
+mod getdel;
+mod getex;
+mod getrange;
+mod incr;
+mod incrby;
+mod incrbyfloat;
+mod lcs;
+mod msetnx;
+mod setnx;
+mod setrange;
+mod strlen;
+
+pub use append::Append;
+pub use decr::Decr;
+pub use decrby::DecrBy;
+pub use getdel::GetDel;
+pub use getex::GetEx;
+pub use getrange::GetRange;
+pub use incr::Incr;
+pub use incrby::IncrBy;
+pub use incrbyfloat::IncrByFloat;
+pub use lcs::Lcs;
+pub use msetnx::MSetNx;
+pub use setnx::SetNx;
+pub use setrange::SetRange;
+pub use strlen::StrLen;
@@ -0,0 +1,76 @@
+use crate::prelude::*;
+
+pub struct MSetNx;
+
+#[async_trait]
+impl CommandTrait for MSetNx {
+    fn name(&self) -> &str {
+        "MSETNX"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Atomically modifies the string values of one or more keys only when all keys don't exist."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.1"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, -1, 2)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let pairs = args.pairs()?;
+
+        let mut store = session.state.store.write().await;
+
+        // all or nothing, a single existing key means none are set
+        if pairs.iter().any(|(key, _)| store.contains_key(key)) {
+            return Ok(Frame::Integer(0));
+        }
+
+        for (key, value) in pairs {
+            store.insert(key.to_vec(), Entry::new(Value::from(value.to_vec())));
+        }
+
+        Ok(Frame::Integer(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{bulks, run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_msetnx() {
+        let session = session().await;
+
+        assert_eq!(run(&session, "MSETNX a 1 b 2").await, Frame::Integer(1));
+        assert_eq!(run(&session, "MGET a b").await, bulks(&["1", "2"]));
+
+        // a single existing key means none are set
+        assert_eq!(run(&session, "MSETNX c 3 a 4").await, Frame::Integer(0));
+        assert_eq!(run(&session, "EXISTS c").await, Frame::Integer(0));
+        assert_eq!(run(&session, "GET a").await, Frame::from("1"));
+
+        assert_eq!(
+            run(&session, "MSETNX c 3 d").await,
+            Frame::from(ReplyError::wrong_arity("msetnx"))
+        );
+        assert_eq!(run(&session, "EXISTS c").await, Frame::Integer(0));
+    }
+}
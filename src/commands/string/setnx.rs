@@ -0,0 +1,70 @@
+use crate::prelude::*;
+
+pub struct SetNx;
+
+#[async_trait]
+impl CommandTrait for SetNx {
+    fn name(&self) -> &str {
+        "SETNX"
+    }
+
+    fn arity(&self) -> i64 {
+        3
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Set the string value of a key only when the key doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let value = args.next()?.to_vec();
+
+        let mut store = session.state.store.write().await;
+
+        if store.contains_key(&key) {
+            return Ok(Frame::Integer(0));
+        }
+
+        store.insert(key, Entry::new(Value::from(value)));
+
+        Ok(Frame::Integer(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_setnx() {
+        let session = session().await;
+
+        assert_eq!(run(&session, "SETNX k a").await, Frame::Integer(1));
+        assert_eq!(run(&session, "SETNX k b").await, Frame::Integer(0));
+        assert_eq!(run(&session, "GET k").await, Frame::from("a"));
+
+        // any type counts as existing
+        run(&session, "RPUSH list a").await;
+
+        assert_eq!(run(&session, "SETNX list b").await, Frame::Integer(0));
+        assert_eq!(run(&session, "LLEN list").await, Frame::Integer(1));
+    }
+}
@@ -0,0 +1,156 @@
+use crate::prelude::*;
+use crate::storage::value::StringValue;
+
+pub struct SetRange;
+
+#[async_trait]
+impl CommandTrait for SetRange {
+    fn name(&self) -> &str {
+        "SETRANGE"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Overwrites a part of a string value with another by an offset. Creates the key if it doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "2.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let offset = args.next_int()?;
+        let patch = args.next()?;
+
+        let Ok(offset) = usize::try_from(offset) else {
+            return Ok(value_error!("offset is out of range"));
+        };
+
+        let mut store = session.state.store.write().await;
+
+        let current = match store.get(&key) {
+            Some(Entry {
+                value: Value::String(value),
+                ..
+            }) => Some(value.len()),
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => None,
+        };
+
+        // an empty patch changes nothing and doesn't create the key
+        if patch.is_empty() {
+            return Ok(Frame::Integer(current.unwrap_or(0) as i64));
+        }
+
+        if offset + patch.len() > session.state.limits.max_bulk_len {
+            return Ok(value_error!(
+                "string exceeds maximum allowed size (proto-max-bulk-len)"
+            ));
+        }
+
+        let entry = store.get_or_insert_with(key, || Entry::new(Value::from(Vec::new())));
+
+        let Value::String(value) = &mut entry.value else {
+            return Ok(Frame::wrong_type());
+        };
+
+        let mut bytes = std::mem::replace(value, StringValue::Raw(Vec::new())).into_vec();
+
+        // the gap up to the offset is zero padded
+        if bytes.len() < offset + patch.len() {
+            bytes.resize(offset + patch.len(), 0);
+        }
+
+        bytes[offset..offset + patch.len()].copy_from_slice(&patch);
+        let len = bytes.len();
+
+        *value = StringValue::Raw(bytes);
+
+        Ok(Frame::Integer(len as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+    use crate::protocol::Limits;
+
+    #[tokio::test]
+    async fn test_setrange() {
+        let session = session().await;
+
+        run(&session, "SET s hello").await;
+
+        assert_eq!(run(&session, "SETRANGE s 1 ipp").await, Frame::Integer(5));
+        assert_eq!(run(&session, "GET s").await, Frame::from("hippo"));
+
+        // the gap up to the offset is filled with zeros
+        assert_eq!(run(&session, "SETRANGE pad 3 x").await, Frame::Integer(4));
+        assert_eq!(
+            run(&session, "GET pad").await,
+            Frame::Bulk(b"\0\0\0x".to_vec())
+        );
+        assert_eq!(run(&session, "SETRANGE s 7 !").await, Frame::Integer(8));
+        assert_eq!(
+            run(&session, "GET s").await,
+            Frame::Bulk(b"hippo\0\0!".to_vec())
+        );
+
+        run(&session, "RPUSH list a").await;
+
+        assert_eq!(
+            run(&session, "SETRANGE list 0 x").await,
+            Frame::wrong_type()
+        );
+        assert_eq!(
+            run(&session, "SETRANGE s -1 x").await,
+            value_error!("offset is out of range")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_setrange_limits() {
+        let session = session().await;
+
+        // an empty value on a missing key doesn't create it
+        assert_eq!(
+            run(&session, "SETRANGE missing 10 ").await,
+            Frame::Integer(0)
+        );
+        assert_eq!(run(&session, "EXISTS missing").await, Frame::Integer(0));
+
+        run(&session, "SET s abc").await;
+
+        assert_eq!(run(&session, "SETRANGE s 100 ").await, Frame::Integer(3));
+
+        // nothing is allocated past the bulk size limit
+        let max = Limits::default().max_bulk_len;
+
+        assert_eq!(
+            run(&session, &format!("SETRANGE s {} ab", max - 1)).await,
+            value_error!("string exceeds maximum allowed size (proto-max-bulk-len)")
+        );
+        assert_eq!(
+            run(&session, &format!("SETRANGE s {} ", max * 2)).await,
+            Frame::Integer(3)
+        );
+        assert_eq!(run(&session, "GET s").await, Frame::from("abc"));
+    }
+}
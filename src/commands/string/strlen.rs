@@ -0,0 +1,47 @@
+use crate::prelude::*;
+
+pub struct StrLen;
+
+#[async_trait]
+impl CommandTrait for StrLen {
+    fn name(&self) -> &str {
+        "STRLEN"
+    }
+
+    fn arity(&self) -> i64 {
+        2
+    }
+
+    fn group(&self) -> &str {
+        "string"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the length of a string value."
+    }
+
+    fn since(&self) -> &str {
+        "2.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        match session.state.store.read().await.get(&key) {
+            Some(Entry {
+                value: Value::String(value),
+                ..
+            }) => Ok(Frame::Integer(value.len() as i64)),
+            Some(_) => Ok(Frame::wrong_type()),
+            None => Ok(Frame::Integer(0)),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use super::zmpop::ZMPopArgs;
+use crate::prelude::*;
+
+pub struct BZMPop;
+
+#[async_trait]
+impl CommandTrait for BZMPop {
+    fn name(&self) -> &str {
+        "BZMPOP"
+    }
+
+    fn arity(&self) -> i64 {
+        -5
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes and returns a member by score from one or more sorted sets. Blocks until a member is available otherwise. Deletes the sorted set if the last element was popped."
+    }
+
+    fn since(&self) -> &str {
+        "7.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[
+            CommandFlag::Write,
+            CommandFlag::Blocking,
+            CommandFlag::MovableKeys,
+        ]
+    }
+
+    fn numkeys_index(&self) -> i64 {
+        2
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let timeout = args.next_timeout()?;
+        let mpop = ZMPopArgs::parse(args)?;
+        let keys = mpop.keys.iter().map(|key| key.to_vec()).collect();
+
+        session
+            .block_on(keys, timeout, move |store| mpop.pop(store))
+            .await
+            .map(|reply| reply.unwrap_or(Frame::NullArray))
+    }
+}
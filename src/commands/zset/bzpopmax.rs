@@ -0,0 +1,39 @@
+use super::bzpopmin::bzpop;
+use crate::prelude::*;
+
+pub struct BZPopMax;
+
+#[async_trait]
+impl CommandTrait for BZPopMax {
+    fn name(&self) -> &str {
+        "BZPOPMAX"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes and returns the member with the highest score from one or more sorted sets. Blocks until a member is available otherwise. Deletes the sorted set if the last element was popped."
+    }
+
+    fn since(&self) -> &str {
+        "5.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Blocking]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, -2, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        bzpop(args, session, true).await
+    }
+}
@@ -0,0 +1,78 @@
+use crate::commands::args::parse_timeout;
+use crate::prelude::*;
+
+pub struct BZPopMin;
+
+#[async_trait]
+impl CommandTrait for BZPopMin {
+    fn name(&self) -> &str {
+        "BZPOPMIN"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes and returns the member with the lowest score from one or more sorted sets. Blocks until a member is available otherwise. Deletes the sorted set if the last element was popped."
+    }
+
+    fn since(&self) -> &str {
+        "5.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Blocking]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, -2, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        bzpop(args, session, false).await
+    }
+}
+
+/// Pop the lowest or highest scoring member of the first non empty sorted set
+/// among the keys, blocking until there is one. The timeout comes last.
+pub(super) async fn bzpop(args: &mut Args, session: SessionRef, max: bool) -> Result<Frame> {
+    let mut keys: Vec<Vec<u8>> = args.rest().map(|key| key.to_vec()).collect();
+    let timeout = parse_timeout(&keys.pop().unwrap_or_default())?;
+
+    session
+        .block_on(keys.clone(), timeout, move |store| {
+            for key in &keys {
+                let zset = match store.get_mut(key) {
+                    Some(Entry {
+                        value: Value::ZSet(zset),
+                        ..
+                    }) => zset,
+                    Some(_) => return Err(ReplyError::WrongType.into()),
+                    None => continue,
+                };
+
+                let Some((member, score)) = zset.pop(1, max).pop() else {
+                    continue;
+                };
+
+                if zset.is_empty() {
+                    store.remove(key);
+                }
+
+                return Ok(Some(Frame::Array(VecDeque::from([
+                    Frame::Bulk(key.clone()),
+                    Frame::Bulk(member),
+                    Frame::Double(score),
+                ]))));
+            }
+
+            Ok(None)
+        })
+        .await
+        .map(|reply| reply.unwrap_or(Frame::NullArray))
+}
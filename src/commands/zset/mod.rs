@@ -0,0 +1,67 @@
+mod bzmpop;
+mod bzpopmax;
+mod bzpopmin;
+mod zadd;
+mod zcard;
+mod zcount;
+mod zdiff;
+mod zdiffstore;
+mod zincrby;
+mod zinter;
+mod zinterstore;
+mod zlexcount;
+mod zmpop;
+mod zmscore;
+mod zpopmax;
+mod zpopmin;
+mod zrandmember;
+mod zrange;
+mod zrangebylex;
+mod zrangebyscore;
+mod zrangestore;
+mod zrank;
+mod zrem;
+mod zremrangebylex;
+mod zremrangebyrank;
+mod zremrangebyscore;
+mod zrevrange;
+mod zrevrangebylex;
+mod zrevrangebyscore;
+mod zrevrank;
+mod zscore;
+mod zunion;
+mod zunionstore;
+
+pub use bzmpop::BZMPop;
+pub use bzpopmax::BZPopMax;
+pub use bzpopmin::BZPopMin;
+pub use zadd::ZAdd;
+pub use zcard::ZCard;
+pub use zcount::ZCount;
+pub use zdiff::ZDiff;
+pub use zdiffstore::ZDiffStore;
+pub use zincrby::ZIncrBy;
+pub use zinter::ZInter;
+pub use zinterstore::ZInterStore;
+pub use zlexcount::ZLexCount;
+pub use zmpop::ZMPop;
+pub use zmscore::ZMScore;
+pub use zpopmax::ZPopMax;
+pub use zpopmin::ZPopMin;
+pub use zrandmember::ZRandMember;
+pub use zrange::ZRange;
+pub use zrangebylex::ZRangeByLex;
+pub use zrangebyscore::ZRangeByScore;
+pub use zrangestore::ZRangeStore;
+pub use zrank::ZRank;
+pub use zrem::ZRem;
+pub use zremrangebylex::ZRemRangeByLex;
+pub use zremrangebyrank::ZRemRangeByRank;
+pub use zremrangebyscore::ZRemRangeByScore;
+pub use zrevrange::ZRevRange;
+pub use zrevrangebylex::ZRevRangeByLex;
+pub use zrevrangebyscore::ZRevRangeByScore;
+pub use zrevrank::ZRevRank;
+pub use zscore::ZScore;
+pub use zunion::ZUnion;
+pub use zunionstore::ZUnionStore;
@@ -0,0 +1,298 @@
+use crate::commands::args::parse_float;
+use crate::prelude::*;
+use crate::storage::zset::ZSetValue;
+
+pub struct ZAdd;
+
+#[async_trait]
+impl CommandTrait for ZAdd {
+    fn name(&self) -> &str {
+        "ZADD"
+    }
+
+    fn arity(&self) -> i64 {
+        -4
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Adds one or more members to a sorted set, or updates their scores. Creates the key if it doesn't exist."
+    }
+
+    fn since(&self) -> &str {
+        "1.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+
+        let mut flags = Flags::default();
+
+        while let Some(arg) = args.front() {
+            match arg.to_ascii_uppercase().as_slice() {
+                b"NX" => flags.nx = true,
+                b"XX" => flags.xx = true,
+                b"GT" => flags.gt = true,
+                b"LT" => flags.lt = true,
+                b"CH" => flags.ch = true,
+                b"INCR" => flags.incr = true,
+                _ => break,
+            }
+
+            args.next()?;
+        }
+
+        if args.is_empty() || !args.len().is_multiple_of(2) {
+            return Err(ReplyError::Syntax.into());
+        }
+
+        if flags.nx && flags.xx {
+            return Ok(value_error!(
+                "XX and NX options at the same time are not compatible"
+            ));
+        }
+
+        if [flags.nx, flags.gt, flags.lt]
+            .iter()
+            .filter(|flag| **flag)
+            .count()
+            > 1
+        {
+            return Ok(value_error!(
+                "GT, LT, and/or NX options at the same time are not compatible"
+            ));
+        }
+
+        if flags.incr && args.len() > 2 {
+            return Ok(value_error!(
+                "INCR option supports a single increment-element pair"
+            ));
+        }
+
+        // every score is checked before anything is added
+        let pairs = args
+            .pairs()?
+            .into_iter()
+            .map(|(score, member)| Ok((parse_float(&score)?, member.to_vec())))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut store = session.state.store.write().await;
+
+        match store.get(&key) {
+            Some(Entry {
+                value: Value::ZSet(_),
+                ..
+            }) => {}
+            Some(_) => return Ok(Frame::wrong_type()),
+            // nothing can be updated in a set that doesn't exist
+            None if flags.xx => {
+                return Ok(match flags.incr {
+                    true => Frame::Nil,
+                    false => Frame::Integer(0),
+                })
+            }
+            None => {}
+        }
+
+        let entry = store.get_or_insert_with(key, || Entry::new(Value::ZSet(ZSetValue::new())));
+
+        let Value::ZSet(zset) = &mut entry.value else {
+            return Ok(Frame::wrong_type());
+        };
+
+        let mut added = 0;
+        let mut changed = 0;
+        let mut last = None;
+
+        for (score, member) in pairs {
+            let current = zset.score(&member);
+
+            let score = match current {
+                Some(_) if flags.nx => continue,
+                None if flags.xx => continue,
+                Some(current) if flags.incr => add_scores(current, score)?,
+                _ => score,
+            };
+
+            match current {
+                Some(current)
+                    if (flags.gt && score <= current) || (flags.lt && score >= current) =>
+                {
+                    continue
+                }
+                Some(current) if current == score => {}
+                Some(_) => {
+                    zset.insert(member, score);
+                    changed += 1;
+                }
+                None => {
+                    zset.insert(member, score);
+                    added += 1;
+                }
+            }
+
+            last = Some(score);
+        }
+
+        if flags.incr {
+            return Ok(last.map_or(Frame::Nil, Frame::Double));
+        }
+
+        Ok(Frame::Integer(if flags.ch {
+            added + changed
+        } else {
+            added
+        }))
+    }
+}
+
+#[derive(Default)]
+struct Flags {
+    nx: bool,
+    xx: bool,
+    gt: bool,
+    lt: bool,
+    ch: bool,
+    incr: bool,
+}
+
+/// Add an increment to a score, adding infinities of opposite signs is an
+/// error instead of a NaN score.
+pub(super) fn add_scores(score: f64, increment: f64) -> Result<f64> {
+    let score = score + increment;
+
+    if score.is_nan() {
+        return Err(ReplyError::Generic("resulting score is not a number (NaN)".into()).into());
+    }
+
+    Ok(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_zadd_conditions() {
+        let session = session().await;
+
+        assert_eq!(run(&session, "ZADD z 1 a 2 b").await, Frame::Integer(2));
+
+        // NX only adds, XX only updates
+        assert_eq!(run(&session, "ZADD z NX 5 a 3 c").await, Frame::Integer(1));
+        assert_eq!(run(&session, "ZSCORE z a").await, Frame::Double(1.0));
+        assert_eq!(run(&session, "ZADD z XX 5 a 4 d").await, Frame::Integer(0));
+        assert_eq!(run(&session, "ZSCORE z a").await, Frame::Double(5.0));
+        assert_eq!(run(&session, "ZSCORE z d").await, Frame::Nil);
+
+        // GT and LT only move scores one way but still add new members
+        assert_eq!(
+            run(&session, "ZADD z GT CH 4 a 9 b 1 e").await,
+            Frame::Integer(2)
+        );
+        assert_eq!(run(&session, "ZSCORE z a").await, Frame::Double(5.0));
+        assert_eq!(run(&session, "ZSCORE z b").await, Frame::Double(9.0));
+        assert_eq!(
+            run(&session, "ZADD z LT CH 7 a 1 b").await,
+            Frame::Integer(1)
+        );
+        assert_eq!(run(&session, "ZSCORE z b").await, Frame::Double(1.0));
+
+        // CH counts changed scores too, an unchanged score is no change
+        assert_eq!(run(&session, "ZADD z 1 b").await, Frame::Integer(0));
+        assert_eq!(run(&session, "ZADD z CH 1 b 2 c").await, Frame::Integer(1));
+
+        assert_eq!(
+            run(&session, "ZADD missing XX 1 a").await,
+            Frame::Integer(0)
+        );
+        assert_eq!(run(&session, "EXISTS missing").await, Frame::Integer(0));
+    }
+
+    #[tokio::test]
+    async fn test_zadd_incr() {
+        let session = session().await;
+
+        assert_eq!(run(&session, "ZADD z INCR 2 a").await, Frame::Double(2.0));
+        assert_eq!(run(&session, "ZADD z INCR 1.5 a").await, Frame::Double(3.5));
+
+        // a skipped update replies nil
+        assert_eq!(run(&session, "ZADD z NX INCR 1 a").await, Frame::Nil);
+        assert_eq!(run(&session, "ZADD z XX INCR 1 b").await, Frame::Nil);
+        assert_eq!(run(&session, "ZADD z GT INCR -1 a").await, Frame::Nil);
+        assert_eq!(
+            run(&session, "ZADD z LT INCR -1 a").await,
+            Frame::Double(2.5)
+        );
+        assert_eq!(run(&session, "ZADD missing XX INCR 1 a").await, Frame::Nil);
+
+        assert_eq!(
+            run(&session, "ZADD z INCR 1 a 2 b").await,
+            value_error!("INCR option supports a single increment-element pair")
+        );
+
+        run(&session, "ZADD z INCR +inf a").await;
+
+        assert_eq!(
+            run(&session, "ZADD z INCR -inf a").await,
+            Frame::from(ReplyError::Generic(
+                "resulting score is not a number (NaN)".into()
+            ))
+        );
+        assert_eq!(
+            run(&session, "ZSCORE z a").await,
+            Frame::Double(f64::INFINITY)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_zadd_errors() {
+        let session = session().await;
+
+        for options in ["NX XX", "XX NX CH"] {
+            assert_eq!(
+                run(&session, &format!("ZADD z {options} 1 a")).await,
+                value_error!("XX and NX options at the same time are not compatible")
+            );
+        }
+
+        for options in ["NX GT", "NX LT", "GT LT", "XX GT LT"] {
+            assert_eq!(
+                run(&session, &format!("ZADD z {options} 1 a")).await,
+                value_error!("GT, LT, and/or NX options at the same time are not compatible")
+            );
+        }
+
+        assert_eq!(
+            run(&session, "ZADD z 1 a 2").await,
+            Frame::from(ReplyError::Syntax)
+        );
+        assert_eq!(
+            run(&session, "ZADD z NX 1").await,
+            Frame::from(ReplyError::Syntax)
+        );
+
+        // every score is checked before anything is added
+        assert_eq!(
+            run(&session, "ZADD z 1 a nan b").await,
+            Frame::from(ReplyError::NotFloat)
+        );
+        assert_eq!(run(&session, "EXISTS z").await, Frame::Integer(0));
+
+        run(&session, "SET text abc").await;
+
+        assert_eq!(run(&session, "ZADD text 1 a").await, Frame::wrong_type());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::prelude::*;
+use crate::storage::zset::ZSetValue;
+use crate::utils::number::parse_double;
+
+pub struct ZCount;
+
+#[async_trait]
+impl CommandTrait for ZCount {
+    fn name(&self) -> &str {
+        "ZCOUNT"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the count of members in a sorted set that have scores within a range."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let range = ScoreRange::parse(&args.next()?, &args.next()?)?;
+
+        let store = session.state.store.read().await;
+
+        match store.get(&key) {
+            Some(Entry {
+                value: Value::ZSet(zset),
+                ..
+            }) => {
+                let (start, end) = range.ranks(zset);
+                Ok(Frame::Integer(end.saturating_sub(start) as i64))
+            }
+            Some(_) => Ok(Frame::wrong_type()),
+            None => Ok(Frame::Integer(0)),
+        }
+    }
+}
+
+/// A score interval like `(1 5`, a bound starting with `(` is exclusive.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct ScoreRange {
+    min: f64,
+    min_exclusive: bool,
+    max: f64,
+    max_exclusive: bool,
+}
+
+impl ScoreRange {
+    pub(super) fn parse(min: &[u8], max: &[u8]) -> Result<Self> {
+        let (min, min_exclusive) = parse_bound(min)?;
+        let (max, max_exclusive) = parse_bound(max)?;
+
+        Ok(Self {
+            min,
+            min_exclusive,
+            max,
+            max_exclusive,
+        })
+    }
+
+    /// The ranks of the members in range as `start..end`, found by two
+    /// lookups instead of a scan. An empty range has `end <= start`.
+    pub(super) fn ranks(&self, zset: &ZSetValue) -> (usize, usize) {
+        let start = zset.partition_point(|score, _| match self.min_exclusive {
+            true => score > self.min,
+            false => score >= self.min,
+        });
+
+        let end = zset.partition_point(|score, _| match self.max_exclusive {
+            true => score >= self.max,
+            false => score > self.max,
+        });
+
+        (start, end)
+    }
+}
+
+fn parse_bound(bound: &[u8]) -> Result<(f64, bool)> {
+    let (value, exclusive) = match bound.strip_prefix(b"(") {
+        Some(value) => (value, true),
+        None => (bound, false),
+    };
+
+    std::str::from_utf8(value)
+        .ok()
+        .filter(|value| !value.is_empty() && !value.starts_with(char::is_whitespace))
+        .and_then(parse_double)
+        .filter(|value| !value.is_nan())
+        .map(|value| (value, exclusive))
+        .ok_or_else(|| ReplyError::Generic("min or max is not a float".into()).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_range() {
+        let zset: ZSetValue = (0..10)
+            .map(|i| (i.to_string().into_bytes(), f64::from(i)))
+            .collect();
+
+        let count = |min: &str, max: &str| {
+            let (start, end) = ScoreRange::parse(min.as_bytes(), max.as_bytes())
+                .unwrap()
+                .ranks(&zset);
+            end.saturating_sub(start)
+        };
+
+        assert_eq!(count("2", "5"), 4);
+        assert_eq!(count("(2", "5"), 3);
+        assert_eq!(count("(2", "(5"), 2);
+        assert_eq!(count("-inf", "+inf"), 10);
+        assert_eq!(count("(9", "inf"), 0);
+        assert_eq!(count("5", "2"), 0);
+        assert_eq!(count("(5", "(5"), 0);
+
+        for bad in ["", "(", "x", "nan", " 1", "((1"] {
+            assert!(ScoreRange::parse(bad.as_bytes(), b"1").is_err());
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use super::zadd::add_scores;
+use crate::prelude::*;
+use crate::storage::zset::ZSetValue;
+
+pub struct ZIncrBy;
+
+#[async_trait]
+impl CommandTrait for ZIncrBy {
+    fn name(&self) -> &str {
+        "ZINCRBY"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Increments the score of a member in a sorted set."
+    }
+
+    fn since(&self) -> &str {
+        "1.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?.to_vec();
+        let increment = args.next_float()?;
+        let member = args.next()?.to_vec();
+
+        let mut store = session.state.store.write().await;
+
+        if store
+            .get(&key)
+            .is_some_and(|entry| !matches!(entry.value, Value::ZSet(_)))
+        {
+            return Ok(Frame::wrong_type());
+        }
+
+        let entry = store.get_or_insert_with(key, || Entry::new(Value::ZSet(ZSetValue::new())));
+
+        let Value::ZSet(zset) = &mut entry.value else {
+            return Ok(Frame::wrong_type());
+        };
+
+        let score = match zset.score(&member) {
+            Some(current) => add_scores(current, increment)?,
+            None => increment,
+        };
+
+        zset.insert(member, score);
+
+        Ok(Frame::Double(score))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::testing::{run, session};
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_zincrby() {
+        let session = session().await;
+
+        assert_eq!(run(&session, "ZINCRBY z 2 a").await, Frame::Double(2.0));
+        assert_eq!(run(&session, "ZINCRBY z -0.5 a").await, Frame::Double(1.5));
+        assert_eq!(
+            run(&session, "ZINCRBY z +inf a").await,
+            Frame::Double(f64::INFINITY)
+        );
+
+        // infinities of opposite signs make no score, the old one stays
+        assert_eq!(
+            run(&session, "ZINCRBY z -inf a").await,
+            Frame::from(ReplyError::Generic(
+                "resulting score is not a number (NaN)".into()
+            ))
+        );
+        assert_eq!(
+            run(&session, "ZSCORE z a").await,
+            Frame::Double(f64::INFINITY)
+        );
+
+        assert_eq!(
+            run(&session, "ZINCRBY z nan a").await,
+            Frame::from(ReplyError::NotFloat)
+        );
+        assert_eq!(
+            run(&session, "ZINCRBY new nan a").await,
+            Frame::from(ReplyError::NotFloat)
+        );
+        assert_eq!(run(&session, "EXISTS new").await, Frame::Integer(0));
+
+        run(&session, "SET text abc").await;
+
+        assert_eq!(run(&session, "ZINCRBY text 1 a").await, Frame::wrong_type());
+    }
+}
@@ -0,0 +1,39 @@
+use super::zunion::{reply_combined, ZSetOp};
+use crate::prelude::*;
+
+pub struct ZInter;
+
+#[async_trait]
+impl CommandTrait for ZInter {
+    fn name(&self) -> &str {
+        "ZINTER"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the intersect of multiple sorted sets."
+    }
+
+    fn since(&self) -> &str {
+        "6.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::MovableKeys]
+    }
+
+    fn numkeys_index(&self) -> i64 {
+        1
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        reply_combined(args, session, ZSetOp::Inter).await
+    }
+}
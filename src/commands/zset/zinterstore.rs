@@ -0,0 +1,47 @@
+use super::zunion::{store_combined, ZSetOp};
+use crate::prelude::*;
+
+pub struct ZInterStore;
+
+#[async_trait]
+impl CommandTrait for ZInterStore {
+    fn name(&self) -> &str {
+        "ZINTERSTORE"
+    }
+
+    fn arity(&self) -> i64 {
+        -4
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Stores the intersect of multiple sorted sets in a key."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[
+            CommandFlag::Write,
+            CommandFlag::DenyOom,
+            CommandFlag::MovableKeys,
+        ]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    fn numkeys_index(&self) -> i64 {
+        2
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        store_combined(args, session, ZSetOp::Inter).await
+    }
+}
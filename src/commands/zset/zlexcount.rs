@@ -0,0 +1,145 @@
+use crate::prelude::*;
+use crate::storage::zset::ZSetValue;
+
+pub struct ZLexCount;
+
+#[async_trait]
+impl CommandTrait for ZLexCount {
+    fn name(&self) -> &str {
+        "ZLEXCOUNT"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the number of members in a sorted set within a lexicographical range."
+    }
+
+    fn since(&self) -> &str {
+        "2.8.9"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let range = LexRange::parse(&args.next()?, &args.next()?)?;
+
+        let store = session.state.store.read().await;
+
+        match store.get(&key) {
+            Some(Entry {
+                value: Value::ZSet(zset),
+                ..
+            }) => {
+                let (start, end) = range.ranks(zset);
+                Ok(Frame::Integer(end.saturating_sub(start) as i64))
+            }
+            Some(_) => Ok(Frame::wrong_type()),
+            None => Ok(Frame::Integer(0)),
+        }
+    }
+}
+
+/// One end of a lexicographical range: `-` and `+` are the open ends, `[`
+/// starts an inclusive bound and `(` an exclusive one.
+#[derive(Clone, Debug, PartialEq)]
+enum LexBound {
+    Min,
+    Max,
+    Inclusive(Vec<u8>),
+    Exclusive(Vec<u8>),
+}
+
+impl LexBound {
+    fn parse(bound: &[u8]) -> Result<Self> {
+        match bound {
+            b"-" => Ok(Self::Min),
+            b"+" => Ok(Self::Max),
+            [b'[', value @ ..] => Ok(Self::Inclusive(value.to_vec())),
+            [b'(', value @ ..] => Ok(Self::Exclusive(value.to_vec())),
+            _ => Err(ReplyError::Generic("min or max not valid string range item".into()).into()),
+        }
+    }
+}
+
+/// A member interval like `[a (c`. Only meaningful when every member has the
+/// same score, the members are then in lexicographical order.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct LexRange {
+    min: LexBound,
+    max: LexBound,
+}
+
+impl LexRange {
+    pub(super) fn parse(min: &[u8], max: &[u8]) -> Result<Self> {
+        Ok(Self {
+            min: LexBound::parse(min)?,
+            max: LexBound::parse(max)?,
+        })
+    }
+
+    /// The ranks of the members in range as `start..end`, found by two
+    /// lookups instead of a scan. An empty range has `end <= start`.
+    pub(super) fn ranks(&self, zset: &ZSetValue) -> (usize, usize) {
+        let start = zset.partition_point(|_, member| match &self.min {
+            LexBound::Min => true,
+            LexBound::Max => false,
+            LexBound::Inclusive(min) => member >= &min[..],
+            LexBound::Exclusive(min) => member > &min[..],
+        });
+
+        let end = zset.partition_point(|_, member| match &self.max {
+            LexBound::Min => true,
+            LexBound::Max => false,
+            LexBound::Inclusive(max) => member > &max[..],
+            LexBound::Exclusive(max) => member >= &max[..],
+        });
+
+        (start, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lex_range() {
+        let zset: ZSetValue = [b"a", b"b", b"c", b"d", b"e"]
+            .into_iter()
+            .map(|member| (member.to_vec(), 0.0))
+            .collect();
+
+        let count = |min: &str, max: &str| {
+            let (start, end) = LexRange::parse(min.as_bytes(), max.as_bytes())
+                .unwrap()
+                .ranks(&zset);
+            end.saturating_sub(start)
+        };
+
+        assert_eq!(count("-", "+"), 5);
+        assert_eq!(count("[b", "[d"), 3);
+        assert_eq!(count("(b", "(d"), 1);
+        assert_eq!(count("[bb", "+"), 3);
+        assert_eq!(count("+", "-"), 0);
+        assert_eq!(count("-", "-"), 0);
+        assert_eq!(count("[d", "[b"), 0);
+
+        for bad in ["", "a", "inf"] {
+            assert!(LexRange::parse(bad.as_bytes(), b"+").is_err());
+        }
+    }
+}
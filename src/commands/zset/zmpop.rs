@@ -0,0 +1,128 @@
+use crate::commands::args::{parse_int, Opt, OptKind};
+use crate::prelude::*;
+use crate::storage::db::Db;
+
+pub struct ZMPop;
+
+#[async_trait]
+impl CommandTrait for ZMPop {
+    fn name(&self) -> &str {
+        "ZMPOP"
+    }
+
+    fn arity(&self) -> i64 {
+        -4
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the highest- or lowest-scoring members from one or more sorted sets after removing them. Deletes the sorted set if the last member was popped."
+    }
+
+    fn since(&self) -> &str {
+        "7.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::MovableKeys]
+    }
+
+    fn numkeys_index(&self) -> i64 {
+        1
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let mpop = ZMPopArgs::parse(args)?;
+
+        let mut store = session.state.store.write().await;
+
+        Ok(mpop.pop(&mut store)?.unwrap_or(Frame::NullArray))
+    }
+}
+
+/// The arguments of `ZMPOP` after the timeout of `BZMPOP`:
+/// `numkeys key [key ...] MIN|MAX [COUNT count]`.
+pub(super) struct ZMPopArgs {
+    pub keys: Vec<Bytes>,
+    pub max: bool,
+    pub count: usize,
+}
+
+impl ZMPopArgs {
+    pub fn parse(args: &mut Args) -> Result<Self> {
+        let numkeys = parse_int(&args.next()?)
+            .ok()
+            .and_then(|numkeys| usize::try_from(numkeys).ok())
+            .filter(|numkeys| *numkeys > 0);
+
+        let Some(numkeys) = numkeys else {
+            return Err(ReplyError::Generic("numkeys should be greater than 0".into()).into());
+        };
+
+        // the keys have to be followed by MIN or MAX
+        if numkeys >= args.len() {
+            return Err(ReplyError::Syntax.into());
+        }
+
+        let keys = (0..numkeys).map(|_| args.next()).collect::<Result<_>>()?;
+
+        let max = match args.next()?.to_ascii_uppercase().as_slice() {
+            b"MIN" => false,
+            b"MAX" => true,
+            _ => return Err(ReplyError::Syntax.into()),
+        };
+
+        let options = args.options(OPTIONS)?;
+
+        let count = match options.int("COUNT").map(usize::try_from) {
+            None => 1,
+            Some(Ok(count)) if count > 0 => count,
+            Some(_) => {
+                return Err(ReplyError::Generic("count should be greater than 0".into()).into())
+            }
+        };
+
+        Ok(Self { keys, max, count })
+    }
+
+    /// Pop from the first key holding a non empty sorted set, replies the key
+    /// and the popped members with their scores or `None` if every sorted set
+    /// is empty.
+    pub fn pop(&self, store: &mut Db) -> Result<Option<Frame>> {
+        for key in &self.keys {
+            let zset = match store.get_mut(key) {
+                Some(Entry {
+                    value: Value::ZSet(zset),
+                    ..
+                }) => zset,
+                Some(_) => return Err(ReplyError::WrongType.into()),
+                None => continue,
+            };
+
+            let popped = zset.pop(self.count, self.max);
+
+            if zset.is_empty() {
+                store.remove(key);
+            }
+
+            let popped = popped
+                .into_iter()
+                .map(|(member, score)| {
+                    Frame::Array(VecDeque::from([Frame::Bulk(member), Frame::Double(score)]))
+                })
+                .collect();
+
+            return Ok(Some(Frame::Array(VecDeque::from([
+                Frame::Bulk(key.to_vec()),
+                Frame::Array(popped),
+            ]))));
+        }
+
+        Ok(None)
+    }
+}
+
+const OPTIONS: &[Opt] = &[Opt::new("COUNT", OptKind::Int, 0)];
@@ -0,0 +1,56 @@
+use crate::prelude::*;
+
+pub struct ZMScore;
+
+#[async_trait]
+impl CommandTrait for ZMScore {
+    fn name(&self) -> &str {
+        "ZMSCORE"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the score of one or more members in a sorted set."
+    }
+
+    fn since(&self) -> &str {
+        "6.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let store = session.state.store.read().await;
+
+        let zset = match store.get(&key) {
+            Some(Entry {
+                value: Value::ZSet(zset),
+                ..
+            }) => Some(zset),
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => None,
+        };
+
+        Ok(Frame::Array(
+            args.rest()
+                .map(|member| zset.and_then(|zset| zset.score(&member)))
+                .map(|score| score.map_or(Frame::Nil, Frame::Double))
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,39 @@
+use super::zpopmin::zpop;
+use crate::prelude::*;
+
+pub struct ZPopMax;
+
+#[async_trait]
+impl CommandTrait for ZPopMax {
+    fn name(&self) -> &str {
+        "ZPOPMAX"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the highest-scoring members from a sorted set after removing them. Deletes the sorted set if the last member was popped."
+    }
+
+    fn since(&self) -> &str {
+        "5.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        zpop(args, session, true).await
+    }
+}
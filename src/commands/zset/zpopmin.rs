@@ -0,0 +1,81 @@
+use super::zrange::scored_reply;
+use crate::prelude::*;
+
+pub struct ZPopMin;
+
+#[async_trait]
+impl CommandTrait for ZPopMin {
+    fn name(&self) -> &str {
+        "ZPOPMIN"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the lowest-scoring members from a sorted set after removing them. Deletes the sorted set if the last member was popped."
+    }
+
+    fn since(&self) -> &str {
+        "5.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        zpop(args, session, false).await
+    }
+}
+
+/// Shared by `ZPOPMIN` and `ZPOPMAX`, `max` pops the highest scores.
+pub(super) async fn zpop(args: &mut Args, session: SessionRef, max: bool) -> Result<Frame> {
+    if args.len() > 2 {
+        return Err(ReplyError::Syntax.into());
+    }
+
+    let key = args.next()?;
+
+    let count = if args.is_empty() {
+        None
+    } else {
+        match usize::try_from(args.next_int()?) {
+            Ok(count) => Some(count),
+            Err(_) => return Ok(value_error!("value is out of range, must be positive")),
+        }
+    };
+
+    let mut store = session.state.store.write().await;
+
+    let zset = match store.get_mut(&key) {
+        Some(Entry {
+            value: Value::ZSet(zset),
+            ..
+        }) => zset,
+        Some(_) => return Ok(Frame::wrong_type()),
+        None => return Ok(Frame::Array(VecDeque::new())),
+    };
+
+    let popped = zset.pop(count.unwrap_or(1), max);
+
+    if zset.is_empty() {
+        store.remove(&key);
+    }
+
+    // a single member comes back flat, even in RESP3
+    Ok(scored_reply(
+        popped.iter().map(|(member, score)| (&member[..], *score)),
+        true,
+        count.is_some() && session.get_proto_version() == 3,
+    ))
+}
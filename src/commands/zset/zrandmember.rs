@@ -0,0 +1,91 @@
+use super::zrange::scored_reply;
+use crate::prelude::*;
+
+pub struct ZRandMember;
+
+#[async_trait]
+impl CommandTrait for ZRandMember {
+    fn name(&self) -> &str {
+        "ZRANDMEMBER"
+    }
+
+    fn arity(&self) -> i64 {
+        -2
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns one or more random members from a sorted set."
+    }
+
+    fn since(&self) -> &str {
+        "6.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        if args.len() > 3 {
+            return Err(ReplyError::Syntax.into());
+        }
+
+        let key = args.next()?;
+        let count = if args.is_empty() {
+            None
+        } else {
+            Some(args.next_int()?)
+        };
+
+        let with_scores = match args.rest().next() {
+            Some(arg) if arg.eq_ignore_ascii_case(b"WITHSCORES") => true,
+            Some(_) => return Err(ReplyError::Syntax.into()),
+            None => false,
+        };
+
+        let store = session.state.store.read().await;
+
+        let zset = match store.get(&key) {
+            Some(Entry {
+                value: Value::ZSet(zset),
+                ..
+            }) => zset,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None if count.is_some() => return Ok(Frame::Array(VecDeque::new())),
+            None => return Ok(Frame::Nil),
+        };
+
+        let Some(count) = count else {
+            return Ok(zset
+                .random()
+                .map_or(Frame::Nil, |(member, _)| Frame::Bulk(member)));
+        };
+
+        // a negative count may return the same member more than once
+        let entries = if count >= 0 {
+            zset.sample(usize::try_from(count).unwrap_or(usize::MAX))
+        } else {
+            let count = usize::try_from(count.unsigned_abs()).unwrap_or(usize::MAX);
+
+            if count > session.state.limits.max_multibulk_len {
+                return Ok(value_error!("value is out of range"));
+            }
+
+            (0..count).filter_map(|_| zset.random()).collect()
+        };
+
+        Ok(scored_reply(
+            entries.iter().map(|(member, score)| (&member[..], *score)),
+            with_scores,
+            session.get_proto_version() == 3,
+        ))
+    }
+}
@@ -0,0 +1,288 @@
+use super::zcount::ScoreRange;
+use super::zlexcount::LexRange;
+use crate::commands::args::{parse_int, range};
+use crate::prelude::*;
+use crate::storage::zset::ZSetValue;
+
+pub struct ZRange;
+
+#[async_trait]
+impl CommandTrait for ZRange {
+    fn name(&self) -> &str {
+        "ZRANGE"
+    }
+
+    fn arity(&self) -> i64 {
+        -4
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns members in a sorted set within a range of indexes, scores or lexicographical order."
+    }
+
+    fn since(&self) -> &str {
+        "1.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        zrange(args, session, None).await
+    }
+}
+
+/// What the bounds of a range are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum By {
+    Rank,
+    Score,
+    Lex,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Bounds {
+    Rank(i64, i64),
+    Score(ScoreRange),
+    Lex(LexRange),
+}
+
+/// A parsed `ZRANGE` style query: `min max` followed by the options.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct RangeQuery {
+    bounds: Bounds,
+    rev: bool,
+    offset: usize,
+    count: Option<usize>,
+    pub(super) with_scores: bool,
+}
+
+impl RangeQuery {
+    /// Parse the range and its options. The legacy commands pass the `by`
+    /// and `rev` their name implies in `fixed` and don't take them as
+    /// options, `store` leaves out `WITHSCORES`.
+    pub(super) fn parse(args: &mut Args, fixed: Option<(By, bool)>, store: bool) -> Result<Self> {
+        let min = args.next()?;
+        let max = args.next()?;
+
+        let (mut by, mut rev) = fixed.unwrap_or((By::Rank, false));
+        let mut limit = None;
+        let mut with_scores = false;
+
+        while !args.is_empty() {
+            let arg = args.next()?;
+
+            match arg.to_ascii_uppercase().as_slice() {
+                b"WITHSCORES" if !store => with_scores = true,
+                b"BYSCORE" if fixed.is_none() && by != By::Lex => by = By::Score,
+                b"BYLEX" if fixed.is_none() && by != By::Score => by = By::Lex,
+                b"REV" if fixed.is_none() => rev = true,
+                b"LIMIT" => {
+                    let offset = parse_int(&args.next_value()?)?;
+                    let count = parse_int(&args.next_value()?)?;
+
+                    limit = Some((offset, count));
+                }
+                _ => return Err(ReplyError::Syntax.into()),
+            }
+        }
+
+        if limit.is_some() && by == By::Rank {
+            return Err(ReplyError::Generic(
+                "syntax error, LIMIT is only supported in combination with either BYSCORE or BYLEX"
+                    .into(),
+            )
+            .into());
+        }
+
+        if with_scores && by == By::Lex {
+            return Err(ReplyError::Generic(
+                "syntax error, WITHSCORES not supported in combination with BYLEX".into(),
+            )
+            .into());
+        }
+
+        // reversed score and lex ranges are given from the top down
+        let (min, max) = match rev && by != By::Rank {
+            true => (max, min),
+            false => (min, max),
+        };
+
+        let bounds = match by {
+            By::Rank => Bounds::Rank(parse_int(&min)?, parse_int(&max)?),
+            By::Score => Bounds::Score(ScoreRange::parse(&min, &max)?),
+            By::Lex => Bounds::Lex(LexRange::parse(&min, &max)?),
+        };
+
+        // a negative offset selects nothing, a negative count everything
+        let (offset, count) = match limit {
+            Some((offset, _)) if offset < 0 => (0, Some(0)),
+            Some((offset, count)) => (offset as usize, usize::try_from(count).ok()),
+            None => (0, None),
+        };
+
+        Ok(Self {
+            bounds,
+            rev,
+            offset,
+            count,
+            with_scores,
+        })
+    }
+
+    /// The ascending ranks `start..end` of the members in range, before the
+    /// limit is applied.
+    pub(super) fn ranks(&self, zset: &ZSetValue) -> (usize, usize) {
+        let (start, end) = match &self.bounds {
+            Bounds::Rank(start, stop) => match range(*start, *stop, zset.len()) {
+                // ranks counted from the top are mirrored
+                Some((start, stop)) if self.rev => (zset.len() - 1 - stop, zset.len() - start),
+                Some((start, stop)) => (start, stop + 1),
+                None => (0, 0),
+            },
+            Bounds::Score(range) => range.ranks(zset),
+            Bounds::Lex(range) => range.ranks(zset),
+        };
+
+        (start, end.max(start))
+    }
+
+    /// The members selected, in reply order. Only the members returned are
+    /// visited once the first one is found.
+    pub(super) fn select<'a>(
+        &self,
+        zset: &'a ZSetValue,
+    ) -> Box<dyn Iterator<Item = (&'a [u8], f64)> + Send + 'a> {
+        let (start, end) = self.ranks(zset);
+
+        let len = end - start;
+        let skip = self.offset.min(len);
+        let take = self.count.unwrap_or(usize::MAX).min(len - skip);
+
+        if take == 0 {
+            return Box::new(std::iter::empty());
+        }
+
+        match self.rev {
+            true => Box::new(zset.iter_rev_from(end - 1 - skip).take(take)),
+            false => Box::new(zset.iter_from(start + skip).take(take)),
+        }
+    }
+}
+
+/// Shared by `ZRANGE` and its legacy forms.
+pub(super) async fn zrange(
+    args: &mut Args,
+    session: SessionRef,
+    fixed: Option<(By, bool)>,
+) -> Result<Frame> {
+    let key = args.next()?;
+    let query = RangeQuery::parse(args, fixed, false)?;
+
+    let store = session.state.store.read().await;
+
+    let zset = match store.get(&key) {
+        Some(Entry {
+            value: Value::ZSet(zset),
+            ..
+        }) => zset,
+        Some(_) => return Ok(Frame::wrong_type()),
+        None => return Ok(Frame::Array(VecDeque::new())),
+    };
+
+    Ok(scored_reply(
+        query.select(zset),
+        query.with_scores,
+        session.get_proto_version() == 3,
+    ))
+}
+
+/// Members, optionally with their scores. RESP3 pairs each member up with its
+/// score, RESP2 interleaves them.
+pub(super) fn scored_reply<'a>(
+    entries: impl Iterator<Item = (&'a [u8], f64)>,
+    with_scores: bool,
+    resp3: bool,
+) -> Frame {
+    let mut reply = VecDeque::new();
+
+    for (member, score) in entries {
+        let member = Frame::Bulk(member.to_vec());
+
+        match (with_scores, resp3) {
+            (false, _) => reply.push_back(member),
+            (true, false) => reply.extend([member, Frame::Double(score)]),
+            (true, true) => {
+                reply.push_back(Frame::Array(VecDeque::from([member, Frame::Double(score)])))
+            }
+        }
+    }
+
+    Frame::Array(reply)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(args: &[&str]) -> Result<RangeQuery> {
+        let mut args = Args::new(
+            "ZRANGE",
+            args.iter()
+                .map(|arg| Bytes::from(arg.to_string()))
+                .collect(),
+        );
+
+        RangeQuery::parse(&mut args, None, false)
+    }
+
+    fn select(zset: &ZSetValue, args: &[&str]) -> Vec<String> {
+        query(args)
+            .unwrap()
+            .select(zset)
+            .map(|(member, _)| String::from_utf8_lossy(member).into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_range_query() {
+        let zset: ZSetValue = (0..10)
+            .map(|i| (format!("m{i}").into_bytes(), f64::from(i)))
+            .collect();
+
+        assert_eq!(select(&zset, &["0", "2"]), ["m0", "m1", "m2"]);
+        assert_eq!(select(&zset, &["-2", "-1"]), ["m8", "m9"]);
+        assert_eq!(select(&zset, &["0", "1", "REV"]), ["m9", "m8"]);
+        assert_eq!(select(&zset, &["5", "3"]), Vec::<String>::new());
+        assert_eq!(select(&zset, &["(7", "+inf", "BYSCORE"]), ["m8", "m9"]);
+        assert_eq!(
+            select(&zset, &["+inf", "(7", "byscore", "rev", "LIMIT", "1", "5"]),
+            ["m8"]
+        );
+        assert_eq!(
+            select(&zset, &["2", "8", "BYSCORE", "LIMIT", "2", "-1"]),
+            ["m4", "m5", "m6", "m7", "m8"]
+        );
+        assert_eq!(
+            select(&zset, &["-inf", "+inf", "BYSCORE", "LIMIT", "-1", "2"]),
+            Vec::<String>::new()
+        );
+        assert_eq!(select(&zset, &["[m8", "+", "BYLEX"]), ["m8", "m9"]);
+        assert_eq!(select(&zset, &["(m2", "-", "BYLEX", "REV"]), ["m1", "m0"]);
+
+        assert!(query(&["0", "1", "LIMIT", "0", "1"]).is_err());
+        assert!(query(&["-", "+", "BYLEX", "WITHSCORES"]).is_err());
+        assert!(query(&["0", "1", "BYSCORE", "BYLEX"]).is_err());
+        assert!(query(&["0", "1", "LIMIT", "0"]).is_err());
+        assert!(query(&["a", "1"]).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use super::zrange::RangeQuery;
+use crate::prelude::*;
+use crate::storage::zset::ZSetValue;
+
+pub struct ZRangeStore;
+
+#[async_trait]
+impl CommandTrait for ZRangeStore {
+    fn name(&self) -> &str {
+        "ZRANGESTORE"
+    }
+
+    fn arity(&self) -> i64 {
+        -5
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Stores a range of members from sorted set in a key."
+    }
+
+    fn since(&self) -> &str {
+        "6.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::DenyOom]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 2, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let destination = args.next()?.to_vec();
+        let source = args.next()?;
+        let query = RangeQuery::parse(args, None, true)?;
+
+        let mut store = session.state.store.write().await;
+
+        let range: ZSetValue = match store.get(&source) {
+            Some(Entry {
+                value: Value::ZSet(zset),
+                ..
+            }) => query
+                .select(zset)
+                .map(|(member, score)| (member.to_vec(), score))
+                .collect(),
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => ZSetValue::new(),
+        };
+
+        let len = range.len();
+
+        // the destination is overwritten whatever it held, an empty range deletes it
+        if range.is_empty() {
+            store.remove(&destination);
+        } else {
+            store.insert(destination, Entry::new(Value::ZSet(range)));
+        }
+
+        Ok(Frame::Integer(len as i64))
+    }
+}
@@ -0,0 +1,80 @@
+use crate::prelude::*;
+
+pub struct ZRank;
+
+#[async_trait]
+impl CommandTrait for ZRank {
+    fn name(&self) -> &str {
+        "ZRANK"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the index of a member in a sorted set ordered by ascending scores."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        rank(args, session, false).await
+    }
+}
+
+/// Shared by `ZRANK` and `ZREVRANK`, `rev` counts from the highest score.
+pub(super) async fn rank(args: &mut Args, session: SessionRef, rev: bool) -> Result<Frame> {
+    if args.len() > 3 {
+        return Err(ReplyError::Syntax.into());
+    }
+
+    let key = args.next()?;
+    let member = args.next()?;
+
+    let with_score = match args.rest().next() {
+        Some(arg) if arg.eq_ignore_ascii_case(b"WITHSCORE") => true,
+        Some(_) => return Err(ReplyError::Syntax.into()),
+        None => false,
+    };
+
+    let store = session.state.store.read().await;
+
+    let zset = match store.get(&key) {
+        Some(Entry {
+            value: Value::ZSet(zset),
+            ..
+        }) => zset,
+        Some(_) => return Ok(Frame::wrong_type()),
+        None => return Ok(Frame::Nil),
+    };
+
+    let (Some(rank), Some(score)) = (zset.rank(&member), zset.score(&member)) else {
+        return Ok(Frame::Nil);
+    };
+
+    let rank = if rev { zset.len() - 1 - rank } else { rank };
+
+    if with_score {
+        return Ok(Frame::Array(VecDeque::from([
+            Frame::Integer(rank as i64),
+            Frame::Double(score),
+        ])));
+    }
+
+    Ok(Frame::Integer(rank as i64))
+}
@@ -0,0 +1,60 @@
+use crate::prelude::*;
+
+pub struct ZRem;
+
+#[async_trait]
+impl CommandTrait for ZRem {
+    fn name(&self) -> &str {
+        "ZREM"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes one or more members from a sorted set. Deletes the sorted set if all members were removed."
+    }
+
+    fn since(&self) -> &str {
+        "1.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+
+        let mut store = session.state.store.write().await;
+
+        let zset = match store.get_mut(&key) {
+            Some(Entry {
+                value: Value::ZSet(zset),
+                ..
+            }) => zset,
+            Some(_) => return Ok(Frame::wrong_type()),
+            None => return Ok(Frame::Integer(0)),
+        };
+
+        let removed = args
+            .rest()
+            .filter(|member| zset.remove(member).is_some())
+            .count();
+
+        if zset.is_empty() {
+            store.remove(&key);
+        }
+
+        Ok(Frame::Integer(removed as i64))
+    }
+}
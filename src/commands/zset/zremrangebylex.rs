@@ -0,0 +1,40 @@
+use super::zrange::By;
+use super::zremrangebyrank::remove_range;
+use crate::prelude::*;
+
+pub struct ZRemRangeByLex;
+
+#[async_trait]
+impl CommandTrait for ZRemRangeByLex {
+    fn name(&self) -> &str {
+        "ZREMRANGEBYLEX"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes members in a sorted set within a lexicographical range. Deletes the sorted set if all members were removed."
+    }
+
+    fn since(&self) -> &str {
+        "2.8.9"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        remove_range(args, session, By::Lex).await
+    }
+}
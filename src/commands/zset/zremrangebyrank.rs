@@ -0,0 +1,71 @@
+use super::zrange::{By, RangeQuery};
+use crate::prelude::*;
+
+pub struct ZRemRangeByRank;
+
+#[async_trait]
+impl CommandTrait for ZRemRangeByRank {
+    fn name(&self) -> &str {
+        "ZREMRANGEBYRANK"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes members in a sorted set within a range of indexes. Deletes the sorted set if all members were removed."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        remove_range(args, session, By::Rank).await
+    }
+}
+
+/// Shared by the `ZREMRANGEBY*` commands.
+pub(super) async fn remove_range(args: &mut Args, session: SessionRef, by: By) -> Result<Frame> {
+    let key = args.next()?;
+    let query = RangeQuery::parse(args, Some((by, false)), true)?;
+
+    let mut store = session.state.store.write().await;
+
+    let zset = match store.get_mut(&key) {
+        Some(Entry {
+            value: Value::ZSet(zset),
+            ..
+        }) => zset,
+        Some(_) => return Ok(Frame::wrong_type()),
+        None => return Ok(Frame::Integer(0)),
+    };
+
+    let members: Vec<Vec<u8>> = query
+        .select(zset)
+        .map(|(member, _)| member.to_vec())
+        .collect();
+
+    for member in &members {
+        zset.remove(member);
+    }
+
+    if zset.is_empty() {
+        store.remove(&key);
+    }
+
+    Ok(Frame::Integer(members.len() as i64))
+}
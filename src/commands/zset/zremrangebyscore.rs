@@ -0,0 +1,40 @@
+use super::zrange::By;
+use super::zremrangebyrank::remove_range;
+use crate::prelude::*;
+
+pub struct ZRemRangeByScore;
+
+#[async_trait]
+impl CommandTrait for ZRemRangeByScore {
+    fn name(&self) -> &str {
+        "ZREMRANGEBYSCORE"
+    }
+
+    fn arity(&self) -> i64 {
+        4
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Removes members in a sorted set within a range of scores. Deletes the sorted set if all members were removed."
+    }
+
+    fn since(&self) -> &str {
+        "1.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::Write]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        remove_range(args, session, By::Score).await
+    }
+}
@@ -0,0 +1,39 @@
+use super::zrange::{zrange, By};
+use crate::prelude::*;
+
+pub struct ZRevRangeByLex;
+
+#[async_trait]
+impl CommandTrait for ZRevRangeByLex {
+    fn name(&self) -> &str {
+        "ZREVRANGEBYLEX"
+    }
+
+    fn arity(&self) -> i64 {
+        -4
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns members in a sorted set within a lexicographical range in reverse order."
+    }
+
+    fn since(&self) -> &str {
+        "2.8.9"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        zrange(args, session, Some((By::Lex, true))).await
+    }
+}
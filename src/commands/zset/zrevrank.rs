@@ -0,0 +1,39 @@
+use super::zrank::rank;
+use crate::prelude::*;
+
+pub struct ZRevRank;
+
+#[async_trait]
+impl CommandTrait for ZRevRank {
+    fn name(&self) -> &str {
+        "ZREVRANK"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the index of a member in a sorted set ordered by descending scores."
+    }
+
+    fn since(&self) -> &str {
+        "2.0.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        rank(args, session, true).await
+    }
+}
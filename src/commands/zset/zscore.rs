@@ -0,0 +1,50 @@
+use crate::prelude::*;
+
+pub struct ZScore;
+
+#[async_trait]
+impl CommandTrait for ZScore {
+    fn name(&self) -> &str {
+        "ZSCORE"
+    }
+
+    fn arity(&self) -> i64 {
+        3
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the score of a member in a sorted set."
+    }
+
+    fn since(&self) -> &str {
+        "1.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::Fast]
+    }
+
+    fn key_range(&self) -> (i64, i64, i64) {
+        (1, 1, 1)
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        let key = args.next()?;
+        let member = args.next()?;
+
+        let store = session.state.store.read().await;
+
+        match store.get(&key) {
+            Some(Entry {
+                value: Value::ZSet(zset),
+                ..
+            }) => Ok(zset.score(&member).map_or(Frame::Nil, Frame::Double)),
+            Some(_) => Ok(Frame::wrong_type()),
+            None => Ok(Frame::Nil),
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use std::borrow::Cow;
+
+use super::zadd::add_scores;
+use super::zrange::scored_reply;
+use crate::commands::args::{parse_float, parse_int};
+use crate::prelude::*;
+use crate::storage::db::Db;
+use crate::storage::set::SetValue;
+use crate::storage::zset::ZSetValue;
+
+pub struct ZUnion;
+
+#[async_trait]
+impl CommandTrait for ZUnion {
+    fn name(&self) -> &str {
+        "ZUNION"
+    }
+
+    fn arity(&self) -> i64 {
+        -3
+    }
+
+    fn group(&self) -> &str {
+        "sorted-set"
+    }
+
+    fn summary(&self) -> &str {
+        "Returns the union of multiple sorted sets."
+    }
+
+    fn since(&self) -> &str {
+        "6.2.0"
+    }
+
+    fn flags(&self) -> &[CommandFlag] {
+        &[CommandFlag::ReadOnly, CommandFlag::MovableKeys]
+    }
+
+    fn numkeys_index(&self) -> i64 {
+        1
+    }
+
+    async fn handle_command(&self, args: &mut Args, session: SessionRef) -> Result<Frame> {
+        reply_combined(args, session, ZSetOp::Union).await
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum ZSetOp {
+    Union,
+    Inter,
+    Diff,
+}
+
+/// How the scores of a member found in several sets are merged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Aggregate {
+    Sum,
+    Min,
+    Max,
+}
+
+impl Aggregate {
+    fn apply(self, a: f64, b: f64) -> f64 {
+        match self {
+            // inf + -inf is taken as zero rather than failing the command
+            Self::Sum => add_scores(a, b).unwrap_or(0.0),
+            Self::Min => a.min(b),
+            Self::Max => a.max(b),
+        }
+    }
+}
+
+/// The arguments after the destination of the store variants:
+/// `numkeys key [key ...] [WEIGHTS weight ...] [AGGREGATE SUM|MIN|MAX]`,
+/// plus `WITHSCORES` when replying.
+pub(super) struct CombineArgs {
+    keys: Vec<Bytes>,
+    weights: Vec<f64>,
+    aggregate: Aggregate,
+    pub(super) with_scores: bool,
+}
+
+impl CombineArgs {
+    pub(super) fn parse(args: &mut Args, op: ZSetOp, store: bool) -> Result<Self> {
+        let numkeys = parse_int(&args.next()?)?;
+
+        let numkeys = match usize::try_from(numkeys) {
+            Ok(numkeys) if numkeys > 0 => numkeys,
+            _ => {
+                return Err(ReplyError::Generic(format!(
+                    "at least 1 input key is needed for '{}' command",
+                    args.command()
+                ))
+                .into())
+            }
+        };
+
+        if numkeys > args.len() {
+            return Err(ReplyError::Syntax.into());
+        }
+
+        let keys: Vec<Bytes> = (0..numkeys).map(|_| args.next()).collect::<Result<_>>()?;
+
+        let mut parsed = Self {
+            weights: vec![1.0; keys.len()],
+            keys,
+            aggregate: Aggregate::Sum,
+            with_scores: false,
+        };
+
+        while !args.is_empty() {
+            let arg = args.next()?;
+
+            match arg.to_ascii_uppercase().as_slice() {
+                b"WITHSCORES" if !store => parsed.with_scores = true,
+                b"WEIGHTS" if op != ZSetOp::Diff => {
+                    for weight in &mut parsed.weights {
+                        *weight = parse_weight(&args.next_value()?)?;
+                    }
+                }
+                b"AGGREGATE" if op != ZSetOp::Diff => {
+                    parsed.aggregate = match args.next_value()?.to_ascii_uppercase().as_slice() {
+                        b"SUM" => Aggregate::Sum,
+                        b"MIN" => Aggregate::Min,
+                        b"MAX" => Aggregate::Max,
+                        _ => return Err(ReplyError::Syntax.into()),
+                    };
+                }
+                _ => return Err(ReplyError::Syntax.into()),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+fn parse_weight(value: &[u8]) -> Result<f64> {
+    parse_float(value).map_err(|_| ReplyError::Generic("weight value is not a float".into()).into())
+}
+
+/// A source of a combination. Plain sets take part with every score at one.
+enum Source<'a> {
+    ZSet(&'a ZSetValue),
+    Set(&'a SetValue),
+}
+
+impl Source<'_> {
+    fn len(&self) -> usize {
+        match self {
+            Self::ZSet(zset) => zset.len(),
+            Self::Set(set) => set.len(),
+        }
+    }
+
+    fn score(&self, member: &[u8]) -> Option<f64> {
+        match self {
+            Self::ZSet(zset) => zset.score(member),
+            Self::Set(set) => set.contains(member).then_some(1.0),
+        }
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Cow<'_, [u8]>, f64)> + '_> {
+        match self {
+            Self::ZSet(zset) => Box::new(
+                zset.iter()
+                    .map(|(member, score)| (Cow::Borrowed(member), score)),
+            ),
+            Self::Set(set) => Box::new(set.iter().map(|member| (member, 1.0))),
+        }
+    }
+}
+
+/// Combine the sorted sets at the parsed keys, a missing key counts as an
+/// empty set.
+///
+/// Like `SINTER`, intersections walk the smallest set and look its members up
+/// in the others. Scores are multiplied by their weight before they're merged.
+pub(super) fn combine(store: &Db, parsed: &CombineArgs, op: ZSetOp) -> Result<ZSetValue> {
+    // every key is type checked before anything is computed
+    let mut sources = Vec::with_capacity(parsed.keys.len());
+
+    for (key, weight) in parsed.keys.iter().zip(&parsed.weights) {
+        let source = match store.get(key) {
+            Some(Entry {
+                value: Value::ZSet(zset),
+                ..
+            }) => Some(Source::ZSet(zset)),
+            Some(Entry {
+                value: Value::Set(set),
+                ..
+            }) => Some(Source::Set(set)),
+            Some(_) => return Err(ReplyError::WrongType.into()),
+            None => None,
+        };
+
+        sources.push((source, *weight));
+    }
+
+    let weighted = |score: f64, weight: f64| {
+        let score = score * weight;
+
+        // zero times infinity
+        if score.is_nan() {
+            0.0
+        } else {
+            score
+        }
+    };
+
+    match op {
+        ZSetOp::Union => {
+            let mut scores: HashMap<Vec<u8>, f64> = HashMap::new();
+
+            for (source, weight) in &sources {
+                let Some(source) = source else {
+                    continue;
+                };
+
+                for (member, score) in source.iter() {
+                    let score = weighted(score, *weight);
+
+                    match scores.get_mut(&member[..]) {
+                        Some(total) => *total = parsed.aggregate.apply(*total, score),
+                        None => {
+                            scores.insert(member.into_owned(), score);
+                        }
+                    }
+                }
+            }
+
+            Ok(scores.into_iter().collect())
+        }
+        ZSetOp::Inter => {
+            let Some(mut sources) = sources
+                .into_iter()
+                .map(|(source, weight)| Some((source?, weight)))
+                .collect::<Option<Vec<_>>>()
+            else {
+                return Ok(ZSetValue::new());
+            };
+
+            // the first weight still goes with the first key
+            let smallest = (0..sources.len())
+                .min_by_key(|i| sources[*i].0.len())
+                .unwrap_or_default();
+            sources.swap(0, smallest);
+
+            let Some(((first, first_weight), rest)) = sources.split_first() else {
+                return Ok(ZSetValue::new());
+            };
+
+            Ok(first
+                .iter()
+                .filter_map(|(member, score)| {
+                    let mut total = weighted(score, *first_weight);
+
+                    for (source, weight) in rest {
+                        let score = weighted(source.score(&member)?, *weight);
+                        total = parsed.aggregate.apply(total, score);
+                    }
+
+                    Some((member.into_owned(), total))
+                })
+                .collect())
+        }
+        ZSetOp::Diff => {
+            let Some(((Some(first), _), rest)) = sources.split_first() else {
+                return Ok(ZSetValue::new());
+            };
+
+            Ok(first
+                .iter()
+                .filter(|(member, _)| {
+                    rest.iter()
+                        .flat_map(|(source, _)| source)
+                        .all(|source| source.score(member).is_none())
+                })
+                .map(|(member, score)| (member.into_owned(), score))
+                .collect())
+        }
+    }
+}
+
+/// Reply with the combination of the sorted sets, ordered by score.
+pub(super) async fn reply_combined(
+    args: &mut Args,
+    session: SessionRef,
+    op: ZSetOp,
+) -> Result<Frame> {
+    let parsed = CombineArgs::parse(args, op, false)?;
+
+    let store = session.state.store.read().await;
+    let zset = combine(&store, &parsed, op)?;
+
+    Ok(scored_reply(
+        zset.iter(),
+        parsed.with_scores,
+        session.get_proto_version() == 3,
+    ))
+}
+
+/// Store the combination of the sorted sets in the destination, replacing
+/// whatever it held. An empty result deletes it.
+pub(super) async fn store_combined(
+    args: &mut Args,
+    session: SessionRef,
+    op: ZSetOp,
+) -> Result<Frame> {
+    let destination = args.next()?.to_vec();
+    let parsed = CombineArgs::parse(args, op, true)?;
+
+    let mut store = session.state.store.write().await;
+    let zset = combine(&store, &parsed, op)?;
+
+    let len = zset.len();
+
+    if zset.is_empty() {
+        store.remove(&destination);
+    } else {
+        store.insert(destination, Entry::new(Value::ZSet(zset)));
+    }
+
+    Ok(Frame::Integer(len as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert(store: &mut Db, key: &str, entries: &[(&str, f64)]) {
+        let zset = entries
+            .iter()
+            .map(|(member, score)| (member.as_bytes().to_vec(), *score))
+            .collect();
+
+        store.insert(key.as_bytes().to_vec(), Entry::new(Value::ZSet(zset)));
+    }
+
+    fn combined(store: &Db, args: &[&str], op: ZSetOp) -> Result<Vec<(String, f64)>> {
+        let mut args = Args::new(
+            "ZUNION",
+            args.iter()
+                .map(|arg| Bytes::from(arg.to_string()))
+                .collect(),
+        );
+        let parsed = CombineArgs::parse(&mut args, op, false)?;
+
+        Ok(combine(store, &parsed, op)?
+            .iter()
+            .map(|(member, score)| (String::from_utf8_lossy(member).into_owned(), score))
+            .collect())
+    }
+
+    #[test]
+    fn test_zset_combine() {
+        let mut store = Db::default();
+        insert(&mut store, "a", &[("x", 1.0), ("y", 2.0), ("z", 3.0)]);
+        insert(&mut store, "b", &[("y", 10.0), ("z", 1.0)]);
+
+        let set: SetValue = [b"z".to_vec(), b"w".to_vec()].into_iter().collect();
+        store.insert(b"s".to_vec(), Entry::new(Value::Set(set)));
+
+        let pairs = |pairs: &[(&str, f64)]| {
+            pairs
+                .iter()
+                .map(|(member, score)| (member.to_string(), *score))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            combined(&store, &["2", "a", "b"], ZSetOp::Union).unwrap(),
+            pairs(&[("x", 1.0), ("z", 4.0), ("y", 12.0)])
+        );
+        assert_eq!(
+            combined(
+                &store,
+                &["3", "a", "b", "s", "WEIGHTS", "2", "1", "5"],
+                ZSetOp::Inter
+            )
+            .unwrap(),
+            pairs(&[("z", 12.0)])
+        );
+        assert_eq!(
+            combined(&store, &["2", "a", "b", "AGGREGATE", "max"], ZSetOp::Inter).unwrap(),
+            pairs(&[("z", 3.0), ("y", 10.0)])
+        );
+        assert_eq!(
+            combined(&store, &["2", "b", "a", "aggregate", "MIN"], ZSetOp::Union).unwrap(),
+            pairs(&[("x", 1.0), ("z", 1.0), ("y", 2.0)])
+        );
+        assert_eq!(
+            combined(&store, &["3", "a", "missing", "s"], ZSetOp::Diff).unwrap(),
+            pairs(&[("x", 1.0), ("y", 2.0)])
+        );
+        assert!(combined(&store, &["2", "a", "missing"], ZSetOp::Inter)
+            .unwrap()
+            .is_empty());
+
+        assert!(combined(&store, &["0", "a"], ZSetOp::Union).is_err());
+        assert!(combined(&store, &["3", "a", "b"], ZSetOp::Union).is_err());
+        assert!(combined(&store, &["2", "a", "b", "WEIGHTS", "1"], ZSetOp::Union).is_err());
+        assert!(combined(&store, &["1", "a", "WEIGHTS", "x"], ZSetOp::Union).is_err());
+        assert!(combined(&store, &["1", "a", "WEIGHTS", "1"], ZSetOp::Diff).is_err());
+        assert!(combined(&store, &["1", "a", "AGGREGATE", "AVG"], ZSetOp::Union).is_err());
+    }
+}
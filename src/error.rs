@@ -1,15 +1,21 @@
+use std::collections::VecDeque;
 use std::error::Error as StdError;
 use std::fmt::{self, Display};
 use std::io::Error as IoError;
 
-use glob::PatternError;
+use bytes::Bytes;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Debug)]
 pub enum Error {
     Io(IoError),
-    Glob(PatternError),
+    /// The client sent something we can't make sense of, the connection gets
+    /// closed after the error is reported.
+    Protocol(String),
+    /// The command failed, the error is sent back as a reply and the
+    /// connection carries on.
+    Reply(ReplyError),
 }
 
 impl From<IoError> for Error {
@@ -18,9 +24,9 @@ impl From<IoError> for Error {
     }
 }
 
-impl From<PatternError> for Error {
-    fn from(e: PatternError) -> Self {
-        Self::Glob(e)
+impl From<ReplyError> for Error {
+    fn from(e: ReplyError) -> Self {
+        Self::Reply(e)
     }
 }
 
@@ -28,7 +34,8 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Io(inner) => fmt::Display::fmt(&inner, f),
-            Self::Glob(inner) => fmt::Display::fmt(&inner, f),
+            Self::Protocol(msg) => write!(f, "Protocol error: {msg}"),
+            Self::Reply(inner) => fmt::Display::fmt(&inner, f),
         }
     }
 }
@@ -37,11 +44,137 @@ impl StdError for Error {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match self {
             Self::Io(inner) => Some(inner),
-            Self::Glob(inner) => Some(inner),
+            Self::Protocol(_) | Self::Reply(_) => None,
         }
     }
 }
 
+/// An error reply, displayed as the error code clients match on followed by
+/// the same message Redis would send.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReplyError {
+    /// `ERR` with a free form message.
+    Generic(String),
+    /// The command was called with the wrong number of arguments.
+    WrongArity(String),
+    /// The command does not exist, the first few arguments are echoed back.
+    UnknownCommand(String, Vec<String>),
+    /// The subcommand (first) of a container command (second) does not exist.
+    UnknownSubcommand(String, String),
+    Syntax,
+    NotInteger,
+    NotFloat,
+    /// The expire time given to the named command is out of range.
+    InvalidExpireTime(String),
+    WrongType,
+    NoAuth,
+    NoScript,
+    NoProto,
+    Busy,
+    /// The key lives in another slot, owned by the node at the address.
+    Moved(u16, String),
+}
+
+impl ReplyError {
+    pub fn wrong_arity(command: &str) -> Self {
+        Self::WrongArity(command.to_lowercase())
+    }
+
+    pub fn unknown_command(command: &[u8], args: &VecDeque<Bytes>) -> Self {
+        let mut echoed = Vec::new();
+        let mut len = 0;
+
+        // only the first handful of arguments make it into the message
+        for arg in args {
+            if len >= MAX_ECHO_LEN {
+                break;
+            }
+
+            let arg = truncated(arg);
+            len += arg.len() + 3;
+            echoed.push(arg);
+        }
+
+        Self::UnknownCommand(truncated(command), echoed)
+    }
+
+    pub fn unknown_subcommand(subcommand: &[u8], command: &str) -> Self {
+        Self::UnknownSubcommand(truncated(subcommand), command.to_uppercase())
+    }
+
+    pub fn invalid_expire_time(command: &str) -> Self {
+        Self::InvalidExpireTime(command.to_lowercase())
+    }
+
+    /// The code a client sees at the start of the error line.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::WrongType => "WRONGTYPE",
+            Self::NoAuth => "NOAUTH",
+            Self::NoScript => "NOSCRIPT",
+            Self::NoProto => "NOPROTO",
+            Self::Busy => "BUSY",
+            Self::Moved(..) => "MOVED",
+            _ => "ERR",
+        }
+    }
+}
+
+impl fmt::Display for ReplyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.code())?;
+
+        match self {
+            Self::Generic(msg) => f.write_str(msg),
+            Self::WrongArity(command) => {
+                write!(f, "wrong number of arguments for '{command}' command")
+            }
+            Self::UnknownCommand(command, args) => {
+                write!(
+                    f,
+                    "unknown command '{command}', with args beginning with: "
+                )?;
+
+                for arg in args {
+                    write!(f, "'{arg}' ")?;
+                }
+
+                Ok(())
+            }
+            Self::UnknownSubcommand(subcommand, command) => write!(
+                f,
+                "unknown subcommand '{subcommand}'. Try {command} HELP."
+            ),
+            Self::Syntax => f.write_str("syntax error"),
+            Self::NotInteger => f.write_str("value is not an integer or out of range"),
+            Self::NotFloat => f.write_str("value is not a valid float"),
+            Self::InvalidExpireTime(command) => {
+                write!(f, "invalid expire time in '{command}' command")
+            }
+            Self::WrongType => {
+                f.write_str("Operation against a key holding the wrong kind of value")
+            }
+            Self::NoAuth => f.write_str("Authentication required."),
+            Self::NoScript => f.write_str("No matching script. Please use EVAL."),
+            Self::NoProto => f.write_str("unsupported protocol version"),
+            Self::Busy => f.write_str(
+                "Redis is busy running a script. You can only call SCRIPT KILL or SHUTDOWN NOSCRIPT.",
+            ),
+            Self::Moved(slot, addr) => write!(f, "{slot} {addr}"),
+        }
+    }
+}
+
+impl StdError for ReplyError {}
+
+const MAX_ECHO_LEN: usize = 128;
+
+/// Echoed arguments are cut short like Redis does, so a huge or binary
+/// argument can't blow up the error line.
+fn truncated(arg: &[u8]) -> String {
+    String::from_utf8_lossy(&arg[..arg.len().min(MAX_ECHO_LEN)]).replace(['\r', '\n'], " ")
+}
+
 pub trait Context<T, E> {
     fn context<C>(self, ctx: C) -> Result<T>
     where
@@ -65,7 +198,33 @@ where
                 ctx
             };
 
-            Error::Io(IoError::new(std::io::ErrorKind::Other, msg))
+            Error::Io(IoError::other(msg))
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reply_error_messages() {
+        assert_eq!(
+            ReplyError::wrong_arity("GET").to_string(),
+            "ERR wrong number of arguments for 'get' command"
+        );
+        assert_eq!(
+            ReplyError::WrongType.to_string(),
+            "WRONGTYPE Operation against a key holding the wrong kind of value"
+        );
+        assert_eq!(
+            ReplyError::Moved(3999, "127.0.0.1:6381".into()).to_string(),
+            "MOVED 3999 127.0.0.1:6381"
+        );
+        assert_eq!(
+            ReplyError::unknown_command(b"foo", &VecDeque::from([Bytes::from("a\r\nb")]))
+                .to_string(),
+            "ERR unknown command 'foo', with args beginning with: 'a  b' "
+        );
+    }
+}
@@ -1,39 +1,88 @@
-use tokio::io::{AsyncWriteExt, BufReader};
+use bytes::BytesMut;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 use crate::prelude::*;
+use crate::protocol::decoder::Decoder;
+
+/// Initial size of the read and write buffers of a connection.
+const BUFFER_SIZE: usize = 16 * 1024;
+
+/// Replies are written out early once this many bytes are pending, so a huge
+/// pipeline doesn't buffer all of its replies in memory.
+const OUTPUT_FLUSH_THRESHOLD: usize = 64 * 1024;
 
 pub async fn handle_client(stream: &mut tokio::net::TcpStream, session: SessionRef) -> Result<()> {
-    let (mut read, mut writer) = stream.split();
+    let (mut reader, mut writer) = stream.split();
 
-    let mut reader = BufReader::new(&mut read);
+    let mut decoder = Decoder::new(session.state.limits);
+    let mut input = BytesMut::with_capacity(BUFFER_SIZE);
+    let mut output = Vec::with_capacity(BUFFER_SIZE);
 
     loop {
-        let request = Value::from_resp(&mut reader).await?;
-
-        #[cfg(debug_assertions)]
-        log::debug!("Received request: {request:?}");
-
-        match request {
-            Some(Value::Multi(mut args)) => {
-                session
-                    .state
-                    .commands
-                    .handle_command(&mut writer, &mut args, session.clone())
-                    .await?
-            }
+        // run every request that is already buffered, then reply to the whole batch at once
+        loop {
+            let args = match decoder.decode(&mut input) {
+                Ok(Some(args)) => args,
+                Ok(None) => break,
+
+                // tell the client what went wrong before hanging up on it
+                Err(Error::Protocol(msg)) => {
+                    session.versioned_response(&value_error!("Protocol error: {msg}"), &mut output);
+                    writer.write_all(&output).await?;
+
+                    return Ok(());
+                }
+
+                Err(e) => return Err(e),
+            };
+
+            #[cfg(debug_assertions)]
+            log::debug!("Received request: {args:?}");
 
-            // If the request is None, the client has disconnected.
-            None => {
-                return Ok(());
+            let blocking = match args.front() {
+                Some(name) => session.state.commands.is_blocking(name).await,
+                None => false,
+            };
+
+            // replies to the requests before it mustn't wait on a client that may block for long
+            if blocking && !output.is_empty() {
+                writer.write_all(&output).await?;
+                output.clear();
             }
 
-            _ => {
-                value_error!("Invalid request")
-                    .to_resp2(&mut writer)
-                    .await?;
+            let command = session.state.commands.handle_command(args, session.clone());
+
+            tokio::pin!(command);
+
+            // keep an eye on the connection while a blocking command waits, hanging up
+            // drops the command which takes the client out of line
+            let response = loop {
+                tokio::select! {
+                    response = &mut command => break response?,
+                    read = reader.read_buf(&mut input), if blocking => {
+                        if read? == 0 {
+                            return Ok(());
+                        }
+                    }
+                }
+            };
+
+            session.versioned_response(&response, &mut output);
+
+            if output.len() >= OUTPUT_FLUSH_THRESHOLD {
+                writer.write_all(&output).await?;
+                output.clear();
             }
-        };
+        }
+
+        if !output.is_empty() {
+            writer.write_all(&output).await?;
+            output.clear();
+        }
 
-        writer.flush().await?;
+        // If nothing was read, the client has disconnected.
+        if reader.read_buf(&mut input).await? == 0 {
+            return Ok(());
+        }
     }
 }
@@ -2,6 +2,7 @@ mod commands;
 pub mod error;
 mod handler;
 mod prelude;
+pub mod protocol;
 mod session;
 pub mod storage;
 mod utils;
@@ -14,6 +15,7 @@ use tokio::net::TcpListener;
 
 use self::prelude::*;
 use crate::handler::handle_client;
+use crate::protocol::Limits;
 use crate::session::state::State;
 
 #[derive(Parser)]
@@ -26,6 +28,29 @@ pub struct CLI {
 
     #[clap(short, long, default_value = "1")]
     pub threads: usize,
+
+    /// Largest accepted bulk string in bytes
+    #[clap(long, default_value = "536870912")]
+    pub proto_max_bulk_len: usize,
+
+    /// Largest accepted number of elements in a request
+    #[clap(long, default_value = "1048576")]
+    pub proto_max_multibulk_len: usize,
+
+    /// Longest accepted inline command in bytes
+    #[clap(long, default_value = "65536")]
+    pub proto_inline_max_size: usize,
+}
+
+impl CLI {
+    pub fn limits(&self) -> Limits {
+        Limits {
+            max_bulk_len: self.proto_max_bulk_len,
+            max_multibulk_len: self.proto_max_multibulk_len,
+            max_inline_len: self.proto_inline_max_size,
+            ..Limits::default()
+        }
+    }
 }
 
 pub async fn run(cli: CLI) -> Result<()> {
@@ -33,7 +58,7 @@ pub async fn run(cli: CLI) -> Result<()> {
 
     utils::bootlog(&cli);
 
-    let state = State::new().await;
+    let state = State::new(cli.limits()).await;
 
     let listener = TcpListener::bind(SocketAddr::new(cli.address, cli.port)).await?;
 
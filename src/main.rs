@@ -1,4 +1,6 @@
 #![deny(clippy::pedantic, clippy::nursery, clippy::cargo)]
+// duplicate transitive versions (windows-sys) are out of our hands
+#![allow(clippy::multiple_crate_versions)]
 
 use clap::Parser;
 use crabdis::CLI;
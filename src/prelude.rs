@@ -1,12 +1,14 @@
 pub(crate) use std::collections::{HashMap, VecDeque};
 
 pub(crate) use async_trait::async_trait;
+pub(crate) use bytes::Bytes;
 pub(crate) use tokio::io::AsyncWriteExt;
-pub(crate) use tokio::net::tcp::WriteHalf;
 
-pub(crate) use super::commands::CommandTrait;
-pub(crate) use super::error::{Context as ErrorContext, Error, Result};
+pub(crate) use super::commands::args::Args;
+pub(crate) use super::commands::{CommandFlag, CommandTrait};
+pub(crate) use super::error::{Error, ReplyError, Result};
+pub(crate) use super::protocol::frame::{value_error, Frame};
 pub(crate) use super::session::state::StateRef;
 pub(crate) use super::session::{Session, SessionRef};
-pub(crate) use super::storage::value::{value_error, Value};
+pub(crate) use super::storage::value::{Entry, Value};
 pub(crate) use super::storage::Store;
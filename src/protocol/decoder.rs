@@ -0,0 +1,276 @@
+use std::collections::VecDeque;
+
+use bytes::{Buf, Bytes, BytesMut};
+
+use super::{inline, Limits};
+use crate::prelude::*;
+
+/// Incremental request decoder.
+///
+/// Requests are decoded straight out of the connection's read buffer, bulk
+/// arguments are split off as [`Bytes`] that share the buffer's allocation so
+/// no payload is copied. Progress through a multibulk request is kept between
+/// calls, a request that arrives in many small reads is only scanned once.
+pub struct Decoder {
+    limits: Limits,
+    /// Arguments of the multibulk request being decoded and how many are left.
+    pending: Option<(usize, VecDeque<Bytes>)>,
+    /// Length of the bulk string being waited for, its header is already consumed.
+    bulk_len: Option<usize>,
+}
+
+impl Decoder {
+    pub fn new(limits: Limits) -> Self {
+        Self {
+            limits,
+            pending: None,
+            bulk_len: None,
+        }
+    }
+
+    /// Decode the next complete request in `buf`, consuming it.
+    ///
+    /// Returns `None` once more data is needed. Empty inline lines and empty
+    /// multibulk requests are skipped, just like Redis does.
+    pub fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<VecDeque<Bytes>>> {
+        loop {
+            let Some((remaining, args)) = &mut self.pending else {
+                match buf.first() {
+                    None => return Ok(None),
+                    Some(b'*') => {}
+                    Some(_) => match self.decode_inline(buf)? {
+                        Some(args) if args.is_empty() => continue,
+                        args => return Ok(args),
+                    },
+                }
+
+                let Some(line) = take_line(buf, self.limits.max_inline_len, "mbulk count string")?
+                else {
+                    return Ok(None);
+                };
+
+                let len = parse_len(&line[1..], self.limits.max_multibulk_len, "multibulk")?;
+
+                if len > 0 {
+                    // never trust the announced length for the allocation
+                    let args = VecDeque::with_capacity(len.min(PREALLOC_ELEMENTS));
+                    self.pending = Some((len, args));
+                }
+
+                continue;
+            };
+
+            while *remaining > 0 {
+                let len = match self.bulk_len {
+                    Some(len) => len,
+                    None => {
+                        let Some(line) =
+                            take_line(buf, self.limits.max_inline_len, "bulk count string")?
+                        else {
+                            return Ok(None);
+                        };
+
+                        if line.first() != Some(&b'$') {
+                            let got = line.first().map(|c| c.escape_ascii().to_string());
+
+                            return Err(Error::Protocol(format!(
+                                "expected '$', got '{}'",
+                                got.unwrap_or_default()
+                            )));
+                        }
+
+                        let len = parse_len(&line[1..], self.limits.max_bulk_len, "bulk")?;
+                        self.bulk_len = Some(len);
+
+                        len
+                    }
+                };
+
+                // +2 for `\r\n`
+                if buf.len() < len + 2 {
+                    // make room for the argument in big steps, but never trust the announced
+                    // length for the allocation, the buffer grows as the bytes arrive
+                    buf.reserve((len + 2 - buf.len()).min(PREALLOC_BYTES));
+
+                    return Ok(None);
+                }
+
+                if &buf[len..len + 2] != b"\r\n" {
+                    return Err(Error::Protocol(
+                        "bulk string is not terminated by CRLF".to_string(),
+                    ));
+                }
+
+                args.push_back(buf.split_to(len).freeze());
+                buf.advance(2);
+
+                self.bulk_len = None;
+                *remaining -= 1;
+            }
+
+            return Ok(self.pending.take().map(|(_, args)| args));
+        }
+    }
+
+    fn decode_inline(&self, buf: &mut BytesMut) -> Result<Option<VecDeque<Bytes>>> {
+        let Some(line) = take_line(buf, self.limits.max_inline_len, "inline request")? else {
+            return Ok(None);
+        };
+
+        let args = inline::split_args(&line)
+            .ok_or_else(|| Error::Protocol("unbalanced quotes in request".to_string()))?;
+
+        Ok(Some(args.into_iter().map(Bytes::from).collect()))
+    }
+}
+
+/// Upper bound of arguments allocated up front, the rest grows as the
+/// arguments actually arrive.
+const PREALLOC_ELEMENTS: usize = 1024;
+
+/// Same as [`PREALLOC_ELEMENTS`] but in bytes, for bulk arguments.
+const PREALLOC_BYTES: usize = 64 * 1024;
+
+/// Split off a line terminated by `\n` (optionally preceded by `\r`), the
+/// terminator is dropped.
+fn take_line(buf: &mut BytesMut, max: usize, what: &str) -> Result<Option<BytesMut>> {
+    let Some(pos) = buf.iter().position(|c| *c == b'\n') else {
+        if buf.len() > max {
+            return Err(Error::Protocol(format!("too big {what}")));
+        }
+
+        return Ok(None);
+    };
+
+    if pos > max {
+        return Err(Error::Protocol(format!("too big {what}")));
+    }
+
+    let mut line = buf.split_to(pos + 1);
+    line.truncate(pos);
+
+    if line.last() == Some(&b'\r') {
+        line.truncate(pos - 1);
+    }
+
+    Ok(Some(line))
+}
+
+fn parse_len(value: &[u8], max: usize, what: &str) -> Result<usize> {
+    std::str::from_utf8(value)
+        .ok()
+        .and_then(|value| value.parse::<usize>().ok())
+        .filter(|len| *len <= max)
+        .ok_or_else(|| Error::Protocol(format!("invalid {what} length")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_all(decoder: &mut Decoder, buf: &mut BytesMut) -> Vec<VecDeque<Bytes>> {
+        let mut requests = Vec::new();
+
+        while let Some(args) = decoder.decode(buf).unwrap() {
+            requests.push(args);
+        }
+
+        requests
+    }
+
+    fn request(args: &[&'static str]) -> VecDeque<Bytes> {
+        args.iter()
+            .map(|arg| Bytes::from_static(arg.as_bytes()))
+            .collect()
+    }
+
+    #[test]
+    fn test_decode_pipeline() {
+        let mut decoder = Decoder::new(Limits::default());
+        let mut buf = BytesMut::from(
+            &b"*1\r\n$4\r\nPING\r\nPING\r\n\r\n*0\r\n*3\r\n$3\r\nSET\r\n$1\r\na\r\n$1\r\nb\r\n*2\r\n$3\r\nGET"[..],
+        );
+
+        assert_eq!(
+            decode_all(&mut decoder, &mut buf),
+            vec![
+                request(&["PING"]),
+                request(&["PING"]),
+                request(&["SET", "a", "b"])
+            ]
+        );
+
+        // the half received request stays buffered until the rest arrives
+        buf.extend_from_slice(b"\r\n$1\r\na\r\n");
+
+        assert_eq!(
+            decode_all(&mut decoder, &mut buf),
+            vec![request(&["GET", "a"])]
+        );
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_decode_byte_by_byte() {
+        let request = b"*2\r\n$3\r\nGET\r\n$10\r\nkey\x00\xFFvalue\r\n";
+
+        let mut decoder = Decoder::new(Limits::default());
+        let mut buf = BytesMut::new();
+        let mut decoded = None;
+
+        for byte in request {
+            assert!(decoded.is_none());
+
+            buf.extend_from_slice(&[*byte]);
+            decoded = decoder.decode(&mut buf).unwrap();
+        }
+
+        assert_eq!(
+            decoded,
+            Some(VecDeque::from([
+                Bytes::from_static(b"GET"),
+                Bytes::from_static(b"key\x00\xFFvalue")
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_decode_announced_length_not_preallocated() {
+        let mut decoder = Decoder::new(Limits::default());
+        let mut buf = BytesMut::from(&b"*1\r\n$536870912\r\nabc"[..]);
+
+        assert_eq!(decoder.decode(&mut buf).unwrap(), None);
+        assert!(buf.capacity() <= 2 * PREALLOC_BYTES);
+    }
+
+    #[test]
+    fn test_decode_limits() {
+        let limits = Limits {
+            max_bulk_len: 8,
+            max_multibulk_len: 4,
+            max_depth: 2,
+            max_inline_len: 32,
+        };
+
+        for (request, expected) in [
+            (&b"*1\r\n$9\r\n"[..], "invalid bulk length"),
+            (b"*5\r\n", "invalid multibulk length"),
+            (b"*-1\r\n", "invalid multibulk length"),
+            (b"*1\r\n:1\r\n", "expected '$', got ':'"),
+            (
+                b"*1\r\n$3\r\nGETxx",
+                "bulk string is not terminated by CRLF",
+            ),
+            (&[b'A'; 64], "too big inline request"),
+            (b"SET key \"value\r\n", "unbalanced quotes in request"),
+        ] {
+            let mut decoder = Decoder::new(limits);
+            let mut buf = BytesMut::from(request);
+
+            match decoder.decode(&mut buf) {
+                Err(Error::Protocol(msg)) => assert_eq!(msg, expected),
+                other => panic!("expected a protocol error, got {other:?}"),
+            }
+        }
+    }
+}
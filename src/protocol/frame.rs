@@ -0,0 +1,789 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::io::Write;
+use std::pin::Pin;
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+
+use super::Limits;
+use crate::prelude::*;
+use crate::utils::number::{format_double, parse_double};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Frame {
+    Ok,   // only for response
+    Pong, // only for response
+    Nil,
+    /// The RESP2 null array, `*-1`, which some commands reply instead of a
+    /// null bulk string. RESP3 has a single null.
+    NullArray,
+    Simple(String),
+    Error(String),
+    Integer(i64),
+    Bulk(Vec<u8>),
+    Array(VecDeque<Frame>),
+
+    // RESP3 only, downgraded when talking to RESP2 clients
+    Map(Vec<(Frame, Frame)>),
+    Set(Vec<Frame>),
+    Double(f64),
+    Boolean(bool),
+    BigNumber(String),
+    /// Verbatim string, the format is a three letter hint such as `txt` or `mkd`.
+    Verbatim(String, Vec<u8>),
+    /// Out of band data, e.g. pub/sub messages.
+    Push(VecDeque<Frame>),
+    /// Auxiliary data attached to the frame that follows it.
+    Attribute(Vec<(Frame, Frame)>, Box<Frame>),
+}
+
+/// Build an `ERR` reply from a format string.
+macro_rules! value_error {
+    ($($arg:tt)*) => {
+        Frame::from($crate::error::ReplyError::Generic(format!($($arg)*)))
+    };
+}
+
+pub(crate) use value_error;
+
+impl Frame {
+    pub fn wrong_type() -> Self {
+        Self::from(ReplyError::WrongType)
+    }
+
+    /// Encode the frame for a RESP2 client, RESP3 only types are downgraded to
+    /// their closest RESP2 equivalent.
+    pub fn encode_resp2(&self, out: &mut Vec<u8>) {
+        match self {
+            Self::Ok => out.extend_from_slice(b"+OK\r\n"),
+            Self::Pong => out.extend_from_slice(b"+PONG\r\n"),
+
+            Self::Nil => out.extend_from_slice(b"$-1\r\n"),
+            Self::NullArray => out.extend_from_slice(b"*-1\r\n"),
+            Self::Simple(s) => write_line(out, b'+', s.as_bytes()),
+            Self::Error(e) => {
+                // RESP2 has no way of carrying newlines inside an error
+                write_line(out, b'-', e.replace(['\r', '\n'], " ").as_bytes());
+            }
+            Self::Integer(i) => write_header(out, b':', i),
+            Self::Bulk(s) => write_blob(out, b'$', s),
+            Self::Array(v) | Self::Push(v) => {
+                write_header(out, b'*', v.len());
+
+                for value in v {
+                    value.encode_resp2(out);
+                }
+            }
+            Self::Map(map) => {
+                write_header(out, b'*', map.len() * 2);
+
+                for (k, v) in map {
+                    k.encode_resp2(out);
+                    v.encode_resp2(out);
+                }
+            }
+            Self::Set(set) => {
+                write_header(out, b'*', set.len());
+
+                for v in set {
+                    v.encode_resp2(out);
+                }
+            }
+            Self::Double(d) => write_blob(out, b'$', format_double(*d).as_bytes()),
+            Self::Boolean(b) => write_header(out, b':', i64::from(*b)),
+            Self::BigNumber(n) => write_blob(out, b'$', n.as_bytes()),
+            Self::Verbatim(_, data) => write_blob(out, b'$', data),
+            // attributes are simply dropped, RESP2 clients would not know what to do with them
+            Self::Attribute(_, frame) => frame.encode_resp2(out),
+        }
+    }
+
+    /// Encode the frame for a RESP3 client.
+    pub fn encode_resp3(&self, out: &mut Vec<u8>) {
+        match self {
+            Self::Nil | Self::NullArray => out.extend_from_slice(b"_\r\n"),
+            Self::Error(e) if e.contains(['\r', '\n']) => write_blob(out, b'!', e.as_bytes()),
+            Self::Array(v) | Self::Push(v) => {
+                let prefix = if matches!(self, Self::Push(_)) {
+                    b'>'
+                } else {
+                    b'*'
+                };
+
+                write_header(out, prefix, v.len());
+
+                for value in v {
+                    value.encode_resp3(out);
+                }
+            }
+            Self::Map(map) | Self::Attribute(map, _) => {
+                let prefix = if matches!(self, Self::Attribute(..)) {
+                    b'|'
+                } else {
+                    b'%'
+                };
+
+                write_header(out, prefix, map.len());
+
+                for (k, v) in map {
+                    k.encode_resp3(out);
+                    v.encode_resp3(out);
+                }
+
+                if let Self::Attribute(_, frame) = self {
+                    frame.encode_resp3(out);
+                }
+            }
+            Self::Set(set) => {
+                write_header(out, b'~', set.len());
+
+                for v in set {
+                    v.encode_resp3(out);
+                }
+            }
+            Self::Double(d) => write_line(out, b',', format_double(*d).as_bytes()),
+            Self::Boolean(b) => write_line(out, b'#', if *b { b"t" } else { b"f" }),
+            Self::BigNumber(n) => write_line(out, b'(', n.as_bytes()),
+            Self::Verbatim(format, data) => {
+                // the length covers the format and the colon as well
+                write_header(out, b'=', data.len() + 4);
+
+                let mut format = format.as_bytes().to_vec();
+                format.resize(3, b' ');
+
+                out.extend_from_slice(&format[..3]);
+                out.push(b':');
+                out.extend_from_slice(data);
+                out.extend_from_slice(b"\r\n");
+            }
+
+            // the remaining types are encoded the same way in both versions
+            Self::Ok
+            | Self::Pong
+            | Self::Simple(_)
+            | Self::Error(_)
+            | Self::Integer(_)
+            | Self::Bulk(_) => self.encode_resp2(out),
+        }
+    }
+
+    pub async fn to_resp2<T>(&self, writer: &mut T) -> Result<()>
+    where
+        T: AsyncWriteExt + Unpin + Send,
+    {
+        let mut out = Vec::new();
+        self.encode_resp2(&mut out);
+
+        Ok(writer.write_all(&out).await?)
+    }
+
+    pub async fn to_resp3<T>(&self, writer: &mut T) -> Result<()>
+    where
+        T: AsyncWriteExt + Unpin + Send,
+    {
+        let mut out = Vec::new();
+        self.encode_resp3(&mut out);
+
+        Ok(writer.write_all(&out).await?)
+    }
+
+    /// Read a single frame, bounded by the default [`Limits`].
+    pub async fn from_resp<T>(reader: &mut BufReader<&mut T>) -> Result<Option<Self>>
+    where
+        T: AsyncReadExt + Unpin + Send,
+    {
+        Self::from_resp_limited(reader, &Limits::default(), 0).await
+    }
+
+    fn from_resp_limited<'a, T>(
+        reader: &'a mut BufReader<&mut T>,
+        limits: &'a Limits,
+        depth: usize,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Self>>> + Send + 'a>>
+    where
+        T: AsyncReadExt + Unpin + Send,
+    {
+        Box::pin(async move {
+            if depth > limits.max_depth {
+                return Err(protocol_error("exceeded maximum nesting depth"));
+            }
+
+            let Some(line) = read_line(reader, limits.max_inline_len, "line").await? else {
+                return Ok(None);
+            };
+
+            let Some((&prefix, rest)) = line.split_first() else {
+                return Err(protocol_error("empty line"));
+            };
+
+            match prefix {
+                b'_' => Ok(Some(Self::Nil)),
+
+                b'$' | b'!' | b'=' => {
+                    let len = parse_len(rest, "bulk")?;
+
+                    if len == -1 && prefix == b'$' {
+                        return Ok(Some(Self::Nil));
+                    }
+
+                    let len = checked_len(len, limits.max_bulk_len, "bulk")?;
+
+                    let Some(mut value) = read_bulk(reader, len).await? else {
+                        return Ok(None);
+                    };
+
+                    match prefix {
+                        b'!' => Ok(Some(Self::Error(
+                            String::from_utf8_lossy(&value).into_owned(),
+                        ))),
+
+                        b'=' => {
+                            if value.len() < 4 || value[3] != b':' {
+                                return Err(protocol_error("invalid verbatim string format"));
+                            }
+
+                            let data = value.split_off(4);
+                            let format = String::from_utf8_lossy(&value[..3]).into_owned();
+
+                            Ok(Some(Self::Verbatim(format, data)))
+                        }
+
+                        _ => Ok(Some(Self::Bulk(value))),
+                    }
+                }
+
+                b':' => {
+                    let value =
+                        parse_number(rest).ok_or_else(|| protocol_error("invalid integer"))?;
+
+                    Ok(Some(Self::Integer(value)))
+                }
+
+                b',' => {
+                    let value = std::str::from_utf8(rest)
+                        .ok()
+                        .and_then(parse_double)
+                        .ok_or_else(|| protocol_error("invalid double"))?;
+
+                    Ok(Some(Self::Double(value)))
+                }
+
+                b'#' => match rest {
+                    b"t" => Ok(Some(Self::Boolean(true))),
+                    b"f" => Ok(Some(Self::Boolean(false))),
+                    _ => Err(protocol_error("invalid boolean")),
+                },
+
+                b'(' => {
+                    let digits = rest.strip_prefix(b"-").unwrap_or(rest);
+                    let digits = digits.strip_prefix(b"+").unwrap_or(digits);
+
+                    if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+                        return Err(protocol_error("invalid big number"));
+                    }
+
+                    // only ascii digits and a sign made it through the check above
+                    let value = String::from_utf8_lossy(rest).into_owned();
+
+                    Ok(Some(Self::BigNumber(value)))
+                }
+
+                b'*' | b'>' | b'~' => {
+                    let len = parse_len(rest, "multibulk")?;
+
+                    if len == -1 && prefix == b'*' {
+                        return Ok(Some(Self::NullArray));
+                    }
+
+                    let len = checked_len(len, limits.max_multibulk_len, "multibulk")?;
+
+                    // never trust the announced length for the allocation
+                    let mut values = VecDeque::with_capacity(len.min(PREALLOC_ELEMENTS));
+
+                    for _ in 0..len {
+                        match Self::from_resp_limited(reader, limits, depth + 1).await? {
+                            Some(value) => values.push_back(value),
+                            None => return Ok(None),
+                        }
+                    }
+
+                    match prefix {
+                        b'>' => Ok(Some(Self::Push(values))),
+                        b'~' => Ok(Some(Self::Set(values.into()))),
+                        _ => Ok(Some(Self::Array(values))),
+                    }
+                }
+
+                b'%' | b'|' => {
+                    let len = parse_len(rest, "multibulk")?;
+                    let len = checked_len(len, limits.max_multibulk_len, "multibulk")?;
+
+                    let mut map = Vec::with_capacity(len.min(PREALLOC_ELEMENTS));
+
+                    for _ in 0..len {
+                        let key = Self::from_resp_limited(reader, limits, depth + 1).await?;
+                        let value = Self::from_resp_limited(reader, limits, depth + 1).await?;
+
+                        match (key, value) {
+                            (Some(key), Some(value)) => {
+                                map.push((key, value));
+                            }
+                            _ => return Ok(None),
+                        }
+                    }
+
+                    if prefix == b'%' {
+                        return Ok(Some(Self::Map(map)));
+                    }
+
+                    // attributes describe the frame right after them, a chain of them nests too
+                    match Self::from_resp_limited(reader, limits, depth + 1).await? {
+                        Some(frame) => Ok(Some(Self::Attribute(map, Box::new(frame)))),
+                        None => Ok(None),
+                    }
+                }
+
+                b'+' => match rest {
+                    b"OK" => Ok(Some(Self::Ok)),
+                    b"PONG" => Ok(Some(Self::Pong)),
+                    _ => Ok(Some(Self::Simple(
+                        String::from_utf8_lossy(rest).into_owned(),
+                    ))),
+                },
+
+                b'-' => Ok(Some(Self::Error(
+                    String::from_utf8_lossy(rest).into_owned(),
+                ))),
+
+                _ => Err(protocol_error(&format!(
+                    "invalid frame type '{}'",
+                    prefix.escape_ascii()
+                ))),
+            }
+        })
+    }
+}
+
+fn write_line(out: &mut Vec<u8>, prefix: u8, line: &[u8]) {
+    out.push(prefix);
+    out.extend_from_slice(line);
+    out.extend_from_slice(b"\r\n");
+}
+
+fn write_header(out: &mut Vec<u8>, prefix: u8, len: impl std::fmt::Display) {
+    out.push(prefix);
+    // writing into a `Vec` can't fail
+    write!(out, "{len}\r\n").ok();
+}
+
+fn write_blob(out: &mut Vec<u8>, prefix: u8, data: &[u8]) {
+    write_header(out, prefix, data.len());
+    out.extend_from_slice(data);
+    out.extend_from_slice(b"\r\n");
+}
+
+/// Upper bound of elements allocated up front for an aggregate, the rest grows
+/// as the elements actually arrive.
+const PREALLOC_ELEMENTS: usize = 1024;
+
+/// Same as [`PREALLOC_ELEMENTS`] but in bytes, for bulk strings.
+const PREALLOC_BYTES: usize = 64 * 1024;
+
+fn protocol_error(msg: &str) -> Error {
+    Error::Protocol(msg.to_string())
+}
+
+fn parse_number(value: &[u8]) -> Option<i64> {
+    std::str::from_utf8(value).ok()?.parse().ok()
+}
+
+fn parse_len(value: &[u8], what: &str) -> Result<i64> {
+    parse_number(value).ok_or_else(|| protocol_error(&format!("invalid {what} length")))
+}
+
+fn checked_len(len: i64, max: usize, what: &str) -> Result<usize> {
+    match usize::try_from(len) {
+        Ok(len) if len <= max => Ok(len),
+        _ => Err(protocol_error(&format!("invalid {what} length"))),
+    }
+}
+
+/// Read a line terminated by `\n` (optionally preceded by `\r`) without
+/// buffering more than `max` bytes of it.
+async fn read_line<T>(
+    reader: &mut BufReader<&mut T>,
+    max: usize,
+    what: &str,
+) -> Result<Option<Vec<u8>>>
+where
+    T: AsyncReadExt + Unpin + Send,
+{
+    let mut line = Vec::new();
+
+    loop {
+        let available = reader.fill_buf().await?;
+
+        if available.is_empty() {
+            // disconnected, possibly half way through the line
+            return Ok(None);
+        }
+
+        let (found, used) = match available.iter().position(|c| *c == b'\n') {
+            Some(pos) => (true, pos + 1),
+            None => (false, available.len()),
+        };
+
+        if line.len() + used > max {
+            return Err(protocol_error(&format!("too big {what}")));
+        }
+
+        line.extend_from_slice(&available[..used]);
+        reader.consume(used);
+
+        if found {
+            line.pop();
+
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+
+            return Ok(Some(line));
+        }
+    }
+}
+
+/// Read `len` bytes of payload followed by the `\r\n` terminator.
+async fn read_bulk<T>(reader: &mut BufReader<&mut T>, len: usize) -> Result<Option<Vec<u8>>>
+where
+    T: AsyncReadExt + Unpin + Send,
+{
+    let mut value = Vec::with_capacity(len.min(PREALLOC_BYTES));
+
+    // `usize` always fits into `u64` on the platforms we support
+    if (&mut *reader)
+        .take(len as u64)
+        .read_to_end(&mut value)
+        .await?
+        < len
+    {
+        return Ok(None);
+    }
+
+    let mut terminator = [0; 2];
+
+    match reader.read_exact(&mut terminator).await {
+        Ok(_) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    }
+
+    if terminator != *b"\r\n" {
+        return Err(protocol_error("bulk string is not terminated by CRLF"));
+    }
+
+    Ok(Some(value))
+}
+
+impl From<Option<Frame>> for Frame {
+    fn from(value: Option<Frame>) -> Self {
+        value.unwrap_or(Frame::Nil)
+    }
+}
+
+impl From<String> for Frame {
+    fn from(value: String) -> Self {
+        Frame::Bulk(value.into_bytes())
+    }
+}
+
+impl From<Vec<u8>> for Frame {
+    fn from(value: Vec<u8>) -> Self {
+        Frame::Bulk(value)
+    }
+}
+
+impl From<&[u8]> for Frame {
+    fn from(value: &[u8]) -> Self {
+        Frame::Bulk(value.to_vec())
+    }
+}
+
+impl From<&str> for Frame {
+    fn from(value: &str) -> Self {
+        Frame::Bulk(value.as_bytes().to_vec())
+    }
+}
+
+impl From<VecDeque<Frame>> for Frame {
+    fn from(value: VecDeque<Frame>) -> Self {
+        Frame::Array(value)
+    }
+}
+
+impl From<ReplyError> for Frame {
+    fn from(value: ReplyError) -> Self {
+        Frame::Error(value.to_string())
+    }
+}
+
+impl From<i64> for Frame {
+    fn from(value: i64) -> Self {
+        Frame::Integer(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::{TcpListener, TcpStream};
+
+    use super::*;
+
+    async fn create_tcp_stream(data: &[u8]) -> TcpStream {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let mut writer = TcpStream::connect(addr).await.unwrap();
+        writer.write_all(data).await.unwrap();
+
+        let (stream, _) = listener.accept().await.unwrap();
+
+        stream
+    }
+
+    #[tokio::test]
+    async fn test_frame_to_resp() {
+        let frame = Frame::from("Hello, World!");
+        let mut buff = Vec::new();
+        frame.to_resp2(&mut buff).await.unwrap();
+
+        assert_eq!(buff, b"$13\r\nHello, World!\r\n");
+
+        let frame = Frame::Integer(42);
+        let mut buff = Vec::new();
+        frame.to_resp2(&mut buff).await.unwrap();
+
+        assert_eq!(buff, b":42\r\n");
+
+        let frame = Frame::Nil;
+        let mut buff = Vec::new();
+        frame.to_resp2(&mut buff).await.unwrap();
+
+        assert_eq!(buff, b"$-1\r\n");
+
+        let frame = Frame::Array(VecDeque::from([
+            Frame::from("Hello, World!"),
+            Frame::Integer(42),
+            Frame::Nil,
+        ]));
+        let mut buff = Vec::new();
+        frame.to_resp2(&mut buff).await.unwrap();
+
+        println!("{:?}", std::str::from_utf8(&buff).unwrap());
+
+        assert_eq!(buff, b"*3\r\n$13\r\nHello, World!\r\n:42\r\n$-1\r\n");
+
+        let frame = Frame::Array(VecDeque::from([Frame::from("key"), Frame::from("value")]));
+
+        let mut buff = Vec::new();
+        frame.to_resp2(&mut buff).await.unwrap();
+
+        assert_eq!(buff, b"*2\r\n$3\r\nkey\r\n$5\r\nvalue\r\n");
+    }
+
+    #[tokio::test]
+    async fn test_frame_from_resp() {
+        let mut stream = create_tcp_stream(b"$13\r\nHello, World!\r\n").await;
+        let (mut read, _) = stream.split();
+        let mut reader = BufReader::new(&mut read);
+
+        let frame = Frame::from_resp(&mut reader).await.unwrap();
+        assert_eq!(frame, Some(Frame::from("Hello, World!")));
+
+        let mut stream = create_tcp_stream(b":42\r\n").await;
+        let (mut read, _) = stream.split();
+        let mut reader = BufReader::new(&mut read);
+
+        let frame = Frame::from_resp(&mut reader).await.unwrap();
+        assert_eq!(frame, Some(Frame::Integer(42)));
+
+        let mut stream = create_tcp_stream(b"$-1\r\n").await;
+        let (mut read, _) = stream.split();
+        let mut reader = BufReader::new(&mut read);
+
+        let frame = Frame::from_resp(&mut reader).await.unwrap();
+        assert_eq!(frame, Some(Frame::Nil));
+
+        let mut stream = create_tcp_stream(b"*3\r\n$13\r\nHello, World!\r\n:42\r\n$-1\r\n").await;
+        let (mut read, _) = stream.split();
+        let mut reader = BufReader::new(&mut read);
+
+        let frame = Frame::from_resp(&mut reader).await.unwrap();
+        assert_eq!(
+            frame,
+            Some(Frame::Array(VecDeque::from([
+                Frame::from("Hello, World!"),
+                Frame::Integer(42),
+                Frame::Nil
+            ])))
+        );
+
+        let mut stream = create_tcp_stream(b"*2\r\n$3\r\nkey\r\n$5\r\nvalue\r\n").await;
+        let (mut read, _) = stream.split();
+        let mut reader = BufReader::new(&mut read);
+
+        let frame = Frame::from_resp(&mut reader).await.unwrap();
+        assert_eq!(
+            frame,
+            // it wont be a hashmap by default since there is no spec for hashmaps in RESP
+            Some(Frame::Array(VecDeque::from([
+                Frame::from("key"),
+                Frame::from("value")
+            ])))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_frame_binary_roundtrip() {
+        // gzip magic, a nul byte and invalid utf8 sequences
+        let payload = vec![0x1F, 0x8B, 0x00, 0xFF, 0xFE, b'\r', b'\n', 0xC3];
+
+        let mut buff = Vec::new();
+        Frame::Bulk(payload.clone())
+            .to_resp2(&mut buff)
+            .await
+            .unwrap();
+
+        assert_eq!(buff, b"$8\r\n\x1F\x8B\x00\xFF\xFE\r\n\xC3\r\n");
+
+        let mut stream = create_tcp_stream(&buff).await;
+        let (mut read, _) = stream.split();
+        let mut reader = BufReader::new(&mut read);
+
+        let frame = Frame::from_resp(&mut reader).await.unwrap();
+        assert_eq!(frame, Some(Frame::Bulk(payload)));
+    }
+
+    #[tokio::test]
+    async fn test_frame_resp3_roundtrip() {
+        let frames = [
+            (Frame::Nil, &b"_\r\n"[..]),
+            (Frame::Double(1.5), b",1.5\r\n"),
+            (Frame::Double(f64::NEG_INFINITY), b",-inf\r\n"),
+            (Frame::Boolean(true), b"#t\r\n"),
+            (Frame::Boolean(false), b"#f\r\n"),
+            (
+                Frame::BigNumber("3492890328409238509324850943850943825024385".to_string()),
+                b"(3492890328409238509324850943850943825024385\r\n",
+            ),
+            (
+                Frame::Verbatim("txt".to_string(), b"Some string".to_vec()),
+                b"=15\r\ntxt:Some string\r\n",
+            ),
+            (
+                Frame::Error("SYNTAX invalid\r\nsyntax".to_string()),
+                b"!22\r\nSYNTAX invalid\r\nsyntax\r\n",
+            ),
+            (
+                Frame::Push(VecDeque::from([Frame::from("message"), Frame::from("hi")])),
+                b">2\r\n$7\r\nmessage\r\n$2\r\nhi\r\n",
+            ),
+            (
+                Frame::Map(vec![(Frame::from("a"), Frame::Double(0.5))]),
+                b"%1\r\n$1\r\na\r\n,0.5\r\n",
+            ),
+            (
+                Frame::Set(vec![Frame::Integer(1), Frame::Boolean(false)]),
+                b"~2\r\n:1\r\n#f\r\n",
+            ),
+            (
+                Frame::Attribute(
+                    vec![(Frame::from("ttl"), Frame::Integer(10))],
+                    Box::new(Frame::Array(VecDeque::from([Frame::Integer(1)]))),
+                ),
+                b"|1\r\n$3\r\nttl\r\n:10\r\n*1\r\n:1\r\n",
+            ),
+        ];
+
+        for (frame, encoded) in frames {
+            let mut buff = Vec::new();
+            frame.to_resp3(&mut buff).await.unwrap();
+            assert_eq!(buff, encoded, "encoding {frame:?}");
+
+            let mut data = encoded;
+            let mut reader = BufReader::new(&mut data);
+            let parsed = Frame::from_resp(&mut reader).await.unwrap();
+            assert_eq!(parsed, Some(frame), "parsing {encoded:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_frame_resp3_downgrade() {
+        let frames = [
+            (Frame::Nil, &b"$-1\r\n"[..]),
+            (Frame::NullArray, b"*-1\r\n"),
+            (Frame::Double(3.0), b"$1\r\n3\r\n"),
+            (Frame::Boolean(true), b":1\r\n"),
+            (Frame::BigNumber("-12".to_string()), b"$3\r\n-12\r\n"),
+            (
+                Frame::Verbatim("txt".to_string(), b"hi".to_vec()),
+                b"$2\r\nhi\r\n",
+            ),
+            (Frame::Error("ERR a\r\nb".to_string()), b"-ERR a  b\r\n"),
+            (
+                Frame::Map(vec![(Frame::from("a"), Frame::Integer(1))]),
+                b"*2\r\n$1\r\na\r\n:1\r\n",
+            ),
+            (
+                Frame::Attribute(
+                    vec![(Frame::from("ttl"), Frame::Integer(10))],
+                    Box::new(Frame::Integer(1)),
+                ),
+                b":1\r\n",
+            ),
+        ];
+
+        for (frame, encoded) in frames {
+            let mut buff = Vec::new();
+            frame.to_resp2(&mut buff).await.unwrap();
+            assert_eq!(buff, encoded, "encoding {frame:?}");
+        }
+    }
+
+    async fn parse_frame(data: &[u8]) -> Result<Option<Frame>> {
+        let mut data = data;
+        let mut reader = BufReader::new(&mut data);
+
+        Frame::from_resp(&mut reader).await
+    }
+
+    fn protocol_message(result: Result<Option<Frame>>) -> String {
+        match result {
+            Err(Error::Protocol(msg)) => msg,
+            other => panic!("expected a protocol error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_frame_never_panics() {
+        let frame = parse_frame(b"+QUEUED\r\n").await;
+        assert_eq!(frame.unwrap(), Some(Frame::Simple("QUEUED".to_string())));
+
+        let frame = parse_frame(b"?what\r\n").await;
+        assert_eq!(protocol_message(frame), "invalid frame type '?'");
+
+        let frame = parse_frame(b":12x\r\n").await;
+        assert_eq!(protocol_message(frame), "invalid integer");
+
+        let frame = parse_frame(b"\r\n").await;
+        assert_eq!(protocol_message(frame), "empty line");
+
+        let nested = b"*1\r\n".repeat(Limits::default().max_depth + 1);
+        let frame = parse_frame(&nested).await;
+        assert_eq!(protocol_message(frame), "exceeded maximum nesting depth");
+
+        let chained = b"|1\r\n+a\r\n+b\r\n".repeat(Limits::default().max_depth + 1);
+        let frame = parse_frame(&chained).await;
+        assert_eq!(protocol_message(frame), "exceeded maximum nesting depth");
+    }
+}
@@ -0,0 +1,180 @@
+/// Split an inline command line into its arguments.
+///
+/// Follows the rules of `sdssplitargs` from Redis: arguments are separated by
+/// whitespace, double quoted arguments understand `\n`, `\r`, `\t`, `\b`, `\a`
+/// and `\xHH` escapes, single quoted arguments only understand `\'`. A closing
+/// quote has to be followed by whitespace or the end of the line.
+///
+/// Like the C string Redis splits, the line ends at the first NUL byte.
+///
+/// Returns `None` if the quotes are unbalanced.
+pub fn split_args(line: &[u8]) -> Option<Vec<Vec<u8>>> {
+    let line = line.split(|&c| c == 0).next().unwrap_or_default();
+
+    let mut args = Vec::new();
+    let mut i = 0;
+
+    loop {
+        while i < line.len() && line[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        if i >= line.len() {
+            return Some(args);
+        }
+
+        let mut current = Vec::new();
+        let mut in_double = false;
+        let mut in_single = false;
+
+        loop {
+            if in_double {
+                let c = *line.get(i)?;
+
+                if c == b'\\' && i + 3 < line.len() && line[i + 1] == b'x' {
+                    if let (Some(high), Some(low)) =
+                        (hex_digit(line[i + 2]), hex_digit(line[i + 3]))
+                    {
+                        current.push(high * 16 + low);
+                        i += 4;
+                        continue;
+                    }
+                }
+
+                match c {
+                    b'\\' if i + 1 < line.len() => {
+                        i += 1;
+
+                        current.push(match line[i] {
+                            b'n' => b'\n',
+                            b'r' => b'\r',
+                            b't' => b'\t',
+                            b'b' => 0x08,
+                            b'a' => 0x07,
+                            c => c,
+                        });
+                    }
+
+                    b'"' => {
+                        // the closing quote must be followed by a space or nothing at all
+                        if line.get(i + 1).is_some_and(|c| !c.is_ascii_whitespace()) {
+                            return None;
+                        }
+
+                        i += 1;
+                        break;
+                    }
+
+                    c => current.push(c),
+                }
+            } else if in_single {
+                let c = *line.get(i)?;
+
+                match c {
+                    b'\\' if line.get(i + 1) == Some(&b'\'') => {
+                        i += 1;
+                        current.push(b'\'');
+                    }
+
+                    b'\'' => {
+                        if line.get(i + 1).is_some_and(|c| !c.is_ascii_whitespace()) {
+                            return None;
+                        }
+
+                        i += 1;
+                        break;
+                    }
+
+                    c => current.push(c),
+                }
+            } else {
+                match line.get(i) {
+                    None => break,
+                    Some(c) if c.is_ascii_whitespace() => break,
+                    Some(b'"') => in_double = true,
+                    Some(b'\'') => in_single = true,
+                    Some(c) => current.push(*c),
+                }
+            }
+
+            i += 1;
+        }
+
+        args.push(current);
+    }
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(line: &str) -> Option<Vec<String>> {
+        split_args(line.as_bytes()).map(|args| {
+            args.into_iter()
+                .map(|arg| String::from_utf8(arg).unwrap())
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_split_args() {
+        assert_eq!(split("PING"), Some(vec!["PING".into()]));
+        assert_eq!(
+            split("  SET  key   value "),
+            Some(vec!["SET".into(), "key".into(), "value".into()])
+        );
+        assert_eq!(split(""), Some(vec![]));
+        assert_eq!(split("   "), Some(vec![]));
+
+        assert_eq!(
+            split(r#"SET key "hello world""#),
+            Some(vec!["SET".into(), "key".into(), "hello world".into()])
+        );
+        assert_eq!(
+            split(r#"SET key "a\tb\n\x41\"""#),
+            Some(vec!["SET".into(), "key".into(), "a\tb\nA\"".into()])
+        );
+        assert_eq!(
+            split(r"SET key 'it\'s \n raw'"),
+            Some(vec!["SET".into(), "key".into(), r"it's \n raw".into()])
+        );
+        assert_eq!(
+            split(r#"SET key """#),
+            Some(vec!["SET".into(), "key".into(), "".into()])
+        );
+    }
+
+    #[test]
+    fn test_split_args_unbalanced() {
+        assert_eq!(split(r#"SET key "value"#), None);
+        assert_eq!(split("SET key 'value"), None);
+        assert_eq!(split(r#"SET key "value"trailing"#), None);
+        assert_eq!(split("SET key 'value'trailing"), None);
+
+        // a NUL byte ends the line, inside quotes too
+        assert_eq!(split("SET key \"val\0ue\""), None);
+    }
+
+    #[test]
+    fn test_split_args_binary() {
+        assert_eq!(
+            split_args(br#"SET "\x00\xff""#),
+            Some(vec![b"SET".to_vec(), vec![0x00, 0xFF]])
+        );
+        assert_eq!(split_args(b"PING\0"), Some(vec![b"PING".to_vec()]));
+        assert_eq!(
+            split_args(b"GET key\0ignored"),
+            Some(vec![b"GET".to_vec(), b"key".to_vec()])
+        );
+        assert_eq!(split_args(b"\0PING"), Some(vec![]));
+    }
+}
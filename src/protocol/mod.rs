@@ -0,0 +1,29 @@
+pub mod decoder;
+pub mod frame;
+pub mod inline;
+
+/// Bounds applied while decoding, anything beyond them is reported as a
+/// protocol error and the connection is closed.
+#[derive(Clone, Copy, Debug)]
+pub struct Limits {
+    /// Largest accepted bulk string, `proto-max-bulk-len` in Redis.
+    pub max_bulk_len: usize,
+    /// Largest accepted number of elements in an array, map or set.
+    pub max_multibulk_len: usize,
+    /// How deep aggregates may be nested inside each other. Requests are flat
+    /// arrays, so this only bounds frames read with [`frame::Frame::from_resp`].
+    pub max_depth: usize,
+    /// Longest accepted inline command or header line.
+    pub max_inline_len: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_bulk_len: 512 * 1024 * 1024,
+            max_multibulk_len: 1024 * 1024,
+            max_depth: 32,
+            max_inline_len: 64 * 1024,
+        }
+    }
+}
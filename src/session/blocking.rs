@@ -0,0 +1,286 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use tokio::sync::oneshot;
+use tokio::time::Duration;
+
+use crate::prelude::*;
+use crate::storage::db::Db;
+
+/// Tries to serve a blocked client against the keyspace, `None` to keep
+/// waiting.
+pub type Serve = Box<dyn FnMut(&mut Db) -> Option<Frame> + Send>;
+
+struct Waiter {
+    keys: Vec<Vec<u8>>,
+    serve: Serve,
+    reply: oneshot::Sender<Frame>,
+}
+
+#[derive(Default)]
+struct Waiters {
+    by_id: HashMap<u64, Waiter>,
+    /// The clients waiting on each key, in the order they blocked.
+    by_key: HashMap<Vec<u8>, VecDeque<u64>>,
+}
+
+impl Waiters {
+    fn remove(&mut self, id: u64) -> Option<Waiter> {
+        let waiter = self.by_id.remove(&id)?;
+
+        for key in &waiter.keys {
+            if let Some(queue) = self.by_key.get_mut(key) {
+                queue.retain(|other| *other != id);
+
+                if queue.is_empty() {
+                    self.by_key.remove(key);
+                }
+            }
+        }
+
+        Some(waiter)
+    }
+}
+
+/// Clients parked by blocking commands until one of their keys can serve
+/// them.
+///
+/// Like in Redis, a blocked client is served by whichever write makes its
+/// key ready: whenever a write lock on the store is released, the clients
+/// waiting on the keys that the write created or pushed to are tried in the
+/// order they blocked, so the first client in line gets the element and
+/// nobody else can take it in between. Writes that touch none of their keys,
+/// like the active expire cycle, don't retry anybody. See
+/// [`Store`](crate::storage::Store).
+#[derive(Default)]
+pub struct Blocking {
+    next_id: AtomicU64,
+    waiters: Mutex<Waiters>,
+}
+
+impl Blocking {
+    /// Park a client on `keys`. Call this with the store locked for writing
+    /// right after the command failed to run, so no write slips in between.
+    pub fn block(&self, keys: Vec<Vec<u8>>, serve: Serve) -> Blocked<'_> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = oneshot::channel();
+
+        let mut waiters = self.lock();
+
+        for key in &keys {
+            waiters.by_key.entry(key.clone()).or_default().push_back(id);
+        }
+
+        waiters.by_id.insert(
+            id,
+            Waiter {
+                keys,
+                serve,
+                reply: sender,
+            },
+        );
+
+        Blocked {
+            blocking: self,
+            id,
+            reply: receiver,
+        }
+    }
+
+    pub fn has_waiters(&self) -> bool {
+        !self.lock().by_id.is_empty()
+    }
+
+    /// Serve the clients waiting on the keys the store noted as ready, first
+    /// come first served for as long as the key holds something.
+    pub fn serve(&self, store: &mut Db) {
+        let mut waiters = self.lock();
+
+        // serving a client can write other keys clients wait on, e.g. BLMOVE
+        loop {
+            let ready = store.take_ready_keys();
+
+            if ready.is_empty() {
+                break;
+            }
+
+            for key in ready {
+                let queue: Vec<u64> = waiters
+                    .by_key
+                    .get(&key)
+                    .map(|queue| queue.iter().copied().collect())
+                    .unwrap_or_default();
+
+                for id in queue {
+                    if !store.contains_key(&key) {
+                        break;
+                    }
+
+                    let Some(waiter) = waiters.by_id.get_mut(&id) else {
+                        continue;
+                    };
+
+                    let Some(reply) = (waiter.serve)(store) else {
+                        continue;
+                    };
+
+                    if let Some(waiter) = waiters.remove(id) {
+                        // the client may have just hung up
+                        let _ = waiter.reply.send(reply);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Forget a waiter, returns whether it was still waiting.
+    fn unblock(&self, id: u64) -> bool {
+        self.lock().remove(id).is_some()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Waiters> {
+        self.waiters.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A parked client. Dropping it, e.g. when the client disconnects, takes it
+/// out of line.
+pub struct Blocked<'a> {
+    blocking: &'a Blocking,
+    id: u64,
+    reply: oneshot::Receiver<Frame>,
+}
+
+impl Blocked<'_> {
+    /// Wait to be served, `None` once the timeout passes. No timeout waits
+    /// forever.
+    pub async fn wait(mut self, timeout: Option<Duration>) -> Option<Frame> {
+        let reply = match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, &mut self.reply).await.ok(),
+            None => Some((&mut self.reply).await),
+        };
+
+        if let Some(Ok(reply)) = reply {
+            return Some(reply);
+        }
+
+        // the client may have been served right as the timeout fired
+        if self.blocking.unblock(self.id) {
+            None
+        } else {
+            self.reply.try_recv().ok()
+        }
+    }
+}
+
+impl Drop for Blocked<'_> {
+    fn drop(&mut self) {
+        self.blocking.unblock(self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::quicklist::QuickList;
+
+    fn pop(key: &'static [u8]) -> Serve {
+        Box::new(move |store| match store.get_mut(key) {
+            Some(Entry {
+                value: Value::List(list),
+                ..
+            }) => {
+                let value = list.pop_front()?;
+
+                if list.is_empty() {
+                    store.remove(key);
+                }
+
+                Some(Frame::Bulk(value))
+            }
+            _ => None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_blocking_fifo() {
+        let blocking = Blocking::default();
+        let mut store = Db::default();
+
+        let first = blocking.block(vec![b"a".to_vec(), b"b".to_vec()], pop(b"b"));
+        let second = blocking.block(vec![b"b".to_vec()], pop(b"b"));
+        let third = blocking.block(vec![b"b".to_vec()], pop(b"b"));
+
+        store.track_ready_keys();
+        blocking.serve(&mut store);
+        assert!(blocking.lock().by_id.len() == 3);
+
+        let list: QuickList = [b"x".to_vec(), b"y".to_vec()].into_iter().collect();
+        store.insert(b"b".to_vec(), Entry::new(Value::List(list)));
+        blocking.serve(&mut store);
+
+        assert_eq!(first.wait(None).await, Some(Frame::Bulk(b"x".to_vec())));
+        assert_eq!(second.wait(None).await, Some(Frame::Bulk(b"y".to_vec())));
+        assert!(!store.contains_key(b"b"));
+
+        let waiting = blocking.lock();
+        assert_eq!(waiting.by_key.keys().collect::<Vec<_>>(), [b"b"]);
+        drop(waiting);
+
+        drop(third);
+        assert!(!blocking.has_waiters() && blocking.lock().by_key.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_blocking_served_before_unlock() {
+        let store = Store::default();
+        let blocked = store.blocking().block(vec![b"key".to_vec()], pop(b"key"));
+
+        let mut db = store.write().await;
+        let list: QuickList = [b"x".to_vec()].into_iter().collect();
+        db.insert(b"key".to_vec(), Entry::new(Value::List(list)));
+        drop(db);
+
+        // the pushed element went to the waiter before anyone else got the lock
+        assert!(!store.read().await.contains_key(b"key"));
+        assert_eq!(blocked.wait(None).await, Some(Frame::Bulk(b"x".to_vec())));
+    }
+
+    #[tokio::test]
+    async fn test_blocking_retries_ready_keys_only() {
+        let blocking = Blocking::default();
+        let mut store = Db::default();
+
+        // written before anybody blocked, so never noted as ready
+        let list: QuickList = [b"x".to_vec()].into_iter().collect();
+        store.insert(b"a".to_vec(), Entry::new(Value::List(list)));
+
+        let blocked = blocking.block(vec![b"a".to_vec()], pop(b"a"));
+
+        store.track_ready_keys();
+        store.insert(b"other".to_vec(), Entry::new(Value::from(b"v".to_vec())));
+        blocking.serve(&mut store);
+        assert!(blocking.has_waiters() && store.contains_key(b"a"));
+
+        let entry = store.get_or_insert_with(b"a".to_vec(), || unreachable!());
+        let Value::List(list) = &mut entry.value else {
+            unreachable!();
+        };
+        list.push_back(b"y".to_vec());
+        blocking.serve(&mut store);
+
+        assert_eq!(blocked.wait(None).await, Some(Frame::Bulk(b"x".to_vec())));
+        assert!(store.take_ready_keys().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_blocking_timeout() {
+        let blocking = Blocking::default();
+        let blocked = blocking.block(vec![b"key".to_vec()], pop(b"key"));
+
+        let reply = blocked.wait(Some(Duration::from_millis(10))).await;
+
+        assert_eq!(reply, None);
+        assert!(!blocking.has_waiters());
+    }
+}
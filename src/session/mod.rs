@@ -1,14 +1,17 @@
+use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::Arc;
 
-use tokio::sync::RwLock;
+use tokio::time::Duration;
 
 use crate::prelude::*;
+use crate::storage::db::Db;
 
+pub mod blocking;
 pub mod state;
 
 pub struct Session {
     pub state: state::StateRef,
-    pub proto_version: RwLock<u8>,
+    pub proto_version: AtomicU8,
     // TODO: auth maybe?
 }
 
@@ -17,26 +20,62 @@ impl Session {
         Arc::new(Self {
             state,
             // default to RESP2 protocol, can be changed via HELLO command
-            proto_version: RwLock::new(2),
+            proto_version: AtomicU8::new(2),
         })
     }
 
-    pub async fn get_proto_version(&self) -> i64 {
-        *self.proto_version.read().await as i64
+    pub fn get_proto_version(&self) -> i64 {
+        i64::from(self.proto_version.load(Ordering::Relaxed))
     }
 
-    pub async fn set_proto_version(&self, version: i64) {
-        *self.proto_version.write().await = version as u8;
+    pub fn set_proto_version(&self, version: u8) {
+        self.proto_version.store(version, Ordering::Relaxed);
     }
 
-    pub async fn versioned_response(
+    /// Run `op` against the keyspace, or block the client on `keys` until it
+    /// can run. `op` gives `None` while there's nothing to work on, e.g. all
+    /// the lists are empty. Gives `None` once the timeout passes, what that
+    /// replies is up to the command.
+    ///
+    /// A waiting client is only retried once one of its keys exists, a key of
+    /// the wrong type keeps it waiting instead of failing it.
+    pub async fn block_on<F>(
         &self,
-        response: &Value,
-        writer: &mut WriteHalf<'_>,
-    ) -> Result<()> {
-        match self.get_proto_version().await {
-            2 => response.to_resp2(writer).await,
-            3 => response.to_resp3(writer).await,
+        keys: Vec<Vec<u8>>,
+        timeout: Option<Duration>,
+        mut op: F,
+    ) -> Result<Option<Frame>>
+    where
+        F: FnMut(&mut Db) -> Result<Option<Frame>> + Send + 'static,
+    {
+        let mut store = self.state.store.write().await;
+        let blocking = self.state.store.blocking();
+
+        if let Some(reply) = op(&mut store)? {
+            return Ok(Some(reply));
+        }
+
+        let blocked = blocking.block(
+            keys,
+            Box::new(move |store| match op(store) {
+                Ok(reply) => reply,
+                Err(Error::Reply(ReplyError::WrongType)) => None,
+                Err(Error::Reply(e)) => Some(e.into()),
+                Err(e) => Some(value_error!("{e}")),
+            }),
+        );
+
+        // the client is in line before anyone else can write
+        drop(store);
+
+        Ok(blocked.wait(timeout).await)
+    }
+
+    /// Encode a reply into `out` using the protocol negotiated by the client.
+    pub fn versioned_response(&self, response: &Frame, out: &mut Vec<u8>) {
+        match self.get_proto_version() {
+            2 => response.encode_resp2(out),
+            3 => response.encode_resp3(out),
             _ => unreachable!("Invalid protocol version"),
         }
     }
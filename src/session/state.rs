@@ -1,19 +1,33 @@
 use std::sync::Arc;
 
+use tokio::time::{Duration, Instant, MissedTickBehavior};
+
 use crate::commands::CommandHandler;
 use crate::prelude::*;
-use crate::storage::ExpireKey;
+use crate::protocol::Limits;
+
+/// How often the active expire cycle runs.
+const ACTIVE_EXPIRE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Keys, and hash fields, deleted per acquisition of the store lock.
+const ACTIVE_EXPIRE_BATCH: usize = 128;
 
-#[derive(Clone, Default)]
+/// How long a single run may keep deleting, a quarter of the interval.
+const ACTIVE_EXPIRE_BUDGET: Duration = Duration::from_millis(25);
+
+#[derive(Default)]
 pub struct State {
     pub store: Store,
     pub commands: CommandHandler,
-    pub expire_keys: ExpireKey,
+    pub limits: Limits,
 }
 
 impl State {
-    pub async fn new() -> Arc<Self> {
-        let mut state = State::default();
+    pub async fn new(limits: Limits) -> Arc<Self> {
+        let mut state = State {
+            limits,
+            ..Default::default()
+        };
 
         state.commands.register().await;
 
@@ -24,42 +38,50 @@ impl State {
         state
     }
 
+    /// Reclaim expired keys in the background, like the Redis active expire
+    /// cycle: every tick keys and hash fields are deleted in small batches,
+    /// soonest deadline first, until none are left or the time budget for the
+    /// tick is spent.
     fn expire_keys_task(state: Arc<Self>) {
         tokio::spawn(async move {
-            // run every 60 seconds
-            let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(60));
-
-            // skip the first tick
-            interval.tick().await;
+            let mut interval = tokio::time::interval(ACTIVE_EXPIRE_INTERVAL);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
 
             loop {
                 interval.tick().await;
 
-                #[cfg(debug_assertions)]
-                log::debug!("Running expire keys task");
+                let started = Instant::now();
+                let (mut keys, mut fields) = (0, 0);
 
-                let now = tokio::time::Instant::now();
+                loop {
+                    // the lock is released between batches so clients are never stalled for long
+                    let (key_batch, field_batch) = {
+                        let mut store = state.store.write().await;
 
-                let mut keys_to_remove = Vec::new();
-
-                for key in state.expire_keys.read().await.iter() {
-                    let expire_at = state.store.read().await;
-                    let expire_at = match expire_at.get(key) {
-                        Some(Value::Expire((_, expire_at))) => expire_at,
-                        _ => continue,
+                        (
+                            store.remove_expired(ACTIVE_EXPIRE_BATCH),
+                            store.remove_expired_fields(ACTIVE_EXPIRE_BATCH),
+                        )
                     };
 
-                    if now > *expire_at {
-                        keys_to_remove.push(key.clone());
+                    keys += key_batch;
+                    fields += field_batch;
+
+                    let drained =
+                        key_batch < ACTIVE_EXPIRE_BATCH && field_batch < ACTIVE_EXPIRE_BATCH;
+
+                    if drained || started.elapsed() > ACTIVE_EXPIRE_BUDGET {
+                        break;
                     }
-                }
 
-                #[cfg(debug_assertions)]
-                log::debug!("Removing keys: {keys_to_remove:?}");
+                    tokio::task::yield_now().await;
+                }
 
-                for key in keys_to_remove {
-                    state.store.write().await.remove(&key);
-                    state.expire_keys.write().await.remove(&key);
+                if keys > 0 || fields > 0 {
+                    log::debug!(
+                        "Removed {keys} expired keys and {fields} expired hash fields in {:?}",
+                        started.elapsed()
+                    );
                 }
             }
         });
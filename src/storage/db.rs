@@ -0,0 +1,407 @@
+use std::collections::{BTreeSet, HashMap};
+
+use super::value::{Entry, Value};
+use crate::utils::time::unix_millis;
+
+/// The keyspace.
+///
+/// Expired keys, and hashes whose fields have all expired, are never handed
+/// out: lookups through a shared reference treat them as missing, and every
+/// method taking `&mut self` deletes an expired key before touching it, the
+/// same way Redis expires keys lazily on access. Whatever nobody touches is reclaimed by the active expire cycle,
+/// which walks the deadline index from the soonest deadline on.
+///
+/// Expire times must be changed through [`Db::set_expire`] so the index stays
+/// in sync with the entries. Hashes whose fields expire are announced through
+/// [`Db::track_field_expire`], so the active expire cycle visits them too.
+///
+/// While clients are blocked, the keys that writes create or push to are
+/// noted as ready, like `signalKeyAsReady` in Redis, so only the clients
+/// waiting on those keys are retried. See [`Db::track_ready_keys`].
+#[derive(Debug, Default)]
+pub struct Db {
+    entries: HashMap<Vec<u8>, Entry>,
+    /// Keys that have an expire time set, ordered by their deadline.
+    expires: BTreeSet<(i64, Vec<u8>)>,
+    /// Hashes with expiring fields, by the soonest deadline they were
+    /// announced with. A hint only: the hash may have changed since.
+    field_expires: BTreeSet<(i64, Vec<u8>)>,
+    /// The deadline each hash is filed under in `field_expires`.
+    field_expire_keys: HashMap<Vec<u8>, i64>,
+    /// Keys written since the blocked clients were last served, `None` while
+    /// nobody is blocked.
+    ready_keys: Option<Vec<Vec<u8>>>,
+}
+
+impl Db {
+    pub fn get(&self, key: &[u8]) -> Option<&Entry> {
+        self.entries.get(key).filter(|entry| !entry.expired())
+    }
+
+    pub fn get_mut(&mut self, key: &[u8]) -> Option<&mut Entry> {
+        self.expire_if_needed(key);
+        self.entries.get_mut(key)
+    }
+
+    pub fn contains_key(&self, key: &[u8]) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Get the entry, creating it with `default` if the key doesn't exist.
+    pub fn get_or_insert_with(
+        &mut self,
+        key: Vec<u8>,
+        default: impl FnOnce() -> Entry,
+    ) -> &mut Entry {
+        self.expire_if_needed(&key);
+        self.signal_ready(&key);
+
+        self.entries.entry(key).or_insert_with_key(|key| {
+            let entry = default();
+
+            if let Some(expire_at) = entry.expire_at {
+                self.expires.insert((expire_at, key.clone()));
+            }
+
+            entry
+        })
+    }
+
+    /// Store the entry, returning the live one it replaced. An entry whose
+    /// deadline has already passed only deletes the key.
+    pub fn insert(&mut self, key: Vec<u8>, entry: Entry) -> Option<Entry> {
+        let prev = self.remove(&key);
+
+        if already_expired(entry.expire_at) {
+            return prev;
+        }
+
+        if let Some(expire_at) = entry.expire_at {
+            self.expires.insert((expire_at, key.clone()));
+        }
+
+        self.signal_ready(&key);
+        self.entries.insert(key, entry);
+
+        prev
+    }
+
+    /// Remove the key, returning the entry if it was still live.
+    pub fn remove(&mut self, key: &[u8]) -> Option<Entry> {
+        self.untrack_field_expire(key);
+
+        let entry = self.entries.remove(key)?;
+
+        if let Some(expire_at) = entry.expire_at {
+            self.expires.remove(&(expire_at, key.to_vec()));
+        }
+
+        (!entry.expired()).then_some(entry)
+    }
+
+    /// Change the expire time of a live key, returns whether the key exists.
+    /// A deadline that has already passed deletes the key.
+    pub fn set_expire(&mut self, key: &[u8], expire_at: Option<i64>) -> bool {
+        if already_expired(expire_at) {
+            return self.remove(key).is_some();
+        }
+
+        let Some(entry) = self.get_mut(key) else {
+            return false;
+        };
+
+        let prev = std::mem::replace(&mut entry.expire_at, expire_at);
+
+        if let Some(prev) = prev {
+            self.expires.remove(&(prev, key.to_vec()));
+        }
+
+        if let Some(expire_at) = expire_at {
+            self.expires.insert((expire_at, key.to_vec()));
+        }
+
+        true
+    }
+
+    /// Every live key.
+    pub fn keys(&self) -> impl Iterator<Item = &Vec<u8>> {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| !entry.expired())
+            .map(|(key, _)| key)
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.expires.clear();
+        self.field_expires.clear();
+        self.field_expire_keys.clear();
+    }
+
+    /// Delete the key if it has expired, returns whether it was deleted.
+    pub fn expire_if_needed(&mut self, key: &[u8]) -> bool {
+        if !self.entries.get(key).is_some_and(Entry::expired) {
+            return false;
+        }
+
+        self.remove(key);
+
+        true
+    }
+
+    /// Delete up to `limit` keys whose deadline has passed, soonest first.
+    /// Returns how many were deleted, less than `limit` means none are left.
+    pub fn remove_expired(&mut self, limit: usize) -> usize {
+        let now = unix_millis();
+        let mut removed = 0;
+
+        while removed < limit {
+            match self.expires.first() {
+                Some((expire_at, _)) if *expire_at < now => {}
+                _ => break,
+            }
+
+            if let Some((_, key)) = self.expires.pop_first() {
+                self.untrack_field_expire(&key);
+                self.entries.remove(&key);
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+
+    /// Note that a field of the hash at `key` expires at `expire_at`, call it
+    /// whenever a field gets an expire time.
+    pub fn track_field_expire(&mut self, key: &[u8], expire_at: i64) {
+        // each hash is filed once, under its soonest deadline
+        if let Some(filed) = self.field_expire_keys.get(key) {
+            if *filed <= expire_at {
+                return;
+            }
+        }
+
+        self.untrack_field_expire(key);
+        self.field_expires.insert((expire_at, key.to_vec()));
+        self.field_expire_keys.insert(key.to_vec(), expire_at);
+    }
+
+    /// Delete up to `limit` hash fields whose deadline has passed, from the
+    /// hashes with the soonest deadlines on. A hash left without fields is
+    /// deleted. Returns how many fields were deleted, less than `limit` means
+    /// none are left.
+    pub fn remove_expired_fields(&mut self, limit: usize) -> usize {
+        let now = unix_millis();
+        let mut removed = 0;
+
+        while removed < limit {
+            match self.field_expires.first() {
+                Some((expire_at, _)) if *expire_at < now => {}
+                _ => break,
+            }
+
+            let Some((_, key)) = self.field_expires.pop_first() else {
+                break;
+            };
+
+            self.field_expire_keys.remove(&key);
+
+            // not through `get_mut`, which would drop a hash of expired fields without counting them
+            let Some(Entry {
+                value: Value::Hash(hash),
+                ..
+            }) = self.entries.get_mut(&key)
+            else {
+                continue;
+            };
+
+            removed += hash.remove_expired(limit - removed);
+
+            let next = hash.next_expire();
+
+            if self.expire_if_needed(&key) {
+                continue;
+            }
+
+            if let Some(next) = next {
+                // fields left over past the limit are picked up by the next batch
+                self.track_field_expire(&key, next);
+            }
+        }
+
+        removed
+    }
+
+    /// Start noting the keys that writes create or push to, call it before
+    /// writing while clients are blocked.
+    pub fn track_ready_keys(&mut self) {
+        self.ready_keys.get_or_insert_with(Vec::new);
+    }
+
+    /// Stop noting ready keys, whatever wasn't taken yet is dropped.
+    pub fn untrack_ready_keys(&mut self) {
+        self.ready_keys = None;
+    }
+
+    /// The keys noted as ready since the last call, in the order they were
+    /// written. They keep being noted, serving a client may write more.
+    pub fn take_ready_keys(&mut self) -> Vec<Vec<u8>> {
+        self.ready_keys
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    fn signal_ready(&mut self, key: &[u8]) {
+        if let Some(ready) = &mut self.ready_keys {
+            ready.push(key.to_vec());
+        }
+    }
+
+    fn untrack_field_expire(&mut self, key: &[u8]) {
+        if let Some(filed) = self.field_expire_keys.remove(key) {
+            self.field_expires.remove(&(filed, key.to_vec()));
+        }
+    }
+}
+
+/// Whether a deadline being set is not in the future, Redis deletes the key
+/// right away instead of storing it.
+fn already_expired(expire_at: Option<i64>) -> bool {
+    expire_at.is_some_and(|expire_at| expire_at <= unix_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::hash::HashValue;
+
+    /// Sneak in a key that expired after it was stored.
+    fn insert_expired(db: &mut Db, key: &[u8]) {
+        let expire_at = unix_millis() - 1000;
+
+        db.entries.insert(
+            key.to_vec(),
+            Entry::with_expire(Value::from(b"old".to_vec()), expire_at),
+        );
+        db.expires.insert((expire_at, key.to_vec()));
+    }
+
+    #[test]
+    fn test_db_hides_expired_keys() {
+        let mut db = Db::default();
+
+        insert_expired(&mut db, b"gone");
+        db.insert(b"live".to_vec(), Entry::new(Value::from(b"value".to_vec())));
+
+        assert!(db.get(b"gone").is_none());
+        assert!(!db.contains_key(b"gone"));
+        assert_eq!(db.keys().collect::<Vec<_>>(), vec![&b"live".to_vec()]);
+    }
+
+    #[test]
+    fn test_db_deletes_expired_keys_on_write() {
+        let mut db = Db::default();
+
+        insert_expired(&mut db, b"key");
+
+        // the expired value is not handed back as the previous one
+        let entry = Entry::new(Value::from(b"new".to_vec()));
+        assert!(db.insert(b"key".to_vec(), entry).is_none());
+
+        insert_expired(&mut db, b"key");
+        assert!(db.remove(b"key").is_none());
+
+        insert_expired(&mut db, b"key");
+
+        let entry =
+            db.get_or_insert_with(b"key".to_vec(), || Entry::new(Value::from(b"new".to_vec())));
+        assert_eq!(entry.value, Value::from(b"new".to_vec()));
+        assert!(!db.set_expire(b"missing", None));
+        assert!(db.expires.is_empty());
+    }
+
+    #[test]
+    fn test_db_past_deadline_deletes_key() {
+        let mut db = Db::default();
+
+        let past = Entry::with_expire(Value::from(b"value".to_vec()), unix_millis() - 1);
+        db.insert(b"key".to_vec(), Entry::new(Value::from(b"value".to_vec())));
+        assert!(db.insert(b"key".to_vec(), past).is_some());
+        assert!(db.entries.is_empty());
+
+        db.insert(b"key".to_vec(), Entry::new(Value::from(b"value".to_vec())));
+        assert!(db.set_expire(b"key", Some(unix_millis() + 60_000)));
+        assert!(db.set_expire(b"key", Some(0)));
+        assert!(db.entries.is_empty() && db.expires.is_empty());
+    }
+
+    #[test]
+    fn test_db_remove_expired() {
+        let mut db = Db::default();
+
+        insert_expired(&mut db, b"a");
+        insert_expired(&mut db, b"b");
+        db.insert(b"c".to_vec(), Entry::new(Value::from(b"value".to_vec())));
+
+        db.insert(
+            b"d".to_vec(),
+            Entry::with_expire(Value::from(b"value".to_vec()), unix_millis() + 60_000),
+        );
+
+        assert_eq!(db.remove_expired(1), 1);
+        assert_eq!(db.remove_expired(10), 1);
+        assert_eq!(db.remove_expired(10), 0);
+        assert_eq!(db.keys().count(), 2);
+        assert_eq!(db.expires.len(), 1);
+
+        // a key reclaimed with expiring fields leaves nothing behind in the field index
+        let mut hash: HashValue = [(b"f".to_vec(), b"v".to_vec())].into_iter().collect();
+        hash.set_expire(b"f", Some(unix_millis() + 60_000));
+        insert_expired(&mut db, b"e");
+        db.entries.get_mut(&b"e"[..]).unwrap().value = Value::Hash(hash);
+        db.track_field_expire(b"e", unix_millis() + 60_000);
+
+        assert_eq!(db.remove_expired(10), 1);
+        assert!(db.field_expires.is_empty() && db.field_expire_keys.is_empty());
+    }
+
+    #[test]
+    fn test_db_remove_expired_fields() {
+        let mut db = Db::default();
+        let soon = unix_millis() + 5;
+
+        for (key, fields) in [(b"h", &[&b"a"[..], b"b"][..]), (b"g", &[b"a"])] {
+            let mut hash: HashValue = fields
+                .iter()
+                .map(|field| (field.to_vec(), b"value".to_vec()))
+                .collect();
+
+            hash.set_expire(b"a", Some(soon));
+            db.insert(key.to_vec(), Entry::new(Value::Hash(hash)));
+            db.track_field_expire(key, soon);
+        }
+
+        assert_eq!(db.remove_expired_fields(10), 0);
+        std::thread::sleep(std::time::Duration::from_millis(10));
+
+        // a hash whose fields have all expired is missing before it's reclaimed
+        assert!(!db.contains_key(b"g"));
+        assert_eq!(db.keys().collect::<Vec<_>>(), vec![&b"h".to_vec()]);
+
+        // a hash left without fields goes away with them
+        assert_eq!(db.remove_expired_fields(10), 2);
+        assert!(!db.contains_key(b"g"));
+        assert!(db.field_expires.is_empty() && db.field_expire_keys.is_empty());
+
+        let Some(Entry {
+            value: Value::Hash(hash),
+            ..
+        }) = db.get(b"h")
+        else {
+            panic!("the hash still has a field");
+        };
+
+        assert_eq!(hash.keys().collect::<Vec<_>>(), vec![&b"b".to_vec()]);
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::utils::time::unix_millis;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Field {
+    value: Vec<u8>,
+    /// Absolute deadline in Unix milliseconds.
+    expire_at: Option<i64>,
+}
+
+impl Field {
+    fn expired(&self, now: i64) -> bool {
+        self.expire_at.is_some_and(|expire_at| now > expire_at)
+    }
+}
+
+/// A hash value whose fields can each have an expire time, like the hash
+/// field expiration of Redis 7.4.
+///
+/// Expired fields are handled the way the keyspace handles expired keys:
+/// reads treat them as missing, writes delete them before touching them, and
+/// the active expire cycle reclaims the rest through [`HashValue::remove_expired`].
+/// The fields with a deadline are indexed by it, soonest first.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HashValue {
+    fields: HashMap<Vec<u8>, Field>,
+    expires: BTreeSet<(i64, Vec<u8>)>,
+}
+
+impl HashValue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of live fields.
+    pub fn len(&self) -> usize {
+        self.fields.len() - self.expired_count(unix_millis())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, field: &[u8]) -> Option<&Vec<u8>> {
+        self.live(field).map(|field| &field.value)
+    }
+
+    /// The value of a live field for editing in place, its expire time is
+    /// kept.
+    pub fn get_mut(&mut self, field: &[u8]) -> Option<&mut Vec<u8>> {
+        self.expire_if_needed(field);
+        self.fields.get_mut(field).map(|field| &mut field.value)
+    }
+
+    pub fn contains_key(&self, field: &[u8]) -> bool {
+        self.live(field).is_some()
+    }
+
+    /// Set a field, clearing its expire time. Returns the live value it
+    /// replaced.
+    pub fn insert(&mut self, field: Vec<u8>, value: Vec<u8>) -> Option<Vec<u8>> {
+        let prev = self.remove(&field);
+
+        self.fields.insert(
+            field,
+            Field {
+                value,
+                expire_at: None,
+            },
+        );
+
+        prev
+    }
+
+    /// Remove a field, returning its value if it was still live.
+    pub fn remove(&mut self, field: &[u8]) -> Option<Vec<u8>> {
+        let removed = self.fields.remove(field)?;
+
+        if let Some(expire_at) = removed.expire_at {
+            self.expires.remove(&(expire_at, field.to_vec()));
+        }
+
+        (!removed.expired(unix_millis())).then_some(removed.value)
+    }
+
+    /// Every live field with its value.
+    pub fn iter(&self) -> impl Iterator<Item = (&Vec<u8>, &Vec<u8>)> {
+        let now = unix_millis();
+
+        self.fields
+            .iter()
+            .filter(move |(_, field)| !field.expired(now))
+            .map(|(name, field)| (name, &field.value))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &Vec<u8>> {
+        self.iter().map(|(field, _)| field)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &Vec<u8>> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// The expire time of a live field: `None` if the field doesn't exist,
+    /// `Some(None)` if it never expires.
+    pub fn expire_at(&self, field: &[u8]) -> Option<Option<i64>> {
+        self.live(field).map(|field| field.expire_at)
+    }
+
+    /// Change the expire time of a live field, returns whether it exists. A
+    /// deadline that has already passed deletes the field.
+    pub fn set_expire(&mut self, field: &[u8], expire_at: Option<i64>) -> bool {
+        if expire_at.is_some_and(|expire_at| expire_at <= unix_millis()) {
+            return self.remove(field).is_some();
+        }
+
+        self.expire_if_needed(field);
+
+        let Some(entry) = self.fields.get_mut(field) else {
+            return false;
+        };
+
+        let prev = std::mem::replace(&mut entry.expire_at, expire_at);
+
+        if let Some(prev) = prev {
+            self.expires.remove(&(prev, field.to_vec()));
+        }
+
+        if let Some(expire_at) = expire_at {
+            self.expires.insert((expire_at, field.to_vec()));
+        }
+
+        true
+    }
+
+    /// The soonest deadline of any field.
+    pub fn next_expire(&self) -> Option<i64> {
+        self.expires.first().map(|(expire_at, _)| *expire_at)
+    }
+
+    /// Delete up to `limit` fields whose deadline has passed, soonest first.
+    /// Returns how many were deleted.
+    pub fn remove_expired(&mut self, limit: usize) -> usize {
+        let now = unix_millis();
+        let mut removed = 0;
+
+        while removed < limit {
+            match self.expires.first() {
+                Some((expire_at, _)) if *expire_at < now => {}
+                _ => break,
+            }
+
+            if let Some((_, field)) = self.expires.pop_first() {
+                self.fields.remove(&field);
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+
+    fn live(&self, field: &[u8]) -> Option<&Field> {
+        self.fields
+            .get(field)
+            .filter(|field| !field.expired(unix_millis()))
+    }
+
+    fn expire_if_needed(&mut self, field: &[u8]) {
+        if self
+            .fields
+            .get(field)
+            .is_some_and(|entry| entry.expired(unix_millis()))
+        {
+            self.remove(field);
+        }
+    }
+
+    /// Fields past their deadline that haven't been deleted yet.
+    fn expired_count(&self, now: i64) -> usize {
+        self.expires.range(..(now, Vec::new())).count()
+    }
+}
+
+impl FromIterator<(Vec<u8>, Vec<u8>)> for HashValue {
+    fn from_iter<I: IntoIterator<Item = (Vec<u8>, Vec<u8>)>>(iter: I) -> Self {
+        let mut hash = Self::new();
+
+        for (field, value) in iter {
+            hash.insert(field, value);
+        }
+
+        hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Give a field a deadline that has already passed.
+    fn expire_now(hash: &mut HashValue, field: &[u8]) {
+        let expire_at = unix_millis() - 1000;
+
+        if let Some(entry) = hash.fields.get_mut(field) {
+            entry.expire_at = Some(expire_at);
+        }
+
+        hash.expires.insert((expire_at, field.to_vec()));
+    }
+
+    #[test]
+    fn test_hash_value_field_expire() {
+        let mut hash: HashValue = [(b"a", b"1"), (b"b", b"2"), (b"c", b"3")]
+            .into_iter()
+            .map(|(field, value)| (field.to_vec(), value.to_vec()))
+            .collect();
+
+        let later = unix_millis() + 60_000;
+        assert!(hash.set_expire(b"a", Some(later)));
+        assert!(!hash.set_expire(b"x", Some(later)));
+        assert_eq!(hash.expire_at(b"a"), Some(Some(later)));
+        assert_eq!(hash.expire_at(b"b"), Some(None));
+        assert_eq!(hash.next_expire(), Some(later));
+
+        // overwriting a field clears its expire time
+        hash.insert(b"a".to_vec(), b"10".to_vec());
+        assert_eq!(hash.expire_at(b"a"), Some(None));
+        assert_eq!(hash.next_expire(), None);
+
+        expire_now(&mut hash, b"b");
+        assert_eq!(hash.len(), 2);
+        assert_eq!(hash.get(b"b"), None);
+        assert!(!hash.contains_key(b"b"));
+        assert_eq!(hash.keys().count(), 2);
+        assert_eq!(hash.insert(b"b".to_vec(), b"new".to_vec()), None);
+
+        expire_now(&mut hash, b"b");
+        expire_now(&mut hash, b"c");
+        assert_eq!(hash.remove_expired(1), 1);
+        assert_eq!(hash.remove_expired(10), 1);
+        assert_eq!(hash.fields.len(), 1);
+        assert!(hash.expires.is_empty());
+
+        // a deadline in the past deletes the field
+        assert!(hash.set_expire(b"a", Some(0)));
+        assert!(hash.is_empty());
+    }
+}
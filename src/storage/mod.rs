@@ -1,11 +1,77 @@
+pub mod db;
+pub mod hash;
+pub mod quicklist;
+pub mod set;
+pub mod skiplist;
 pub mod value;
+pub mod zset;
 
-use std::collections::HashSet;
-use std::sync::Arc;
+use std::ops::{Deref, DerefMut};
 
-use tokio::sync::RwLock;
+use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
-use crate::prelude::*;
+use self::db::Db;
+use crate::session::blocking::Blocking;
 
-pub type Store = Arc<RwLock<HashMap<String, Value>>>;
-pub type ExpireKey = Arc<RwLock<HashSet<String>>>;
+/// The keyspace behind its lock, along with the clients blocked on it.
+///
+/// Before a write lock is released the clients waiting on keys that the
+/// write made ready are served, inside the same critical section, so an
+/// element pushed for a blocked client can't be taken by anyone else first.
+#[derive(Default)]
+pub struct Store {
+    db: RwLock<Db>,
+    blocking: Blocking,
+}
+
+impl Store {
+    pub async fn read(&self) -> RwLockReadGuard<'_, Db> {
+        self.db.read().await
+    }
+
+    pub async fn write(&self) -> StoreWriteGuard<'_> {
+        let mut db = self.db.write().await;
+
+        // nobody can block or be served while the lock is held, so this holds
+        // for the whole write
+        if self.blocking.has_waiters() {
+            db.track_ready_keys();
+        }
+
+        StoreWriteGuard {
+            db,
+            blocking: &self.blocking,
+        }
+    }
+
+    pub fn blocking(&self) -> &Blocking {
+        &self.blocking
+    }
+}
+
+/// Write access to the keyspace, serves blocked clients when dropped.
+pub struct StoreWriteGuard<'a> {
+    db: RwLockWriteGuard<'a, Db>,
+    blocking: &'a Blocking,
+}
+
+impl Deref for StoreWriteGuard<'_> {
+    type Target = Db;
+
+    fn deref(&self) -> &Db {
+        &self.db
+    }
+}
+
+impl DerefMut for StoreWriteGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Db {
+        &mut self.db
+    }
+}
+
+impl Drop for StoreWriteGuard<'_> {
+    fn drop(&mut self) {
+        self.blocking.serve(&mut self.db);
+        self.db.untrack_ready_keys();
+    }
+}
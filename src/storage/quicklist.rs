@@ -0,0 +1,413 @@
+use std::collections::VecDeque;
+
+/// Most entries a single node holds before it's split.
+const NODE_MAX_ENTRIES: usize = 128;
+/// Most bytes a node with more than one entry holds before it's split.
+const NODE_MAX_BYTES: usize = 8 * 1024;
+
+/// A list stored as a deque of small nodes, the way Redis keeps lists in a
+/// quicklist of listpacks.
+///
+/// Pushes and pops at either end only touch the end nodes, and finding an
+/// index walks whole nodes from the nearer end instead of single entries.
+/// Nodes are bounded both by entry count and size, so inserting into the
+/// middle of a long list shifts a few entries rather than all of them.
+#[derive(Clone, Debug, Default)]
+pub struct QuickList {
+    nodes: VecDeque<Node>,
+    len: usize,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    entries: VecDeque<Vec<u8>>,
+    bytes: usize,
+}
+
+impl Node {
+    fn full(&self) -> bool {
+        self.entries.len() >= NODE_MAX_ENTRIES || self.bytes >= NODE_MAX_BYTES
+    }
+
+    fn push_front(&mut self, value: Vec<u8>) {
+        self.bytes += value.len();
+        self.entries.push_front(value);
+    }
+
+    fn push_back(&mut self, value: Vec<u8>) {
+        self.bytes += value.len();
+        self.entries.push_back(value);
+    }
+
+    fn remove(&mut self, index: usize) -> Option<Vec<u8>> {
+        let value = self.entries.remove(index)?;
+        self.bytes -= value.len();
+
+        Some(value)
+    }
+
+    /// Split off the second half into a new node.
+    fn split(&mut self) -> Self {
+        let entries = self.entries.split_off(self.entries.len() / 2);
+        let bytes = entries.iter().map(Vec::len).sum();
+        self.bytes -= bytes;
+
+        Self { entries, bytes }
+    }
+}
+
+impl QuickList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push_front(&mut self, value: Vec<u8>) {
+        if self.nodes.front().is_none_or(Node::full) {
+            self.nodes.push_front(Node::default());
+        }
+
+        if let Some(node) = self.nodes.front_mut() {
+            node.push_front(value);
+        }
+
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, value: Vec<u8>) {
+        if self.nodes.back().is_none_or(Node::full) {
+            self.nodes.push_back(Node::default());
+        }
+
+        if let Some(node) = self.nodes.back_mut() {
+            node.push_back(value);
+        }
+
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<Vec<u8>> {
+        self.remove(0)
+    }
+
+    pub fn pop_back(&mut self) -> Option<Vec<u8>> {
+        self.remove(self.len.checked_sub(1)?)
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Vec<u8>> {
+        let (node, offset) = self.locate(index)?;
+        self.nodes[node].entries.get(offset)
+    }
+
+    /// Replace the entry at `index`, returns whether it exists.
+    pub fn set(&mut self, index: usize, value: Vec<u8>) -> bool {
+        let Some((node, offset)) = self.locate(index) else {
+            return false;
+        };
+
+        let node = &mut self.nodes[node];
+        node.bytes = node.bytes - node.entries[offset].len() + value.len();
+        node.entries[offset] = value;
+
+        true
+    }
+
+    /// Insert before the entry at `index`, an index equal to the length
+    /// appends.
+    pub fn insert(&mut self, index: usize, value: Vec<u8>) {
+        if index >= self.len {
+            return self.push_back(value);
+        }
+
+        let Some((node, offset)) = self.locate(index) else {
+            return;
+        };
+
+        self.nodes[node].bytes += value.len();
+        self.nodes[node].entries.insert(offset, value);
+        self.len += 1;
+
+        // a node that grew past its bounds is split in two
+        if self.nodes[node].entries.len() > NODE_MAX_ENTRIES
+            || (self.nodes[node].bytes > NODE_MAX_BYTES && self.nodes[node].entries.len() > 1)
+        {
+            let tail = self.nodes[node].split();
+            self.nodes.insert(node + 1, tail);
+        }
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<Vec<u8>> {
+        let (node, offset) = self.locate(index)?;
+        let value = self.nodes[node].remove(offset)?;
+
+        if self.nodes[node].entries.is_empty() {
+            self.nodes.remove(node);
+        }
+
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    /// Keep only the entries in the inclusive range `start..=end`, anything
+    /// out of range empties the list.
+    pub fn trim(&mut self, start: usize, end: usize) {
+        if start > end || start >= self.len {
+            return self.clear();
+        }
+
+        let back = self.len - 1 - end.min(self.len - 1);
+
+        self.remove_front(start);
+        self.remove_back(back);
+    }
+
+    /// Remove up to `limit` entries equal to `value`, scanning from the back
+    /// with `from_back`. Returns how many were removed.
+    pub fn remove_matching(&mut self, value: &[u8], limit: usize, from_back: bool) -> usize {
+        let mut removed = 0;
+
+        let count = self.nodes.len();
+
+        for i in 0..count {
+            let node = &mut self.nodes[if from_back { count - 1 - i } else { i }];
+            let mut seen = 0;
+
+            // removing an entry brings the next candidate to the same distance from the start
+            while seen < node.entries.len() && removed < limit {
+                let offset = if from_back {
+                    node.entries.len() - 1 - seen
+                } else {
+                    seen
+                };
+
+                if node.entries[offset] == value {
+                    node.remove(offset);
+                    removed += 1;
+                } else {
+                    seen += 1;
+                }
+            }
+        }
+
+        self.nodes.retain(|node| !node.entries.is_empty());
+        self.len -= removed;
+
+        removed
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.len = 0;
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Vec<u8>> {
+        self.nodes.iter().flat_map(|node| node.entries.iter())
+    }
+
+    /// The entries from `start` on, skipping whole nodes to get there.
+    pub fn iter_from(&self, start: usize) -> impl Iterator<Item = &Vec<u8>> {
+        let (node, offset) = self.locate(start).unwrap_or((self.nodes.len(), 0));
+
+        self.nodes
+            .range(node..)
+            .flat_map(|node| node.entries.iter())
+            .skip(offset)
+    }
+
+    /// Remove the first `count` entries.
+    fn remove_front(&mut self, mut count: usize) {
+        while count > 0 {
+            let Some(node) = self.nodes.front_mut() else {
+                break;
+            };
+
+            if node.entries.len() <= count {
+                count -= node.entries.len();
+                self.len -= node.entries.len();
+                self.nodes.pop_front();
+            } else {
+                for value in node.entries.drain(..count) {
+                    node.bytes -= value.len();
+                }
+
+                self.len -= count;
+                count = 0;
+            }
+        }
+    }
+
+    /// Remove the last `count` entries.
+    fn remove_back(&mut self, mut count: usize) {
+        while count > 0 {
+            let Some(node) = self.nodes.back_mut() else {
+                break;
+            };
+
+            if node.entries.len() <= count {
+                count -= node.entries.len();
+                self.len -= node.entries.len();
+                self.nodes.pop_back();
+            } else {
+                let keep = node.entries.len() - count;
+
+                for value in node.entries.drain(keep..) {
+                    node.bytes -= value.len();
+                }
+
+                self.len -= count;
+                count = 0;
+            }
+        }
+    }
+
+    /// The node holding `index` and the offset within it, walking from
+    /// whichever end is closer.
+    fn locate(&self, index: usize) -> Option<(usize, usize)> {
+        if index >= self.len {
+            return None;
+        }
+
+        if index < self.len / 2 {
+            let mut offset = index;
+
+            for (i, node) in self.nodes.iter().enumerate() {
+                if offset < node.entries.len() {
+                    return Some((i, offset));
+                }
+
+                offset -= node.entries.len();
+            }
+        } else {
+            let mut offset = self.len - 1 - index;
+
+            for (i, node) in self.nodes.iter().enumerate().rev() {
+                if offset < node.entries.len() {
+                    return Some((i, node.entries.len() - 1 - offset));
+                }
+
+                offset -= node.entries.len();
+            }
+        }
+
+        None
+    }
+}
+
+impl PartialEq for QuickList {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for QuickList {}
+
+impl FromIterator<Vec<u8>> for QuickList {
+    fn from_iter<I: IntoIterator<Item = Vec<u8>>>(iter: I) -> Self {
+        let mut list = Self::new();
+
+        for value in iter {
+            list.push_back(value);
+        }
+
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(list: &QuickList) -> Vec<usize> {
+        list.iter()
+            .map(|value| String::from_utf8_lossy(value).parse().unwrap())
+            .collect()
+    }
+
+    fn numbers(range: std::ops::Range<usize>) -> QuickList {
+        range.map(|i| i.to_string().into_bytes()).collect()
+    }
+
+    #[test]
+    fn test_quicklist_ends() {
+        let mut list = numbers(0..300);
+        list.push_front(b"1000".to_vec());
+
+        assert_eq!(list.len(), 301);
+        assert_eq!(list.nodes.len(), 4);
+        assert_eq!(list.pop_front(), Some(b"1000".to_vec()));
+        assert_eq!(list.pop_back(), Some(b"299".to_vec()));
+        assert_eq!(list.get(128), Some(&b"128".to_vec()));
+        assert_eq!(list.get(298), Some(&b"298".to_vec()));
+        assert_eq!(list.get(299), None);
+        assert_eq!(values(&list), (0..299).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_quicklist_middle() {
+        let mut list = numbers(0..256);
+
+        for _ in 0..100 {
+            list.insert(130, b"1000".to_vec());
+        }
+
+        assert_eq!(list.len(), 356);
+        assert!(list
+            .nodes
+            .iter()
+            .all(|node| node.entries.len() <= NODE_MAX_ENTRIES));
+        assert_eq!(list.get(129), Some(&b"129".to_vec()));
+        assert_eq!(list.get(230), Some(&b"130".to_vec()));
+
+        for _ in 0..100 {
+            assert_eq!(list.remove(130), Some(b"1000".to_vec()));
+        }
+
+        assert!(list.set(0, b"7".to_vec()));
+        assert!(!list.set(256, b"7".to_vec()));
+        assert_eq!(values(&list)[..3], [7, 1, 2]);
+
+        for i in (0..256).step_by(2) {
+            assert!(list.set(i, b"0".to_vec()));
+        }
+
+        assert_eq!(list.remove_matching(b"0", 3, true), 3);
+        assert_eq!(list.get(250), Some(&b"251".to_vec()));
+        assert_eq!(list.get(251), Some(&b"253".to_vec()));
+        assert_eq!(list.get(248), Some(&b"0".to_vec()));
+        assert_eq!(list.remove_matching(b"0", usize::MAX, false), 125);
+        assert_eq!(list.len(), 128);
+        assert_eq!(values(&list)[..2], [1, 3]);
+        list.set(0, b"7".to_vec());
+        assert_eq!(list.iter_from(100).count(), 28);
+        assert_eq!(list.iter_from(128).count(), 0);
+    }
+
+    #[test]
+    fn test_quicklist_trim() {
+        let mut list = numbers(0..300);
+
+        list.trim(10, 289);
+        assert_eq!(values(&list), (10..290).collect::<Vec<_>>());
+        assert_eq!(
+            list.nodes
+                .iter()
+                .map(|node| node.entries.len())
+                .sum::<usize>(),
+            280
+        );
+
+        list.trim(0, 1000);
+        assert_eq!(list.len(), 280);
+
+        list.trim(5, 2);
+        assert!(list.is_empty() && list.nodes.is_empty());
+    }
+}
@@ -0,0 +1,326 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::utils::number::parse_integer;
+use crate::utils::random::{random_index, sample_indices};
+
+/// Most members a set keeps in the integer encoding.
+const MAX_INTSET_ENTRIES: usize = 512;
+
+/// A set value. Small sets of nothing but canonical integers are kept as a
+/// sorted array of numbers like the Redis intset, anything else is converted
+/// to a hash table for good.
+#[derive(Clone, Debug)]
+pub enum SetValue {
+    Ints(Vec<i64>),
+    Hash(Members),
+}
+
+impl Default for SetValue {
+    fn default() -> Self {
+        Self::Ints(Vec::new())
+    }
+}
+
+impl SetValue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Ints(ints) => ints.len(),
+            Self::Hash(members) => members.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains(&self, member: &[u8]) -> bool {
+        match self {
+            Self::Ints(ints) => {
+                parse_integer(member).is_some_and(|int| ints.binary_search(&int).is_ok())
+            }
+            Self::Hash(members) => members.contains(member),
+        }
+    }
+
+    /// Add a member, returns whether it's new.
+    pub fn insert(&mut self, member: Vec<u8>) -> bool {
+        if let Self::Ints(ints) = self {
+            if let Some(int) = parse_integer(&member) {
+                let Err(index) = ints.binary_search(&int) else {
+                    return false;
+                };
+
+                if ints.len() < MAX_INTSET_ENTRIES {
+                    ints.insert(index, int);
+                    return true;
+                }
+            }
+
+            self.convert();
+        }
+
+        match self {
+            Self::Hash(members) => members.insert(member),
+            Self::Ints(_) => unreachable!("converted to a hash set above"),
+        }
+    }
+
+    /// Remove a member, returns whether it was there.
+    pub fn remove(&mut self, member: &[u8]) -> bool {
+        match self {
+            Self::Ints(ints) => match parse_integer(member).map(|int| ints.binary_search(&int)) {
+                Some(Ok(index)) => {
+                    ints.remove(index);
+                    true
+                }
+                _ => false,
+            },
+            Self::Hash(members) => members.remove(member),
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        match self {
+            Self::Ints(ints) => Iter::Ints(ints.iter()),
+            Self::Hash(members) => Iter::Hash(members.iter()),
+        }
+    }
+
+    /// A random member.
+    pub fn random(&self) -> Option<Vec<u8>> {
+        match self {
+            Self::Ints(ints) => ints
+                .get(random_index(ints.len()))
+                .map(|int| int.to_string().into_bytes()),
+            Self::Hash(members) => members
+                .list
+                .get(random_index(members.len()))
+                .map(|member| member.to_vec()),
+        }
+    }
+
+    /// Up to `count` distinct random members.
+    pub fn sample(&self, count: usize) -> Vec<Vec<u8>> {
+        sample_indices(self.len(), count)
+            .into_iter()
+            .map(|index| match self {
+                Self::Ints(ints) => ints[index].to_string().into_bytes(),
+                Self::Hash(members) => members.list[index].to_vec(),
+            })
+            .collect()
+    }
+
+    /// Remove up to `count` random members and return them.
+    pub fn pop(&mut self, count: usize) -> Vec<Vec<u8>> {
+        let popped = self.sample(count);
+
+        for member in &popped {
+            self.remove(member);
+        }
+
+        popped
+    }
+
+    fn convert(&mut self) {
+        if let Self::Ints(ints) = self {
+            let members = ints
+                .iter()
+                .map(|int| int.to_string().into_bytes())
+                .collect();
+            *self = Self::Hash(members);
+        }
+    }
+}
+
+impl PartialEq for SetValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|member| other.contains(&member))
+    }
+}
+
+impl Eq for SetValue {}
+
+impl FromIterator<Vec<u8>> for SetValue {
+    fn from_iter<I: IntoIterator<Item = Vec<u8>>>(iter: I) -> Self {
+        let mut set = Self::new();
+
+        for member in iter {
+            set.insert(member);
+        }
+
+        set
+    }
+}
+
+/// The members of a set in the hash table encoding.
+///
+/// They're kept in an array, so a random member is picked in O(1) like Redis
+/// does out of its dict buckets, and found through a map to their position in
+/// it. Both share each member.
+#[derive(Clone, Debug, Default)]
+pub struct Members {
+    list: Vec<Arc<[u8]>>,
+    positions: HashMap<Arc<[u8]>, usize>,
+}
+
+impl Members {
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn contains(&self, member: &[u8]) -> bool {
+        self.positions.contains_key(member)
+    }
+
+    /// Add a member, returns whether it's new.
+    pub fn insert(&mut self, member: Vec<u8>) -> bool {
+        if self.contains(&member) {
+            return false;
+        }
+
+        let member: Arc<[u8]> = member.into();
+
+        self.positions.insert(member.clone(), self.list.len());
+        self.list.push(member);
+
+        true
+    }
+
+    /// Remove a member, returns whether it was there. The last member takes
+    /// its place in the array.
+    pub fn remove(&mut self, member: &[u8]) -> bool {
+        let Some(position) = self.positions.remove(member) else {
+            return false;
+        };
+
+        self.list.swap_remove(position);
+
+        if let Some(moved) = self.list.get(position) {
+            self.positions.insert(moved.clone(), position);
+        }
+
+        true
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Arc<[u8]>> {
+        self.list.iter()
+    }
+}
+
+impl FromIterator<Vec<u8>> for Members {
+    fn from_iter<I: IntoIterator<Item = Vec<u8>>>(iter: I) -> Self {
+        let mut members = Self::default();
+
+        for member in iter {
+            members.insert(member);
+        }
+
+        members
+    }
+}
+
+/// The members of a set, integers are formatted on the fly.
+pub enum Iter<'a> {
+    Ints(std::slice::Iter<'a, i64>),
+    Hash(std::slice::Iter<'a, Arc<[u8]>>),
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = Cow<'a, [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Ints(ints) => ints
+                .next()
+                .map(|int| Cow::Owned(int.to_string().into_bytes())),
+            Self::Hash(members) => members.next().map(|member| Cow::Borrowed(&member[..])),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Ints(ints) => ints.size_hint(),
+            Self::Hash(members) => members.size_hint(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_set_value_encoding() {
+        let mut set = SetValue::new();
+
+        assert!(set.insert(b"3".to_vec()));
+        assert!(set.insert(b"-1".to_vec()));
+        assert!(!set.insert(b"3".to_vec()));
+        assert!(set.contains(b"-1") && !set.contains(b"03"));
+        assert_eq!(set, SetValue::Ints(vec![-1, 3]));
+
+        // a non canonical integer is a string member like any other
+        assert!(set.insert(b"03".to_vec()));
+        assert!(matches!(set, SetValue::Hash(_)));
+        assert!(set.contains(b"3") && set.contains(b"03"));
+        assert!(set.remove(b"-1") && !set.remove(b"-1"));
+        assert_eq!(set.len(), 2);
+
+        let mut set: SetValue = (0..MAX_INTSET_ENTRIES)
+            .map(|i| i.to_string().into_bytes())
+            .collect();
+        assert!(matches!(set, SetValue::Ints(_)));
+
+        set.insert(b"1000".to_vec());
+        assert!(matches!(set, SetValue::Hash(_)));
+        assert_eq!(set.len(), MAX_INTSET_ENTRIES + 1);
+    }
+
+    #[test]
+    fn test_set_value_random() {
+        let mut set: SetValue = (0..10).map(|i| i.to_string().into_bytes()).collect();
+
+        let sample = set.sample(4);
+        assert_eq!(sample.len(), 4);
+        assert_eq!(sample.iter().collect::<HashSet<_>>().len(), 4);
+        assert!(sample.iter().all(|member| set.contains(member)));
+        assert_eq!(set.sample(20).len(), 10);
+
+        let popped = set.pop(3);
+        assert_eq!(set.len(), 7);
+        assert!(popped.iter().all(|member| !set.contains(member)));
+        assert!(set.random().is_some_and(|member| set.contains(&member)));
+    }
+
+    #[test]
+    fn test_set_members_positions() {
+        let mut members: Members = ["a", "b", "c", "d"]
+            .into_iter()
+            .map(|member| member.as_bytes().to_vec())
+            .collect();
+
+        // the last member moves into the hole and stays reachable
+        assert!(members.remove(b"b") && !members.remove(b"b"));
+        assert!(!members.insert(b"d".to_vec()));
+        assert!(members.remove(b"d"));
+        assert!(members.insert(b"e".to_vec()));
+
+        for (position, member) in members.iter().enumerate() {
+            assert_eq!(members.positions[member], position);
+        }
+
+        assert_eq!(members.len(), 3);
+        assert!(members.contains(b"c") && !members.contains(b"d"));
+    }
+}
@@ -0,0 +1,387 @@
+use std::cmp::Ordering;
+
+use crate::utils::random::random_u64;
+
+/// Most levels a node can have, enough for 4^32 elements.
+const MAX_LEVEL: usize = 32;
+
+/// A link to the next node on one level, `span` counts the nodes it skips
+/// over plus one so ranks can be summed up on the way down.
+#[derive(Clone, Copy, Debug, Default)]
+struct Link {
+    forward: Option<usize>,
+    span: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    member: Vec<u8>,
+    score: f64,
+    backward: Option<usize>,
+    levels: Vec<Link>,
+}
+
+/// The ordered half of a large sorted set: a skiplist ordered by score, then
+/// member, with spans on every link like the Redis zskiplist, so finding an
+/// element's rank or the element at a rank takes O(log N).
+///
+/// Nodes live in a slab and link to each other by index. Positions are
+/// `Option<usize>`, where `None` is the head of the list.
+#[derive(Clone, Debug)]
+pub struct SkipList {
+    nodes: Vec<Option<Node>>,
+    free: Vec<usize>,
+    head: Vec<Link>,
+    tail: Option<usize>,
+    level: usize,
+    len: usize,
+}
+
+impl Default for SkipList {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: vec![Link::default(); MAX_LEVEL],
+            tail: None,
+            level: 1,
+            len: 0,
+        }
+    }
+}
+
+/// Order by score first, then by member bytes.
+pub fn compare(score: f64, member: &[u8], other_score: f64, other_member: &[u8]) -> Ordering {
+    score
+        .partial_cmp(&other_score)
+        .unwrap_or(Ordering::Equal)
+        .then_with(|| member.cmp(other_member))
+}
+
+impl SkipList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Insert an element, the member must not be in the list yet.
+    pub fn insert(&mut self, member: Vec<u8>, score: f64) {
+        let (update, rank) = self.search(score, &member);
+
+        let level = random_level();
+
+        if level > self.level {
+            for link in &mut self.head[self.level..level] {
+                link.span = self.len;
+            }
+
+            self.level = level;
+        }
+
+        let id = self.alloc(Node {
+            member,
+            score,
+            backward: update[0],
+            levels: vec![Link::default(); level],
+        });
+
+        for i in 0..level {
+            let prev = *self.link(update[i], i);
+            let skipped = rank[0] - rank[i];
+
+            self.node_mut(id).levels[i] = Link {
+                forward: prev.forward,
+                span: prev.span - skipped,
+            };
+
+            *self.link_mut(update[i], i) = Link {
+                forward: Some(id),
+                span: skipped + 1,
+            };
+        }
+
+        // the levels above the new node now skip over one more
+        for (i, at) in update.iter().enumerate().take(self.level).skip(level) {
+            self.link_mut(*at, i).span += 1;
+        }
+
+        match self.node(id).levels[0].forward {
+            Some(next) => self.node_mut(next).backward = Some(id),
+            None => self.tail = Some(id),
+        }
+
+        self.len += 1;
+    }
+
+    /// Remove an element, returns whether it was there.
+    pub fn remove(&mut self, member: &[u8], score: f64) -> bool {
+        let (update, _) = self.search(score, member);
+
+        let Some(id) = self.link(update[0], 0).forward else {
+            return false;
+        };
+
+        if compare(self.node(id).score, &self.node(id).member, score, member).is_ne() {
+            return false;
+        }
+
+        for (i, at) in update.iter().enumerate().take(self.level) {
+            let link = *self.link(*at, i);
+
+            if link.forward == Some(id) {
+                let removed = self.node(id).levels[i];
+
+                *self.link_mut(*at, i) = Link {
+                    forward: removed.forward,
+                    span: link.span + removed.span - 1,
+                };
+            } else {
+                self.link_mut(*at, i).span -= 1;
+            }
+        }
+
+        let backward = self.node(id).backward;
+
+        match self.node(id).levels[0].forward {
+            Some(next) => self.node_mut(next).backward = backward,
+            None => self.tail = backward,
+        }
+
+        while self.level > 1 && self.head[self.level - 1].forward.is_none() {
+            self.level -= 1;
+        }
+
+        self.nodes[id] = None;
+        self.free.push(id);
+        self.len -= 1;
+
+        true
+    }
+
+    /// The number of elements before the first one matching `pred`, which
+    /// has to be false for a prefix of the list and true for the rest.
+    pub fn partition_point(&self, pred: impl Fn(f64, &[u8]) -> bool) -> usize {
+        let mut at = None;
+        let mut rank = 0;
+
+        for i in (0..self.level).rev() {
+            while let Some(next) = self.link(at, i).forward {
+                let node = self.node(next);
+
+                if pred(node.score, &node.member) {
+                    break;
+                }
+
+                rank += self.link(at, i).span;
+                at = Some(next);
+            }
+        }
+
+        rank
+    }
+
+    /// Elements from the 0 based `rank` on, in order.
+    pub fn iter_from(&self, rank: usize) -> impl Iterator<Item = (&[u8], f64)> {
+        std::iter::successors(self.find(rank), |id| self.node(*id).levels[0].forward)
+            .map(|id| self.entry(id))
+    }
+
+    /// Elements from the 0 based `rank` back to the first one.
+    pub fn iter_rev_from(&self, rank: usize) -> impl Iterator<Item = (&[u8], f64)> {
+        std::iter::successors(self.find(rank), |id| self.node(*id).backward)
+            .map(|id| self.entry(id))
+    }
+
+    /// The node at a 0 based rank.
+    fn find(&self, rank: usize) -> Option<usize> {
+        if rank >= self.len {
+            return None;
+        }
+
+        // popping from the end is common, the tail gets there right away
+        if rank + 1 == self.len {
+            return self.tail;
+        }
+
+        let mut at = None;
+        let mut traversed = 0;
+
+        for i in (0..self.level).rev() {
+            while let Some(next) = self.link(at, i).forward {
+                let span = self.link(at, i).span;
+
+                if traversed + span > rank + 1 {
+                    break;
+                }
+
+                traversed += span;
+                at = Some(next);
+            }
+
+            if traversed == rank + 1 {
+                return at;
+            }
+        }
+
+        None
+    }
+
+    /// The last position before where `(score, member)` goes on every level
+    /// and the rank of each of those positions.
+    fn search(
+        &self,
+        score: f64,
+        member: &[u8],
+    ) -> ([Option<usize>; MAX_LEVEL], [usize; MAX_LEVEL]) {
+        let mut update = [None; MAX_LEVEL];
+        let mut rank = [0; MAX_LEVEL];
+        let mut at = None;
+
+        for i in (0..self.level).rev() {
+            rank[i] = if i + 1 == self.level { 0 } else { rank[i + 1] };
+
+            while let Some(next) = self.link(at, i).forward {
+                let node = self.node(next);
+
+                if compare(node.score, &node.member, score, member).is_ge() {
+                    break;
+                }
+
+                rank[i] += self.link(at, i).span;
+                at = Some(next);
+            }
+
+            update[i] = at;
+        }
+
+        (update, rank)
+    }
+
+    fn entry(&self, id: usize) -> (&[u8], f64) {
+        let node = self.node(id);
+        (&node.member, node.score)
+    }
+
+    fn alloc(&mut self, node: Node) -> usize {
+        match self.free.pop() {
+            Some(id) => {
+                self.nodes[id] = Some(node);
+                id
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn node(&self, id: usize) -> &Node {
+        self.nodes[id]
+            .as_ref()
+            .expect("links only point at live nodes")
+    }
+
+    fn node_mut(&mut self, id: usize) -> &mut Node {
+        self.nodes[id]
+            .as_mut()
+            .expect("links only point at live nodes")
+    }
+
+    fn link(&self, at: Option<usize>, level: usize) -> &Link {
+        match at {
+            Some(id) => &self.node(id).levels[level],
+            None => &self.head[level],
+        }
+    }
+
+    fn link_mut(&mut self, at: Option<usize>, level: usize) -> &mut Link {
+        match at {
+            Some(id) => &mut self.node_mut(id).levels[level],
+            None => &mut self.head[level],
+        }
+    }
+}
+
+/// Each level up is taken with a chance of one in four.
+fn random_level() -> usize {
+    let mut level = 1;
+
+    while level < MAX_LEVEL && random_u64().is_multiple_of(4) {
+        level += 1;
+    }
+
+    level
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn members(list: &SkipList) -> Vec<String> {
+        list.iter_from(0)
+            .map(|(member, _)| String::from_utf8_lossy(member).into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_skiplist_order_and_rank() {
+        let mut list = SkipList::new();
+
+        for i in (0..1000).rev() {
+            list.insert(format!("m{i:04}").into_bytes(), f64::from(i % 10));
+        }
+
+        assert_eq!(list.len(), 1000);
+
+        // ordered by score, ties broken by member
+        let all = members(&list);
+        assert_eq!(all[..3], ["m0000", "m0010", "m0020"]);
+        assert_eq!(all[100], "m0001");
+
+        for rank in [0, 1, 99, 100, 500, 998, 999] {
+            let (member, score) = list.iter_from(rank).next().unwrap();
+            let (prev, _) = list.iter_rev_from(rank).next().unwrap();
+            assert_eq!(member, prev);
+
+            let below = list.partition_point(|s, m| compare(s, m, score, member).is_ge());
+            assert_eq!(below, rank);
+        }
+
+        assert_eq!(list.partition_point(|score, _| score >= 5.0), 500);
+        assert!(list.iter_from(1000).next().is_none());
+        assert_eq!(list.iter_rev_from(999).count(), 1000);
+    }
+
+    #[test]
+    fn test_skiplist_remove() {
+        let mut list = SkipList::new();
+
+        for i in 0..200 {
+            list.insert(format!("m{i:03}").into_bytes(), f64::from(i));
+        }
+
+        for i in (0..200).step_by(2) {
+            assert!(list.remove(format!("m{i:03}").as_bytes(), f64::from(i)));
+        }
+
+        assert!(!list.remove(b"m000", 0.0));
+        assert!(!list.remove(b"m001", 2.0));
+        assert_eq!(list.len(), 100);
+        assert_eq!(members(&list)[..2], ["m001", "m003"]);
+        assert_eq!(list.iter_from(99).next().unwrap().0, b"m199");
+        assert_eq!(list.iter_rev_from(99).last().unwrap().0, b"m001");
+
+        // freed slots are reused
+        list.insert(b"m000".to_vec(), 0.0);
+        assert_eq!(list.nodes.len(), 200);
+        assert_eq!(list.iter_from(0).next().unwrap().0, b"m000");
+        assert_eq!(list.iter_from(1).next().unwrap().0, b"m001");
+    }
+}
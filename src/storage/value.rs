@@ -1,464 +1,192 @@
-use std::collections::{HashMap, HashSet, VecDeque};
-use std::future::Future;
-use std::pin::Pin;
-
-use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
-use tokio::time::Instant;
-
-use crate::prelude::*;
-
+use std::borrow::Cow;
+
+use super::hash::HashValue;
+use super::quicklist::QuickList;
+use super::set::SetValue;
+use super::zset::ZSetValue;
+use crate::utils::number::parse_integer;
+use crate::utils::time::unix_millis;
+
+/// A value held in the keyspace.
+///
+/// This is purely the stored datum, how it ends up on the wire is decided by
+/// the command that reads it.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Value {
-    Ok,   // only for response
-    Pong, // only for response
-    Nil,
-    Simple(String),
-    Error(String),
-    Integer(i64),
-    String(String),
-    Multi(VecDeque<Value>),
-    Expire((Box<Value>, Instant)),
-    Map(HashMap<Value, Value>),
-
-    // not implemented yet
-    Set(HashSet<Value>),
+    String(StringValue),
+    List(QuickList),
+    Hash(HashValue),
+    Set(SetValue),
+    ZSet(ZSetValue),
 }
 
-impl std::hash::Hash for Value {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl Value {
+    /// The name reported by `TYPE`.
+    pub fn type_name(&self) -> &'static str {
         match self {
-            Self::Ok => "ok".hash(state),
-            Self::Nil => "nil".hash(state),
-            Self::Pong => "pong".hash(state),
-            Self::Simple(s) => s.hash(state),
-            Self::Error(e) => e.hash(state),
-            Self::Integer(i) => i.hash(state),
-            Self::String(s) => s.hash(state),
-            Self::Multi(v) => v.hash(state),
-            Self::Expire((v, _)) => v.hash(state),
-
-            Self::Map(_) | Self::Set(_) => unreachable!(),
-            // Self::Hashmap(v) => {
-            //     use std::collections::hash_map::DefaultHasher;
-            //     use std::hash::Hasher;
-
-            //     let mut total_hash = 0;
-
-            //     for (k, v) in v.iter() {
-            //         let mut hasher = DefaultHasher::new();
-
-            //         k.hash(&mut hasher);
-            //         v.hash(&mut hasher);
-
-            //         let pair_hash = hasher.finish();
-
-            //         // this might be insecure however it does not matter for now
-            //         total_hash ^= pair_hash; // XOR the hashes together
-            //     }
-
-            //     total_hash.hash(state);
-            // }
+            Self::String(_) => "string",
+            Self::List(_) => "list",
+            Self::Hash(_) => "hash",
+            Self::Set(_) => "set",
+            Self::ZSet(_) => "zset",
         }
     }
 }
 
-macro_rules! value_error {
-    ($($arg:tt)*) => {
-        Value::Error(format!($($arg)*))
-    };
+impl From<Vec<u8>> for Value {
+    fn from(value: Vec<u8>) -> Self {
+        Value::String(value.into())
+    }
+}
+
+/// A string value. Strings holding a canonical integer are kept as one, so
+/// counters don't round-trip through text on every update.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StringValue {
+    Raw(Vec<u8>),
+    Int(i64),
 }
 
-pub(crate) use value_error;
+impl StringValue {
+    /// The longest an integer can be in text, `-9223372036854775808`.
+    const MAX_INT_LEN: usize = 20;
 
-impl Value {
-    pub fn expired(&self) -> bool {
+    pub fn to_vec(&self) -> Vec<u8> {
         match self {
-            Self::Expire((_, expires_at)) => Instant::now() > *expires_at,
-            _ => false,
+            Self::Raw(bytes) => bytes.clone(),
+            Self::Int(value) => value.to_string().into_bytes(),
         }
     }
 
-    pub fn is_some(&self) -> bool {
+    pub fn into_vec(self) -> Vec<u8> {
         match self {
-            Self::Nil => false,
-            _ if Self::expired(&self) => false,
-            _ => true,
+            Self::Raw(bytes) => bytes,
+            Self::Int(value) => value.to_string().into_bytes(),
         }
     }
 
-    pub fn is_none(&self) -> bool {
-        !self.is_some()
+    pub fn as_bytes(&self) -> Cow<'_, [u8]> {
+        match self {
+            Self::Raw(bytes) => Cow::Borrowed(bytes),
+            Self::Int(value) => Cow::Owned(value.to_string().into_bytes()),
+        }
     }
 
-    pub fn to_resp2<'a, T>(
-        &'a self,
-        writer: &'a mut T,
-    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
-    where
-        T: AsyncWriteExt + Unpin + Send,
-    {
-        Box::pin(async move {
-            match self {
-                Self::Ok => Self::to_resp2(&Self::Simple("OK".to_string()), writer).await,
-                Self::Pong => Self::to_resp2(&Self::Simple("PONG".to_string()), writer).await,
-
-                Self::Nil => Ok(writer.write_all(b"$-1\r\n").await?),
-                Self::Simple(s) => Ok(writer.write_all(format!("+{s}\r\n").as_bytes()).await?),
-                Self::Error(e) => Ok(writer.write_all(format!("-{e}\r\n").as_bytes()).await?),
-                Self::Integer(i) => Ok(writer.write_all(format!(":{i}\r\n").as_bytes()).await?),
-                Self::String(s) => {
-                    let len = s.len();
-
-                    writer
-                        .write_all(format!("${len}\r\n{s}\r\n").as_bytes())
-                        .await?;
-
-                    Ok(())
-                }
-                Self::Multi(v) => {
-                    let len = v.len();
-
-                    writer.write_all(format!("*{len}\r\n").as_bytes()).await?;
-
-                    for value in v {
-                        value.to_resp2(writer).await?;
-                    }
-
-                    Ok(())
-                }
-                Self::Map(h) => {
-                    let mut values = VecDeque::with_capacity(h.len() * 2);
-
-                    for (k, v) in h {
-                        values.push_back(k.clone());
-                        values.push_back(v.clone());
-                    }
-
-                    Value::Multi(values).to_resp2(writer).await
-                }
-                Self::Set(s) => {
-                    let mut values = VecDeque::with_capacity(s.len());
-
-                    for v in s {
-                        values.push_back(v.clone());
-                    }
-
-                    Value::Multi(values).to_resp2(writer).await
-                }
-                Self::Expire((v, _)) => {
-                    // check if the value is expired
-                    if Self::expired(&self) {
-                        return Self::Nil.to_resp2(writer).await;
-                    }
+    /// Switch to the raw encoding and hand out the bytes for editing in place.
+    pub fn raw_mut(&mut self) -> &mut Vec<u8> {
+        if let Self::Int(value) = self {
+            *self = Self::Raw(value.to_string().into_bytes());
+        }
 
-                    v.to_resp2(writer).await
-                }
-            }
-        })
+        match self {
+            Self::Raw(bytes) => bytes,
+            Self::Int(_) => unreachable!("converted to raw above"),
+        }
     }
 
-    pub fn to_resp3<'a, T>(
-        &'a self,
-        writer: &'a mut T,
-    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
-    where
-        T: AsyncWriteExt + Unpin + Send,
-    {
-        Box::pin(async move {
-            match self {
-                Self::Nil => Ok(writer.write_all(b"$_\r\n").await?),
-                Self::Map(map) => {
-                    let len = map.len();
-
-                    writer.write_all(format!("%{len}\r\n").as_bytes()).await?;
-
-                    for (k, v) in map {
-                        k.to_resp3(writer).await?;
-                        v.to_resp3(writer).await?;
-                    }
-
-                    Ok(())
-                }
-                Self::Set(set) => {
-                    let len = set.len();
-
-                    writer.write_all(format!("~{len}\r\n").as_bytes()).await?;
-
-                    for v in set {
-                        v.to_resp3(writer).await?;
-                    }
-
-                    Ok(())
-                }
-
-                // rest of the code is the same as to_resp2
-                // edit: not really but clients are forgiving
-                _ => self.to_resp2(writer).await,
-            }
-        })
+    /// Length in bytes of the value as text.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Raw(bytes) => bytes.len(),
+            Self::Int(value) => value.to_string().len(),
+        }
     }
 
-    pub fn from_resp<'a, T>(
-        reader: &'a mut BufReader<&mut T>,
-    ) -> Pin<Box<dyn Future<Output = Result<Option<Self>>> + Send + 'a>>
-    where
-        T: AsyncReadExt + Unpin + Send,
-    {
-        Box::pin(async move {
-            let mut line = String::new();
-
-            reader.read_line(&mut line).await?;
-
-            match line.chars().next() {
-                Some('$') if line == "$-1\r\n" => Ok(Some(Self::Nil)),
-
-                Some('$') => {
-                    let len: usize = line[1..]
-                        .trim()
-                        .parse()
-                        .context("Could not parse integer")?;
-
-                    // +2 for `\r\n
-                    let mut value = vec![0; len];
-                    reader.read_exact(&mut value).await?;
-
-                    // +2 for `\r\n
-                    reader.read_exact(&mut [0; 2]).await?;
-
-                    // SAFETY: we know that the value is a valid utf8 string, or at least it should
-                    let value = unsafe { String::from_utf8_unchecked(value) };
-
-                    Ok(Some(Self::String(value)))
-                }
-
-                Some(':') => {
-                    let value: i64 = line[1..]
-                        .trim()
-                        .parse()
-                        .context("Could not parse integer")?;
-
-                    Ok(Some(Self::Integer(value)))
-                }
-
-                Some('*') => {
-                    let len: usize = line[1..]
-                        .trim()
-                        .parse()
-                        .context("Could not parse integer")?;
-                    let mut values = VecDeque::with_capacity(len);
-
-                    for _ in 0..len {
-                        let value = Self::from_resp(reader).await?;
-
-                        if let Some(value) = value {
-                            values.push_back(value);
-                        } else {
-                            return Ok(None);
-                        }
-                    }
-
-                    Ok(Some(Self::Multi(values)))
-                }
-
-                Some('%') => {
-                    let len: usize = line[1..]
-                        .trim()
-                        .parse()
-                        .context("Could not parse integer")?;
-                    let mut map = HashMap::with_capacity(len);
-
-                    for _ in 0..len {
-                        let key = Self::from_resp(reader).await?;
-                        let value = Self::from_resp(reader).await?;
-
-                        match (key, value) {
-                            (Some(key), Some(value)) => {
-                                map.insert(key, value);
-                            }
-                            _ => return Ok(None),
-                        }
-                    }
-
-                    Ok(Some(Self::Map(map)))
-                }
-
-                Some('~') => {
-                    let len: usize = line[1..]
-                        .trim()
-                        .parse()
-                        .context("Could not parse integer")?;
-                    let mut set = HashSet::with_capacity(len);
-
-                    for _ in 0..len {
-                        let value = Self::from_resp(reader).await?;
-
-                        if let Some(value) = value {
-                            set.insert(value);
-                        } else {
-                            return Ok(None);
-                        }
-                    }
-
-                    Ok(Some(Self::Set(set)))
-                }
-
-                Some('+') => {
-                    let value = line[1..].trim();
-
-                    match value {
-                        "OK" => Ok(Some(Self::Ok)),
-                        "PONG" => Ok(Some(Self::Pong)),
-                        _ => unreachable!("Invalid response"),
-                    }
-                }
-
-                Some('-') => Ok(Some(Self::Error(line[1..].trim().to_string()))),
-
-                None => Ok(None),
-
-                _ => Ok(Some(Self::Error("Invalid response".to_string()))),
-            }
-        })
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-}
 
-impl From<Option<Value>> for Value {
-    fn from(value: Option<Value>) -> Self {
-        value.unwrap_or(Value::Nil)
+    /// The value as an integer, if it is one.
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Self::Raw(bytes) if bytes.len() <= Self::MAX_INT_LEN => parse_integer(bytes),
+            Self::Raw(_) => None,
+            Self::Int(value) => Some(*value),
+        }
     }
 }
 
-impl From<String> for Value {
-    fn from(value: String) -> Self {
-        Value::String(value)
-    }
-}
+impl From<Vec<u8>> for StringValue {
+    fn from(value: Vec<u8>) -> Self {
+        if value.len() <= Self::MAX_INT_LEN {
+            if let Some(int) = parse_integer(&value) {
+                return Self::Int(int);
+            }
+        }
 
-impl From<VecDeque<Value>> for Value {
-    fn from(value: VecDeque<Value>) -> Self {
-        Value::Multi(value)
+        Self::Raw(value)
     }
 }
 
-impl From<i64> for Value {
+impl From<i64> for StringValue {
     fn from(value: i64) -> Self {
-        Value::Integer(value)
+        Self::Int(value)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use tokio::io::AsyncWriteExt;
-    use tokio::net::{TcpListener, TcpStream};
-
-    use super::*;
-
-    async fn create_tcp_stream<'a>(data: &str) -> TcpStream {
-        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
-        let addr = listener.local_addr().unwrap();
-
-        let mut writer = TcpStream::connect(addr).await.unwrap();
-        writer.write(data.as_bytes()).await.unwrap();
-
-        let (stream, _) = listener.accept().await.unwrap();
+/// A keyspace slot: the value plus the metadata kept per key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub value: Value,
+    /// Absolute deadline in Unix milliseconds.
+    pub expire_at: Option<i64>,
+}
 
-        stream
+impl Entry {
+    pub fn new(value: Value) -> Self {
+        Self {
+            value,
+            expire_at: None,
+        }
     }
 
-    #[tokio::test]
-    async fn test_value_to_resp() {
-        let value = Value::String("Hello, World!".to_string());
-        let mut buff = Vec::new();
-        value.to_resp2(&mut buff).await.unwrap();
-
-        assert_eq!(buff, b"$13\r\nHello, World!\r\n");
-
-        let value = Value::Integer(42);
-        let mut buff = Vec::new();
-        value.to_resp2(&mut buff).await.unwrap();
-
-        assert_eq!(buff, b":42\r\n");
-
-        let value = Value::Nil;
-        let mut buff = Vec::new();
-        value.to_resp2(&mut buff).await.unwrap();
-
-        assert_eq!(buff, b"$-1\r\n");
-
-        let value = Value::Multi(VecDeque::from([
-            Value::String("Hello, World!".to_string()),
-            Value::Integer(42),
-            Value::Nil,
-        ]));
-        let mut buff = Vec::new();
-        value.to_resp2(&mut buff).await.unwrap();
-
-        println!("{:?}", std::str::from_utf8(&buff).unwrap());
-
-        assert_eq!(buff, b"*3\r\n$13\r\nHello, World!\r\n:42\r\n$-1\r\n");
-
-        let value = Value::Multi(VecDeque::from([
-            Value::String("key".to_string()),
-            Value::String("value".to_string()),
-        ]));
-
-        let mut buff = Vec::new();
-        value.to_resp2(&mut buff).await.unwrap();
-
-        assert_eq!(buff, b"*2\r\n$3\r\nkey\r\n$5\r\nvalue\r\n");
+    pub fn with_expire(value: Value, expire_at: i64) -> Self {
+        Self {
+            value,
+            expire_at: Some(expire_at),
+        }
     }
 
-    #[tokio::test]
-    async fn test_value_from_resp() {
-        let mut stream = create_tcp_stream("$13\r\nHello, World!\r\n").await;
-        let (mut read, _) = stream.split();
-        let mut reader = BufReader::new(&mut read);
-
-        let value = Value::from_resp(&mut reader).await.unwrap();
-        assert_eq!(value, Some(Value::String("Hello, World!".to_string())));
-
-        let mut stream = create_tcp_stream(":42\r\n").await;
-        let (mut read, _) = stream.split();
-        let mut reader = BufReader::new(&mut read);
-
-        let value = Value::from_resp(&mut reader).await.unwrap();
-        assert_eq!(value, Some(Value::Integer(42)));
-
-        let mut stream = create_tcp_stream("$-1\r\n").await;
-        let (mut read, _) = stream.split();
-        let mut reader = BufReader::new(&mut read);
+    /// Whether the key is gone: its deadline has passed, or it's a hash
+    /// whose fields have all expired.
+    pub fn expired(&self) -> bool {
+        let deadline_passed = match self.expire_at {
+            Some(expire_at) => unix_millis() > expire_at,
+            None => false,
+        };
 
-        let value = Value::from_resp(&mut reader).await.unwrap();
-        assert_eq!(value, Some(Value::Nil));
+        deadline_passed || matches!(&self.value, Value::Hash(hash) if hash.is_empty())
+    }
+}
 
-        let mut stream = create_tcp_stream("*3\r\n$13\r\nHello, World!\r\n:42\r\n$-1\r\n").await;
-        let (mut read, _) = stream.split();
-        let mut reader = BufReader::new(&mut read);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let value = Value::from_resp(&mut reader).await.unwrap();
+    #[test]
+    fn test_string_value_encoding() {
+        assert_eq!(StringValue::from(b"42".to_vec()), StringValue::Int(42));
         assert_eq!(
-            value,
-            Some(Value::Multi(VecDeque::from([
-                Value::String("Hello, World!".to_string()),
-                Value::Integer(42),
-                Value::Nil
-            ])))
+            StringValue::from(b"-9223372036854775808".to_vec()),
+            StringValue::Int(i64::MIN)
         );
 
-        let mut stream = create_tcp_stream("*2\r\n$3\r\nkey\r\n$5\r\nvalue\r\n").await;
-        let (mut read, _) = stream.split();
-        let mut reader = BufReader::new(&mut read);
+        // anything that wouldn't print back the same stays raw
+        for raw in [
+            &b"042"[..],
+            b"+1",
+            b" 1",
+            b"-0",
+            b"1.0",
+            b"9223372036854775808",
+        ] {
+            assert_eq!(
+                StringValue::from(raw.to_vec()),
+                StringValue::Raw(raw.to_vec())
+            );
+        }
 
-        let value = Value::from_resp(&mut reader).await.unwrap();
-        let mut hashmap = HashMap::new();
-        hashmap.insert("key".to_string(), Value::String("value".to_string()));
-        assert_eq!(
-            value,
-            // it wont be a hashmap by default since there is no spec for hashmaps in RESP
-            Some(Value::Multi(VecDeque::from([
-                Value::String("key".to_string()),
-                Value::String("value".to_string())
-            ])))
-        );
+        assert_eq!(StringValue::Int(-7).to_vec(), b"-7");
+        assert_eq!(StringValue::Raw(b"12".to_vec()).as_int(), Some(12));
     }
 }
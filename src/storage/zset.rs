@@ -0,0 +1,302 @@
+use std::collections::HashMap;
+
+use super::skiplist::{compare, SkipList};
+use crate::utils::random::{random_index, sample_indices};
+
+/// Most members a sorted set keeps in the compact encoding.
+const MAX_COMPACT_ENTRIES: usize = 128;
+/// Longest member the compact encoding holds.
+const MAX_COMPACT_MEMBER: usize = 64;
+
+/// A sorted set value, ordered by score and then member.
+///
+/// Small sets are a sorted array of `(member, score)` pairs, like the Redis
+/// listpack encoding, where members are found by a scan and ranks by a
+/// binary search. Once a set outgrows it, it's converted for good into a
+/// skiplist for the order plus a map from member to score for lookups.
+#[derive(Clone, Debug)]
+pub enum ZSetValue {
+    Compact(Vec<(Vec<u8>, f64)>),
+    Skip {
+        list: SkipList,
+        scores: HashMap<Vec<u8>, f64>,
+    },
+}
+
+impl Default for ZSetValue {
+    fn default() -> Self {
+        Self::Compact(Vec::new())
+    }
+}
+
+impl ZSetValue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Compact(entries) => entries.len(),
+            Self::Skip { scores, .. } => scores.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn score(&self, member: &[u8]) -> Option<f64> {
+        match self {
+            Self::Compact(entries) => entries
+                .iter()
+                .find(|(other, _)| other == member)
+                .map(|(_, score)| *score),
+            Self::Skip { scores, .. } => scores.get(member).copied(),
+        }
+    }
+
+    /// Add a member or update its score, returns the previous score.
+    pub fn insert(&mut self, member: Vec<u8>, score: f64) -> Option<f64> {
+        let previous = self.remove(&member);
+
+        if let Self::Compact(entries) = self {
+            if entries.len() < MAX_COMPACT_ENTRIES && member.len() <= MAX_COMPACT_MEMBER {
+                let index = entries.partition_point(|(other, other_score)| {
+                    compare(*other_score, other, score, &member).is_lt()
+                });
+
+                entries.insert(index, (member, score));
+                return previous;
+            }
+
+            self.convert();
+        }
+
+        if let Self::Skip { list, scores } = self {
+            list.insert(member.clone(), score);
+            scores.insert(member, score);
+        }
+
+        previous
+    }
+
+    /// Remove a member, returns its score if it was there.
+    pub fn remove(&mut self, member: &[u8]) -> Option<f64> {
+        match self {
+            Self::Compact(entries) => {
+                let index = entries.iter().position(|(other, _)| other == member)?;
+                Some(entries.remove(index).1)
+            }
+            Self::Skip { list, scores } => {
+                let score = scores.remove(member)?;
+                list.remove(member, score);
+
+                Some(score)
+            }
+        }
+    }
+
+    /// The 0 based rank of a member in ascending order.
+    pub fn rank(&self, member: &[u8]) -> Option<usize> {
+        let score = self.score(member)?;
+
+        Some(self.partition_point(|other_score, other| {
+            compare(other_score, other, score, member).is_ge()
+        }))
+    }
+
+    /// The number of members before the first one matching `pred`, which has
+    /// to be false for a prefix of the set and true for the rest.
+    pub fn partition_point(&self, pred: impl Fn(f64, &[u8]) -> bool) -> usize {
+        match self {
+            Self::Compact(entries) => {
+                entries.partition_point(|(member, score)| !pred(*score, member))
+            }
+            Self::Skip { list, .. } => list.partition_point(pred),
+        }
+    }
+
+    /// Members with their scores from the 0 based `rank` on, in order.
+    pub fn iter_from(&self, rank: usize) -> Box<dyn Iterator<Item = (&[u8], f64)> + Send + '_> {
+        match self {
+            Self::Compact(entries) => Box::new(
+                entries
+                    .iter()
+                    .skip(rank)
+                    .map(|(member, score)| (&member[..], *score)),
+            ),
+            Self::Skip { list, .. } => Box::new(list.iter_from(rank)),
+        }
+    }
+
+    /// Members with their scores from the 0 based `rank` back to the first.
+    pub fn iter_rev_from(&self, rank: usize) -> Box<dyn Iterator<Item = (&[u8], f64)> + Send + '_> {
+        match self {
+            Self::Compact(entries) => Box::new(
+                entries
+                    .iter()
+                    .take(rank.saturating_add(1))
+                    .rev()
+                    .map(|(member, score)| (&member[..], *score)),
+            ),
+            Self::Skip { list, .. } => Box::new(list.iter_rev_from(rank)),
+        }
+    }
+
+    pub fn iter(&self) -> Box<dyn Iterator<Item = (&[u8], f64)> + Send + '_> {
+        self.iter_from(0)
+    }
+
+    /// A random member with its score, picked by rank.
+    pub fn random(&self) -> Option<(Vec<u8>, f64)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.iter_from(random_index(self.len()))
+            .next()
+            .map(|(member, score)| (member.to_vec(), score))
+    }
+
+    /// Up to `count` distinct random members with their scores.
+    pub fn sample(&self, count: usize) -> Vec<(Vec<u8>, f64)> {
+        sample_indices(self.len(), count)
+            .into_iter()
+            .filter_map(|rank| self.iter_from(rank).next())
+            .map(|(member, score)| (member.to_vec(), score))
+            .collect()
+    }
+
+    /// Remove up to `count` members with the lowest scores, or the highest
+    /// with `max`, and return them in the order they were popped.
+    pub fn pop(&mut self, count: usize, max: bool) -> Vec<(Vec<u8>, f64)> {
+        let count = count.min(self.len());
+
+        let popped: Vec<_> = match max {
+            true => self.iter_rev_from(self.len().saturating_sub(1)),
+            false => self.iter(),
+        }
+        .take(count)
+        .map(|(member, score)| (member.to_vec(), score))
+        .collect();
+
+        for (member, _) in &popped {
+            self.remove(member);
+        }
+
+        popped
+    }
+
+    fn convert(&mut self) {
+        if let Self::Compact(entries) = self {
+            let mut list = SkipList::new();
+            let mut scores = HashMap::with_capacity(entries.len());
+
+            for (member, score) in entries.drain(..) {
+                list.insert(member.clone(), score);
+                scores.insert(member, score);
+            }
+
+            *self = Self::Skip { list, scores };
+        }
+    }
+}
+
+impl PartialEq for ZSetValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for ZSetValue {}
+
+impl FromIterator<(Vec<u8>, f64)> for ZSetValue {
+    fn from_iter<I: IntoIterator<Item = (Vec<u8>, f64)>>(iter: I) -> Self {
+        let mut zset = Self::new();
+
+        for (member, score) in iter {
+            zset.insert(member, score);
+        }
+
+        zset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(zset: &ZSetValue) {
+        let all: Vec<_> = zset.iter().collect();
+
+        assert!(all
+            .windows(2)
+            .all(|pair| compare(pair[0].1, pair[0].0, pair[1].1, pair[1].0).is_lt()));
+
+        for (rank, (member, score)) in all.iter().enumerate() {
+            assert_eq!(zset.rank(member), Some(rank));
+            assert_eq!(zset.score(member), Some(*score));
+            assert_eq!(zset.iter_rev_from(rank).next(), Some((*member, *score)));
+        }
+    }
+
+    #[test]
+    fn test_zset_value_encoding() {
+        let mut zset = ZSetValue::new();
+
+        assert_eq!(zset.insert(b"b".to_vec(), 1.0), None);
+        assert_eq!(zset.insert(b"a".to_vec(), 1.0), None);
+        assert_eq!(zset.insert(b"c".to_vec(), 0.5), None);
+        assert_eq!(zset.insert(b"c".to_vec(), 2.0), Some(0.5));
+        assert!(matches!(zset, ZSetValue::Compact(_)));
+        assert_eq!(zset.rank(b"c"), Some(2));
+        assert_eq!(zset.rank(b"x"), None);
+        check(&zset);
+
+        // a long member converts the set
+        let long = vec![b'x'; MAX_COMPACT_MEMBER + 1];
+        zset.insert(long.clone(), -1.0);
+        assert!(matches!(zset, ZSetValue::Skip { .. }));
+        assert_eq!(zset.rank(&long), Some(0));
+        check(&zset);
+
+        let mut zset: ZSetValue = (0..=MAX_COMPACT_ENTRIES)
+            .map(|i| (i.to_string().into_bytes(), (i % 7) as f64))
+            .collect();
+        assert!(matches!(zset, ZSetValue::Skip { .. }));
+        check(&zset);
+
+        for i in (0..=MAX_COMPACT_ENTRIES).step_by(3) {
+            assert_eq!(zset.remove(i.to_string().as_bytes()), Some((i % 7) as f64));
+        }
+
+        assert_eq!(zset.remove(b"0"), None);
+        assert_eq!(zset.len(), 86);
+        check(&zset);
+        assert_eq!(zset.partition_point(|score, _| score >= 3.0), 38);
+    }
+
+    #[test]
+    fn test_zset_value_pop() {
+        let mut zset: ZSetValue = (0..200)
+            .map(|i| (i.to_string().into_bytes(), f64::from(i)))
+            .collect();
+
+        let low = zset.pop(2, false);
+        assert_eq!(low, [(b"0".to_vec(), 0.0), (b"1".to_vec(), 1.0)]);
+
+        let high = zset.pop(2, true);
+        assert_eq!(high, [(b"199".to_vec(), 199.0), (b"198".to_vec(), 198.0)]);
+        assert_eq!(zset.len(), 196);
+        check(&zset);
+
+        let sample = zset.sample(5);
+        assert_eq!(sample.len(), 5);
+        assert!(sample
+            .iter()
+            .all(|(member, score)| zset.score(member) == Some(*score)));
+        assert_eq!(zset.sample(1000).len(), 196);
+        assert_eq!(zset.pop(1000, true).len(), 196);
+        assert!(zset.is_empty() && zset.random().is_none());
+    }
+}
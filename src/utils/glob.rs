@@ -0,0 +1,163 @@
+/// Match `string` against a Redis style glob `pattern`, byte by byte.
+///
+/// Supports `*`, `?`, `[...]` classes (with `^` negation and `a-z` ranges)
+/// and `\` escapes, mirroring `stringmatchlen` from the Redis source so keys
+/// don't have to be valid UTF-8 to be matched.
+///
+/// Every other token matches exactly one byte, so on a mismatch only the last
+/// `*` has to swallow one more byte and the rest is retried from there. That
+/// single backtrack point keeps the match O(pattern * string), where
+/// recursing for every star blows up exponentially on patterns like
+/// `*a*a*a*b`.
+pub fn matches(pattern: &[u8], string: &[u8]) -> bool {
+    let mut p = 0;
+    let mut s = 0;
+
+    // the pattern right after the last star and how far into the string it got
+    let mut backtrack = None;
+
+    while s < string.len() {
+        if pattern.get(p) == Some(&b'*') {
+            // collapse consecutive stars
+            while pattern.get(p) == Some(&b'*') {
+                p += 1;
+            }
+
+            if p == pattern.len() {
+                return true;
+            }
+
+            backtrack = Some((p, s));
+            continue;
+        }
+
+        if let Some(next) = match_one(pattern, p, string[s]) {
+            p = next;
+            s += 1;
+            continue;
+        }
+
+        let Some((star_p, star_s)) = backtrack else {
+            return false;
+        };
+
+        backtrack = Some((star_p, star_s + 1));
+        p = star_p;
+        s = star_s + 1;
+    }
+
+    // an empty string is still matched by stars
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+/// Match the token at `p` against a byte, returns where the next token starts
+/// if it matched.
+fn match_one(pattern: &[u8], mut p: usize, c: u8) -> Option<usize> {
+    match *pattern.get(p)? {
+        b'?' => Some(p + 1),
+
+        b'[' => {
+            p += 1;
+
+            let negate = p < pattern.len() && pattern[p] == b'^';
+            if negate {
+                p += 1;
+            }
+
+            let mut matched = false;
+
+            loop {
+                if p >= pattern.len() {
+                    // unterminated class, treat the end as the closing bracket
+                    p -= 1;
+                    break;
+                }
+
+                match pattern[p] {
+                    b'\\' if p + 1 < pattern.len() => {
+                        p += 1;
+
+                        if pattern[p] == c {
+                            matched = true;
+                        }
+                    }
+
+                    b']' => break,
+
+                    start if p + 2 < pattern.len() && pattern[p + 1] == b'-' => {
+                        let end = pattern[p + 2];
+                        let (low, high) = if start > end {
+                            (end, start)
+                        } else {
+                            (start, end)
+                        };
+
+                        p += 2;
+
+                        if (low..=high).contains(&c) {
+                            matched = true;
+                        }
+                    }
+
+                    other => {
+                        if other == c {
+                            matched = true;
+                        }
+                    }
+                }
+
+                p += 1;
+            }
+
+            (matched != negate).then_some(p + 1)
+        }
+
+        b'\\' if p + 1 < pattern.len() => (pattern[p + 1] == c).then_some(p + 2),
+
+        other => (other == c).then_some(p + 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(matches(b"*", b""));
+        assert!(matches(b"*", b"anything"));
+        assert!(matches(b"h?llo", b"hello"));
+        assert!(!matches(b"h?llo", b"hllo"));
+        assert!(matches(b"h*llo", b"heeeello"));
+        assert!(matches(b"h[ae]llo", b"hallo"));
+        assert!(!matches(b"h[ae]llo", b"hillo"));
+        assert!(matches(b"h[^e]llo", b"hallo"));
+        assert!(!matches(b"h[^e]llo", b"hello"));
+        assert!(matches(b"h[a-b]llo", b"hbllo"));
+        assert!(matches(b"h\\*llo", b"h*llo"));
+        assert!(!matches(b"h\\*llo", b"hello"));
+        assert!(matches(b"user:*:name", b"user:42:name"));
+        assert!(!matches(b"user:*:name", b"user:42:age"));
+        assert!(matches(b"a*", b"a"));
+        assert!(matches(b"*a*b", b"xaxxab"));
+        assert!(!matches(b"*a*b", b"xaxxa"));
+        assert!(matches(b"h[a-", b"h-"));
+        assert!(matches(b"end\\", b"end\\"));
+    }
+
+    #[test]
+    fn test_glob_matches_many_stars() {
+        // recursing for every star takes exponential time on this one
+        let string = [b'a'; 60];
+
+        assert!(!matches(b"*a*a*a*a*a*a*a*a*b", &string));
+        assert!(matches(b"*a*a*a*a*a*a*a*a*", &string));
+    }
+
+    #[test]
+    fn test_glob_matches_binary() {
+        assert!(matches(b"bin:*", b"bin:\x00\xFF\xFE"));
+        assert!(matches(b"\xFF?", b"\xFF\x00"));
+        assert!(!matches(b"\xFF?", b"\xFE\x00"));
+    }
+}
@@ -1,6 +1,10 @@
 use crate::CLI;
 
+pub mod glob;
 pub mod logger;
+pub mod number;
+pub mod random;
+pub mod time;
 
 pub fn bootlog(cli: &CLI) {
     let name = env!("CARGO_PKG_NAME");
@@ -0,0 +1,116 @@
+/// Format a double the way Redis replies with it: the shortest representation
+/// that round-trips, `inf`/`-inf`/`nan` for the special values and an exponent
+/// for very large or very small magnitudes.
+pub fn format_double(value: f64) -> String {
+    if value.is_nan() {
+        return "nan".to_string();
+    }
+
+    if value.is_infinite() {
+        return if value > 0.0 { "inf" } else { "-inf" }.to_string();
+    }
+
+    let abs = value.abs();
+
+    if abs != 0.0 && !(1e-6..1e21).contains(&abs) {
+        let formatted = format!("{value:e}");
+
+        // 1e300 -> 1e+300, matching the C printf family
+        return match formatted.split_once('e') {
+            Some((mantissa, exponent)) if !exponent.starts_with('-') => {
+                format!("{mantissa}e+{exponent}")
+            }
+            _ => formatted,
+        };
+    }
+
+    value.to_string()
+}
+
+/// Format the result of float arithmetic stored as a string, like
+/// `INCRBYFLOAT`: in full without an exponent, trailing zeros trimmed.
+///
+/// Redis sums these in a `long double` and prints 17 decimals, which rounds
+/// away the binary error of the sum, so 0.1 plus 0.2 is `0.3`. Rounding to the
+/// 15 significant digits a double carries free of that error does the same,
+/// but only while the rounding stays within one unit in the last place: past
+/// that it would throw away digits the double really holds, so large values
+/// are written out exactly instead.
+pub fn format_human(value: f64) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return format_double(value.abs());
+    }
+
+    let rounded: f64 = format!("{value:.14e}").parse().unwrap_or(value);
+
+    if rounded == value || rounded == value.next_up() || rounded == value.next_down() {
+        return rounded.to_string();
+    }
+
+    value.to_string()
+}
+
+/// Parse a double, accepting `inf`, `-inf` and `nan` spelled in any case.
+pub fn parse_double(value: &str) -> Option<f64> {
+    value.parse().ok()
+}
+
+/// Parse a signed 64 bit integer the way Redis does: no sign on zero, no
+/// leading zeros or spaces.
+pub fn parse_integer(value: &[u8]) -> Option<i64> {
+    let canonical = matches!(value, [b'0'] | [b'-', b'1'..=b'9', ..] | [b'1'..=b'9', ..]);
+
+    if !canonical {
+        return None;
+    }
+
+    std::str::from_utf8(value).ok()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_double() {
+        assert_eq!(format_double(3.0), "3");
+        assert_eq!(format_double(-0.5), "-0.5");
+        assert_eq!(format_double(2.25), "2.25");
+        assert_eq!(format_double(1e300), "1e+300");
+        assert_eq!(format_double(1.5e-10), "1.5e-10");
+        assert_eq!(format_double(f64::INFINITY), "inf");
+        assert_eq!(format_double(f64::NEG_INFINITY), "-inf");
+        assert_eq!(format_double(f64::NAN), "nan");
+    }
+
+    #[test]
+    fn test_format_human() {
+        assert_eq!(format_human(0.1 + 0.2), "0.3");
+        assert_eq!(format_human(10.5), "10.5");
+        assert_eq!(format_human(-3.0), "-3");
+        assert_eq!(format_human(-0.0), "0");
+        assert_eq!(format_human(5.0e3), "5000");
+        assert_eq!(format_human(1e20), "100000000000000000000");
+        assert_eq!(format_human(1.5e-10), "0.00000000015");
+        assert_eq!(format_human(3.0e-1 - 1.0), "-0.7");
+
+        // digits the double holds are never rounded away
+        assert_eq!(format_human(1e15 + 0.5), "1000000000000000.5");
+        assert_eq!(
+            format_human(123456789012345678.0 + 1.0),
+            "123456789012345680"
+        );
+        assert_eq!(format_human(0.1 + 0.7), "0.8");
+    }
+
+    #[test]
+    fn test_parse_double() {
+        assert_eq!(parse_double("1.5"), Some(1.5));
+        assert_eq!(parse_double("-inf"), Some(f64::NEG_INFINITY));
+        assert_eq!(parse_double("+inf"), Some(f64::INFINITY));
+        assert!(parse_double("nan").unwrap().is_nan());
+        assert_eq!(parse_double(""), None);
+        assert_eq!(parse_double(" 1"), None);
+        assert_eq!(parse_double("abc"), None);
+    }
+}
@@ -0,0 +1,86 @@
+use std::cell::Cell;
+use std::collections::hash_map::RandomState;
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+
+thread_local! {
+    static STATE: Cell<u64> = Cell::new(RandomState::new().hash_one(0u64) | 1);
+}
+
+/// A pseudo random number from a per thread xorshift generator, good enough
+/// to pick random members but nothing that has to be unpredictable.
+pub fn random_u64() -> u64 {
+    STATE.with(|state| {
+        let mut x = state.get();
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        state.set(x);
+
+        x
+    })
+}
+
+/// A random index below `len`, or 0 for an empty range.
+pub fn random_index(len: usize) -> usize {
+    random_u64().checked_rem(len as u64).unwrap_or(0) as usize
+}
+
+/// Move `count` randomly picked items to the front, a partial Fisher-Yates
+/// shuffle.
+pub fn shuffle_front<T>(items: &mut [T], count: usize) {
+    for i in 0..count.min(items.len()) {
+        let j = i + random_index(items.len() - i);
+        items.swap(i, j);
+    }
+}
+
+/// How many times larger than the sample a range has to be for it to be
+/// picked sparsely, `SRANDMEMBER_SUB_STRATEGY_MUL` in Redis.
+const SPARSE_SAMPLE_FACTOR: usize = 3;
+
+/// Up to `count` distinct random indices below `len`.
+///
+/// A count much smaller than the range picks indices at random and rejects
+/// the ones it already has, like Redis does, so a few members of a huge
+/// collection cost no more than a few lookups. Otherwise the whole range is
+/// shuffled.
+pub fn sample_indices(len: usize, count: usize) -> Vec<usize> {
+    if count.saturating_mul(SPARSE_SAMPLE_FACTOR) <= len {
+        let mut picked = HashSet::with_capacity(count);
+
+        return std::iter::repeat_with(|| random_index(len))
+            .filter(|index| picked.insert(*index))
+            .take(count)
+            .collect();
+    }
+
+    let mut indices: Vec<usize> = (0..len).collect();
+
+    shuffle_front(&mut indices, count);
+    indices.truncate(count);
+
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_indices() {
+        for (len, count, expected) in [
+            (1000, 5, 5),
+            (10, 5, 5),
+            (10, 20, 10),
+            (0, 3, 0),
+            (10, 0, 0),
+        ] {
+            let indices = sample_indices(len, count);
+
+            assert_eq!(indices.len(), expected);
+            assert_eq!(indices.iter().collect::<HashSet<_>>().len(), expected);
+            assert!(indices.iter().all(|index| *index < len));
+        }
+    }
+}